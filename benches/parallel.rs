@@ -0,0 +1,132 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use drs::{
+    norms::euclidean_contiguous,
+    parallel,
+    problem::ConstraintProblem,
+    problems::{
+        latin_square::AssignmentProjector,
+        sudoku::{divide_projector, Sudoku},
+    },
+    projectors::simplex::SimplexProjector,
+    states::{aligned::AlignedVecState, vector::VecState},
+    Projector,
+};
+use std::hint::black_box;
+
+const SIZES: [usize; 6] = [1 << 10, 1 << 13, 1 << 16, 1 << 18, 1 << 20, 1 << 22];
+
+// Each size is run with the parallel path forced off and on, so the crossover is
+// where the two lines meet.
+fn modes() -> [(&'static str, usize); 2] {
+    [("sequential", usize::MAX), ("parallel", 0)]
+}
+
+fn bench_vec_state(c: &mut Criterion) {
+    let mut group = c.benchmark_group("vec_state_axpy");
+    for n in SIZES {
+        let x = VecState(vec![1.0; n]);
+        let y = VecState(vec![2.0; n]);
+        for (mode, threshold) in modes() {
+            parallel::set_threshold(threshold);
+            group.bench_with_input(BenchmarkId::new(mode, n), &n, |b, _| {
+                b.iter(|| black_box(x.clone() + y.clone() * 0.5))
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_aligned_state(c: &mut Criterion) {
+    let mut group = c.benchmark_group("aligned_state_axpy");
+    for n in SIZES {
+        let x = AlignedVecState::from(vec![1.0; n]);
+        let y = AlignedVecState::from(vec![2.0; n]);
+        for (mode, threshold) in modes() {
+            parallel::set_threshold(threshold);
+            group.bench_with_input(BenchmarkId::new(mode, n), &n, |b, _| {
+                b.iter(|| black_box(x.clone() + y.clone() * 0.5))
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_norm(c: &mut Criterion) {
+    let mut group = c.benchmark_group("euclidean_norm");
+    for n in SIZES {
+        let x = vec![1.0f32; n];
+        let y = vec![2.0f32; n];
+        for (mode, threshold) in modes() {
+            parallel::set_threshold(threshold);
+            group.bench_with_input(BenchmarkId::new(mode, n), &n, |b, _| {
+                b.iter(|| black_box(euclidean_contiguous(&x, &y)))
+            });
+        }
+    }
+    group.finish();
+}
+
+// One simplex block per group of 64 variables, so each block is a sort.
+fn bench_divide(c: &mut Criterion) {
+    let mut group = c.benchmark_group("product_space_divide");
+    for blocks in [16, 64, 256, 1024, 4096] {
+        let mut problem = ConstraintProblem::new();
+        for _ in 0..blocks {
+            let x = problem.variables(64);
+            problem.constrain(&x, SimplexProjector::new(1.0));
+        }
+        let values: Vec<f32> = (0..blocks * 64).map(|i| (i % 7) as f32).collect();
+        let state = problem.initial_state(&values).unwrap();
+        for (mode, threshold) in modes() {
+            parallel::set_block_threshold(threshold);
+            group.bench_with_input(BenchmarkId::new(mode, blocks), &blocks, |b, _| {
+                b.iter(|| black_box(problem.divide().project(state.clone()).unwrap()))
+            });
+        }
+    }
+    group.finish();
+}
+
+// A Hungarian assignment per row, column and block: 3n of them, each n x n.
+fn bench_sudoku_divide(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sudoku_divide");
+    for (n, k) in [(9, 3), (16, 4), (25, 5), (36, 6)] {
+        let cells = (0..n * n)
+            .map(|i| (k * (i / n % k) + i / n / k + i % n) % n + 1)
+            .collect();
+        let state = Sudoku::new(n, cells).initial_state(|| 0.5);
+        for (mode, threshold) in modes() {
+            parallel::set_block_threshold(threshold);
+            group.bench_with_input(BenchmarkId::new(mode, n), &n, |b, _| {
+                b.iter(|| black_box(divide_projector(state.clone()).unwrap()))
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_assignment(c: &mut Criterion) {
+    let mut group = c.benchmark_group("latin_square_assignment");
+    for n in [8, 16, 32, 64] {
+        let projector = AssignmentProjector::new(n);
+        let values = VecState((0..2 * n * n * n).map(|i| (i % 11) as f32).collect());
+        for (mode, threshold) in modes() {
+            parallel::set_block_threshold(threshold);
+            group.bench_with_input(BenchmarkId::new(mode, n), &n, |b, _| {
+                b.iter(|| black_box(projector.project(values.clone()).unwrap()))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_vec_state,
+    bench_aligned_state,
+    bench_norm,
+    bench_divide,
+    bench_sudoku_divide,
+    bench_assignment
+);
+criterion_main!(benches);
@@ -0,0 +1,69 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput};
+use drs::{
+    projectors::{
+        ball::L2BallProjector, box_constraints::BoxProjector,
+        doubly_stochastic::DoublyStochasticProjector, halfspace::HalfspaceProjector,
+        one_hot::OneHotProjector, permutation::PermutationProjector, simplex::SimplexProjector,
+        sparsity::SparsityProjector,
+    },
+    Projector,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::hint::black_box;
+
+const SIZES: [usize; 4] = [64, 1 << 10, 1 << 14, 1 << 18];
+
+// Square sides for the matrix projectors, whose inputs hold n^2 values.
+const SIDES: [usize; 4] = [8, 16, 32, 64];
+
+fn random(n: usize) -> Vec<f32> {
+    let mut rng = StdRng::seed_from_u64(n as u64);
+    (0..n).map(|_| rng.gen_range(-1.0..1.0)).collect()
+}
+
+// Each call gets a fresh copy of the input, outside the timing, since the
+// projectors work in place.
+fn bench<P>(c: &mut Criterion, name: &str, sizes: &[usize], projector: impl Fn(usize) -> P)
+where
+    P: Projector<Vec<f32>>,
+{
+    let mut group = c.benchmark_group(name);
+    for &n in sizes {
+        let input = random(n);
+        let projector = projector(n);
+        group.throughput(Throughput::Elements(n as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, _| {
+            b.iter_batched(
+                || input.clone(),
+                |x| black_box(projector.project(x).unwrap()),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn bench_vector(c: &mut Criterion) {
+    bench(c, "box", &SIZES, |_| BoxProjector::new(0.0, 1.0));
+    bench(c, "l2_ball", &SIZES, |_| L2BallProjector::new(1.0));
+    bench(c, "halfspace", &SIZES, |n| {
+        HalfspaceProjector::new(vec![1.0; n], 0.5)
+    });
+    bench(c, "simplex", &SIZES, |_| SimplexProjector::new(1.0));
+    bench(c, "one_hot", &SIZES, |_| OneHotProjector::new());
+    bench(c, "sparsity", &SIZES, |n| SparsityProjector::new(n / 16));
+}
+
+fn bench_matrix(c: &mut Criterion) {
+    let sizes = SIDES.map(|n| n * n);
+    let side = |len: usize| (len as f32).sqrt() as usize;
+    bench(c, "permutation", &sizes, |len| {
+        PermutationProjector::new(side(len))
+    });
+    bench(c, "doubly_stochastic", &sizes, |len| {
+        DoublyStochasticProjector::new(side(len))
+    });
+}
+
+criterion_group!(benches, bench_vector, bench_matrix);
+criterion_main!(benches);
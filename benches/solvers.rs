@@ -0,0 +1,114 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use drs::{
+    problems::{
+        n_queens::NQueens,
+        sat::{Cnf, SatProblem},
+        sudoku::Sudoku,
+    },
+    Solver,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::hint::black_box;
+
+const BETAS: [f32; 3] = [0.5, 0.9, 1.0];
+
+// The puzzle from the sudoku example.
+const SUDOKU: &str = "
+    7 _ _ | _ _ _ | _ 5 9
+    2 _ 6 | _ _ _ | _ _ _
+    4 _ _ | 8 _ _ | _ _ 1
+    ------+-------+------
+    _ 3 _ | _ _ _ | 9 6 _
+    _ _ _ | _ 4 _ | _ 3 _
+    _ _ _ | _ _ 5 | 7 _ _
+    ------+-------+------
+    _ _ _ | _ 2 _ | 8 _ _
+    _ 8 _ | 1 _ 6 | _ _ _
+    _ _ 5 | _ _ 3 | _ 2 _
+";
+
+// Whole runs from a fixed seed, so each variant's time includes however many
+// steps it needs. A run that hits the step limit is timed all the same.
+fn bench_sudoku(c: &mut Criterion) {
+    let puzzle: Sudoku = SUDOKU.parse().unwrap();
+    let mut group = c.benchmark_group("sudoku");
+    group.sample_size(10);
+    for beta in BETAS {
+        let solver = Sudoku::solver(beta, 1.0, 100_000);
+        group.bench_with_input(BenchmarkId::new("beta", beta), &beta, |b, _| {
+            b.iter(|| {
+                let mut rng = StdRng::seed_from_u64(0);
+                let state = puzzle.initial_state(|| rng.gen());
+                black_box(solver.run(state))
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_n_queens(c: &mut Criterion) {
+    let mut group = c.benchmark_group("n_queens");
+    group.sample_size(10);
+    for n in [8, 16] {
+        let queens = NQueens::new(n);
+        let mut rng = StdRng::seed_from_u64(0);
+        let values: Vec<f32> = (0..n * n).map(|_| rng.gen()).collect();
+        for beta in BETAS {
+            let solver = queens.solver(beta, 1e-3, 20_000);
+            let id = BenchmarkId::new(format!("beta_{beta}"), n);
+            group.bench_with_input(id, &n, |b, _| {
+                b.iter(|| black_box(solver.run(queens.initial_state(&values).unwrap())))
+            });
+        }
+    }
+    group.finish();
+}
+
+// A random 3-SAT formula at clause ratio 4, kept satisfiable by planting an
+// assignment every clause must agree with.
+fn planted_3sat(n: usize, rng: &mut StdRng) -> Cnf {
+    let planted: Vec<bool> = (0..n).map(|_| rng.gen()).collect();
+    let clauses = (0..4 * n)
+        .map(|_| loop {
+            let clause: Vec<i32> = (0..3)
+                .map(|_| {
+                    let v = rng.gen_range(0..n);
+                    let literal = v as i32 + 1;
+                    if rng.gen() {
+                        literal
+                    } else {
+                        -literal
+                    }
+                })
+                .collect();
+            let satisfied = clause
+                .iter()
+                .any(|&l| planted[l.unsigned_abs() as usize - 1] == (l > 0));
+            if satisfied {
+                break clause;
+            }
+        })
+        .collect();
+    Cnf::new(n, clauses)
+}
+
+fn bench_sat(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sat");
+    group.sample_size(10);
+    for n in [25, 50] {
+        let mut rng = StdRng::seed_from_u64(n as u64);
+        let problem = SatProblem::new(planted_3sat(n, &mut rng));
+        let values: Vec<f32> = (0..n).map(|_| rng.gen_range(-1.0..1.0)).collect();
+        for beta in BETAS {
+            let solver = problem.solver(beta, 1e-3, 20_000);
+            let id = BenchmarkId::new(format!("beta_{beta}"), n);
+            group.bench_with_input(id, &n, |b, _| {
+                b.iter(|| black_box(solver.run(problem.initial_state(&values).unwrap())))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_sudoku, bench_n_queens, bench_sat);
+criterion_main!(benches);
@@ -0,0 +1,92 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use drs::{
+    norms::{euclidean, euclidean_bounded, euclidean_compensated, euclidean_contiguous},
+    projectors::{ball::L2BallProjector, box_constraints::BoxProjector},
+    solvers::{
+        divide_and_concur::{step, step_mut, step_with},
+        workspace::StepWorkspace,
+    },
+    states::vector::VecState,
+};
+use std::hint::black_box;
+
+const SIZES: [usize; 5] = [1 << 8, 1 << 12, 1 << 16, 1 << 18, 1 << 20];
+
+// Cheap linear-time projectors, so the numbers are the iteration core's own:
+// the arithmetic, the copies and the workspace.
+fn projectors() -> (BoxProjector, L2BallProjector) {
+    (BoxProjector::new(0.0, 1.0), L2BallProjector::new(8.0))
+}
+
+fn start(n: usize) -> VecState {
+    VecState((0..n).map(|i| (i % 13) as f32 / 6.0 - 1.0).collect())
+}
+
+fn bench_step(c: &mut Criterion) {
+    let (divide, concur) = projectors();
+    let mut group = c.benchmark_group("step");
+    for n in SIZES {
+        group.throughput(Throughput::Elements(n as u64));
+        let state = start(n);
+
+        group.bench_with_input(BenchmarkId::new("step", n), &n, |b, _| {
+            b.iter(|| black_box(step(state.clone(), &divide, &concur, 0.9).unwrap()))
+        });
+
+        let mut workspace = StepWorkspace::new();
+        group.bench_with_input(BenchmarkId::new("step_with", n), &n, |b, _| {
+            b.iter(|| {
+                let update = step_with(
+                    workspace.copy(&state),
+                    &divide,
+                    &concur,
+                    0.9,
+                    &mut workspace,
+                );
+                workspace.recycle(black_box(update.unwrap()));
+            })
+        });
+
+        let mut current = state.clone();
+        let mut workspace = StepWorkspace::new();
+        group.bench_with_input(BenchmarkId::new("step_mut", n), &n, |b, _| {
+            b.iter(|| {
+                step_mut(
+                    black_box(&mut current),
+                    &divide,
+                    &concur,
+                    0.9,
+                    &mut workspace,
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+// The bounded norm is given a bound the distance passes early, as on a step
+// far from convergence.
+fn bench_norm(c: &mut Criterion) {
+    let mut group = c.benchmark_group("norm");
+    for n in SIZES {
+        group.throughput(Throughput::Elements(n as u64));
+        let (x, y) = (start(n), VecState(vec![0.0; n]));
+
+        group.bench_with_input(BenchmarkId::new("euclidean", n), &n, |b, _| {
+            b.iter(|| black_box(euclidean(&x, &y)))
+        });
+        group.bench_with_input(BenchmarkId::new("contiguous", n), &n, |b, _| {
+            b.iter(|| black_box(euclidean_contiguous(&x, &y)))
+        });
+        group.bench_with_input(BenchmarkId::new("compensated", n), &n, |b, _| {
+            b.iter(|| black_box(euclidean_compensated(&x, &y)))
+        });
+        group.bench_with_input(BenchmarkId::new("bounded", n), &n, |b, _| {
+            b.iter(|| black_box(euclidean_bounded(&x, &y, 1.0)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_step, bench_norm);
+criterion_main!(benches);
@@ -0,0 +1,14 @@
+fn main() {
+    // The gRPC service is generated from `proto/drs.proto`, compiled with protox
+    // so that building doesn't need protoc installed.
+    #[cfg(feature = "grpc")]
+    {
+        println!("cargo:rerun-if-changed=proto/drs.proto");
+        let descriptors =
+            protox::compile(["proto/drs.proto"], ["proto"]).expect("failed to compile proto");
+        tonic_prost_build::configure()
+            .build_client(false)
+            .compile_fds(descriptors)
+            .expect("failed to generate gRPC service");
+    }
+}
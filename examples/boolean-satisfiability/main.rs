@@ -1,21 +1,13 @@
-#![allow(unused_imports, unused_variables, dead_code)]
-
-mod projectors;
-mod states;
-
-use crate::projectors::{concur_projector, divide_projector, norm};
-use crate::states::{Clause, SatState};
-use drs::prelude::{DivideAndConcurSolver, Result, Solver};
+use drs::prelude::{Assignment, Result, SatProblem, Solver};
 use rand::prelude::*;
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
-const NVARS: usize = 2;
-const INDICES: [[usize; 3]; 3] = [[0, 0, 1], [0, 1, 1], [0, 1, 1]];
-const NEGATINGS: [[bool; 3]; 3] = [
-    [false, false, false],
-    [true, true, true],
-    [true, false, false],
-];
+// Used when no DIMACS file is given on the command line.
+const INSTANCE: &str = "p cnf 2 3
+1 1 2 0
+-1 -2 -2 0
+-1 2 2 0
+";
 
 fn main() -> Result<()> {
     tracing_subscriber::registry()
@@ -23,25 +15,22 @@ fn main() -> Result<()> {
         .with(EnvFilter::from_default_env())
         .init();
 
-    let states = create_sat_instance();
-    let solver =
-        DivideAndConcurSolver::new(divide_projector, concur_projector, norm, 1.0, 0.4, 1000);
-    let (states, steps, delta) = solver.run(states)?;
+    let input = match std::env::args().nth(1) {
+        Some(path) => std::fs::read_to_string(path).expect("readable DIMACS file"),
+        None => INSTANCE.to_string(),
+    };
 
-    println!("Solved in {steps} steps, with delta={delta}");
-    let solutions = states.solution();
-    for (i, x) in solutions.into_iter().enumerate() {
-        println!("var #{i} = {x}");
-    }
+    let problem = SatProblem::from_dimacs(&input)?;
+    let mut rng = thread_rng();
+    let values: Vec<f32> = (0..problem.cnf().n_variables())
+        .map(|_| rng.gen_range(-1.0..1.0))
+        .collect();
 
-    Ok(())
-}
+    let solver = problem.solver(1.0, 0.4, 1000);
+    let (state, steps, delta) = solver.run(problem.initial_state(&values)?)?;
 
-fn create_sat_instance() -> SatState {
-    let mut rng = thread_rng();
-    let vars: [f32; 2] = rng.gen();
-    let indices: Vec<Vec<usize>> = INDICES.iter().map(Vec::from).collect();
-    let negations: Vec<Vec<bool>> = NEGATINGS.iter().map(Vec::from).collect();
+    println!("Solved in {steps} steps, with delta={delta}");
+    print!("{}", Assignment::from(problem.decode(&state)?));
 
-    SatState::new(Vec::from(vars), indices, negations)
+    Ok(())
 }
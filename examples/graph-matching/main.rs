@@ -0,0 +1,139 @@
+// Align two graphs: find a permutation matrix P with B P = P A, i.e. a relabeling
+// of the vertices of A that turns it into B. Divide rounds P to the nearest
+// permutation matrix and concur projects onto the affine set where B P = P A and
+// every row and column of P sums to one.
+use drs::prelude::{
+    euclidean_contiguous, AffineProjector, DivideAndConcurSolver, PermutationProjector, Result,
+    Solver, VecState,
+};
+use nalgebra::{DMatrix, DVector};
+use rand::prelude::*;
+
+// Linear equations over the row-major entries of P. They are heavily redundant,
+// so keep an orthonormal basis of their row space to give AffineProjector the
+// full row rank it needs.
+fn adjacency_constraints(n: usize, a: &[f32], b: &[f32]) -> Result<AffineProjector> {
+    let mut rows: Vec<(Vec<f32>, f32)> = Vec::new();
+    for i in 0..n {
+        for j in 0..n {
+            // (B P - P A)[i][j] = 0
+            let mut row = vec![0.0; n * n];
+            for k in 0..n {
+                row[k * n + j] += b[i * n + k];
+                row[i * n + k] -= a[k * n + j];
+            }
+            rows.push((row, 0.0));
+        }
+    }
+    for i in 0..n {
+        let mut row = vec![0.0; n * n];
+        (0..n).for_each(|j| row[i * n + j] = 1.0);
+        rows.push((row, 1.0));
+
+        let mut column = vec![0.0; n * n];
+        (0..n).for_each(|j| column[j * n + i] = 1.0);
+        rows.push((column, 1.0));
+    }
+
+    let m = DMatrix::from_fn(rows.len(), n * n, |r, c| rows[r].0[c]);
+    let rhs = DVector::from_fn(rows.len(), |r, _| rows[r].1);
+    let svd = m.svd(true, true);
+    let (Some(u), Some(v_t)) = (svd.u, svd.v_t) else {
+        unreachable!("both factors were requested");
+    };
+    let rank: Vec<usize> = (0..svd.singular_values.len())
+        .filter(|&r| svd.singular_values[r] > 1e-3)
+        .collect();
+
+    let basis = DMatrix::from_fn(rank.len(), n * n, |r, c| v_t[(rank[r], c)]);
+    let target = DVector::from_fn(rank.len(), |r, _| {
+        u.column(rank[r]).dot(&rhs) / svd.singular_values[rank[r]]
+    });
+    AffineProjector::new(basis, target)
+}
+
+// The relabeled adjacency matrix P A P^T, read off a permutation matrix.
+fn relabel(n: usize, a: &[f32], p: &[f32]) -> Vec<f32> {
+    let mut image = vec![0; n];
+    for i in 0..n {
+        for j in 0..n {
+            if p[i * n + j] >= 0.5 {
+                image[j] = i;
+            }
+        }
+    }
+
+    let mut output = vec![0.0; n * n];
+    for u in 0..n {
+        for v in 0..n {
+            output[image[u] * n + image[v]] = a[u * n + v];
+        }
+    }
+    output
+}
+
+fn mismatched_edges(b: &[f32], relabeled: &[f32]) -> usize {
+    b.iter().zip(relabeled).filter(|(x, y)| x != y).count() / 2
+}
+
+fn main() -> Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let mut args = std::env::args().skip(1);
+    let n: usize = args
+        .next()
+        .map(|arg| arg.parse().expect("number of vertices as an integer"))
+        .unwrap_or(10);
+    let density: f32 = args
+        .next()
+        .map(|arg| arg.parse().expect("edge probability as a number"))
+        .unwrap_or(0.3);
+
+    // A random graph A, and B the same graph with its vertices shuffled.
+    let mut rng = thread_rng();
+    let mut a = vec![0.0; n * n];
+    for u in 0..n {
+        for v in u + 1..n {
+            if rng.gen::<f32>() < density {
+                a[u * n + v] = 1.0;
+                a[v * n + u] = 1.0;
+            }
+        }
+    }
+    let mut shuffle: Vec<usize> = (0..n).collect();
+    shuffle.shuffle(&mut rng);
+    let mut truth = vec![0.0; n * n];
+    shuffle
+        .iter()
+        .enumerate()
+        .for_each(|(u, &v)| truth[v * n + u] = 1.0);
+    let b = relabel(n, &a, &truth);
+
+    let solver = DivideAndConcurSolver::new(
+        PermutationProjector::new(n),
+        adjacency_constraints(n, &a, &b)?,
+        euclidean_contiguous,
+        0.5,
+        1e-4,
+        20000,
+    )
+    .with_feasibility({
+        let (a, b) = (a.clone(), b.clone());
+        move |state: &VecState| mismatched_edges(&b, &relabel(n, &a, state)) as f32
+    });
+
+    let values: Vec<f32> = (0..n * n).map(|_| rng.gen()).collect();
+    let (p, steps, delta) = solver.run(VecState(values))?;
+
+    println!("Solved in {steps} steps, with delta={delta}");
+    println!(
+        "Mismatched edges: {}",
+        mismatched_edges(&b, &relabel(n, &a, &p))
+    );
+    for j in 0..n {
+        let i = (0..n).position(|i| p[i * n + j] == 1.0).unwrap_or(j);
+        println!("A:{j} -> B:{i} (planted B:{})", shuffle[j]);
+    }
+
+    Ok(())
+}
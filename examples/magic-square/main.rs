@@ -0,0 +1,29 @@
+use drs::prelude::{MagicSquare, Result, Solver};
+use rand::prelude::*;
+
+fn main() -> Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let n: usize = std::env::args()
+        .nth(1)
+        .map(|arg| arg.parse().expect("square size as an integer"))
+        .unwrap_or(4);
+
+    let square = MagicSquare::new(n);
+    let mut rng = thread_rng();
+    let values: Vec<f32> = (0..n * n)
+        .map(|_| rng.gen_range(1.0..=(n * n) as f32))
+        .collect();
+
+    let solver = square.solver(1.0, 1e-3, 100000);
+    let (state, steps, delta) = solver.run(square.initial_state(&values)?)?;
+
+    println!("Solved in {steps} steps, with delta={delta}");
+    let width = (n * n).to_string().len();
+    for row in square.decode(&state)?.chunks(n) {
+        let row: Vec<String> = row.iter().map(|v| format!("{v:>width$}")).collect();
+        println!("{}", row.join(" "));
+    }
+
+    Ok(())
+}
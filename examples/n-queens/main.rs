@@ -0,0 +1,26 @@
+use drs::prelude::{NQueens, Result, Solver};
+use rand::prelude::*;
+
+fn main() -> Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let n: usize = std::env::args()
+        .nth(1)
+        .map(|arg| arg.parse().expect("board size as an integer"))
+        .unwrap_or(8);
+
+    let queens = NQueens::new(n);
+    let mut rng = thread_rng();
+    let values: Vec<f32> = (0..n * n).map(|_| rng.gen()).collect();
+
+    let solver = queens.solver(1.0, 1e-3, 100000);
+    let (state, steps, delta) = solver.run(queens.initial_state(&values)?)?;
+
+    println!("Solved in {steps} steps, with delta={delta}");
+    for c in queens.decode(&state)? {
+        let row: Vec<&str> = (0..n).map(|j| if j == c { "Q" } else { "." }).collect();
+        println!("{}", row.join(" "));
+    }
+
+    Ok(())
+}
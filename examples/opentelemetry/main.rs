@@ -0,0 +1,40 @@
+use drs::prelude::{Result, Solver, Sudoku, Telemetry};
+use rand::prelude::*;
+
+// Start a collector first, for example Jaeger with OTLP enabled:
+//
+//   docker run --rm -p 16686:16686 -p 4318:4318 jaegertracing/all-in-one
+//
+// then run with `RUST_LOG=info` and open http://localhost:16686 to browse one
+// trace per solve, each step broken down into divide, concur and norm.
+const PUZZLE: &str = "
+    7 _ _ | _ _ _ | _ 5 9
+    2 _ 6 | _ _ _ | _ _ _
+    4 _ _ | 8 _ _ | _ _ 1
+    ------+-------+------
+    _ 3 _ | _ _ _ | 9 6 _
+    _ _ _ | _ 4 _ | _ 3 _
+    _ _ _ | _ _ 5 | 7 _ _
+    ------+-------+------
+    _ _ _ | _ 2 _ | 8 _ _
+    _ 8 _ | 1 _ 6 | _ _ _
+    _ _ 5 | _ _ 3 | _ 2 _
+";
+
+fn main() -> Result<()> {
+    let endpoint = std::env::var("OTEL_EXPORTER_OTLP_TRACES_ENDPOINT")
+        .unwrap_or_else(|_| "http://localhost:4318/v1/traces".to_string());
+    let _telemetry = Telemetry::init("drs-sudoku", &endpoint)?;
+
+    let puzzle: Sudoku = PUZZLE.parse()?;
+    let mut rng = thread_rng();
+    let solver = Sudoku::solver(0.9, 1.0, 100000);
+
+    for run in 0..3 {
+        let state = puzzle.initial_state(|| rng.gen());
+        let (_, steps, delta) = solver.run(state)?;
+        println!("Run {run}: solved in {steps} steps, with delta={delta}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use drs::prelude::{PhaseRetrieval, Result, Solver};
+use drs::problems::phase_retrieval::reconstruction_error;
+use rand::prelude::*;
+
+const SHADES: &[u8] = b" .:-=+*#%@";
+
+fn main() -> Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let size: usize = std::env::args()
+        .nth(1)
+        .map(|arg| arg.parse().expect("object size as an integer"))
+        .unwrap_or(12);
+
+    // Oversample by two in each direction so the phases are determined.
+    let n = 2 * size;
+    let (problem, object) = PhaseRetrieval::synthetic(n, n, size, size, 0);
+
+    let mut rng = thread_rng();
+    let values: Vec<f32> = (0..n * n).map(|_| rng.gen()).collect();
+
+    let solver = problem.solver(0.9, 1e-5, 100000);
+    let (state, steps, delta) = solver.run(problem.initial_state(&values)?)?;
+    let estimate = problem.decode(&state)?;
+
+    println!("Solved in {steps} steps, with delta={delta}");
+    println!("Fourier error: {}", problem.fourier_error(&estimate)?);
+    println!(
+        "Reconstruction error: {}",
+        reconstruction_error(&estimate, &object, n, n)?
+    );
+    for row in estimate.chunks(n).take(size) {
+        let line: String = row[..size]
+            .iter()
+            .map(|x| {
+                let i = (x.clamp(0.0, 1.0) * (SHADES.len() - 1) as f32).round() as usize;
+                SHADES[i] as char
+            })
+            .collect();
+        println!("{line}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,179 @@
+// Fold an HP-model protein on a w x w patch of the square lattice. Residue i
+// placed on site p is a 0/1 variable; consecutive residues must occupy
+// neighbouring sites, every site holds at most one residue (self-avoidance),
+// and the hydrophobic (H) residues are confined to a central core, which
+// pushes them into contact. Runs that fail to converge are restarted from a
+// fresh random state, and the fold with the most H-H contacts is kept.
+use drs::prelude::{
+    ConstraintProblem, Error, OneHotProjector, ProblemState, Result, Solver, Variable, VecState,
+};
+use rand::prelude::*;
+use std::sync::Arc;
+
+fn adjacent(w: usize, p: usize, q: usize) -> bool {
+    let (pr, pc, qr, qc) = (p / w, p % w, q / w, q % w);
+    pr.abs_diff(qr) + pc.abs_diff(qc) == 1
+}
+
+// Projects the site indicators of two bonded residues, stored back to back, onto
+// the pairs of one-hot vectors at neighbouring sites: the nearest such pair
+// maximises u_p + v_q.
+fn bonded(w: usize) -> impl Fn(VecState) -> Result<VecState> + Send + Sync + 'static {
+    move |mut state: VecState| {
+        let sites = w * w;
+        let (u, v) = state.split_at(sites);
+        let (p, q) = (0..sites)
+            .flat_map(|p| {
+                (0..sites)
+                    .filter(move |&q| adjacent(w, p, q))
+                    .map(move |q| (p, q))
+            })
+            .max_by(|&(p1, q1), &(p2, q2)| (u[p1] + v[q1]).total_cmp(&(u[p2] + v[q2])))
+            .expect("a lattice with at least two sites");
+
+        state.iter_mut().for_each(|x| *x = 0.0);
+        state[p] = 1.0;
+        state[sites + q] = 1.0;
+        Ok(state)
+    }
+}
+
+// The site of each residue.
+fn fold(n: usize, sites: usize, values: &[f32]) -> Vec<usize> {
+    values
+        .chunks(sites)
+        .take(n)
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map_or(0, |(p, _)| p)
+        })
+        .collect()
+}
+
+// Broken bonds plus pairs of residues sharing a site.
+fn violations(w: usize, fold: &[usize]) -> usize {
+    let broken = fold.windows(2).filter(|b| !adjacent(w, b[0], b[1])).count();
+    let mut clashes = 0;
+    for (i, p) in fold.iter().enumerate() {
+        clashes += fold[i + 1..].iter().filter(|&q| p == q).count();
+    }
+    broken + clashes
+}
+
+// Minus the number of H-H pairs on neighbouring sites that are not bonded.
+fn energy(w: usize, sequence: &[bool], fold: &[usize]) -> i32 {
+    let mut contacts = 0;
+    for i in 0..fold.len() {
+        for j in i + 2..fold.len() {
+            if sequence[i] && sequence[j] && adjacent(w, fold[i], fold[j]) {
+                contacts += 1;
+            }
+        }
+    }
+    -contacts
+}
+
+fn main() -> Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let mut args = std::env::args().skip(1);
+    let sequence: Vec<bool> = args
+        .next()
+        .unwrap_or_else(|| "HPHPPHHPHPPHPHHPPHPH".to_string())
+        .chars()
+        .map(|c| match c {
+            'H' | 'h' => true,
+            'P' | 'p' => false,
+            _ => panic!("sequence of H and P residues"),
+        })
+        .collect();
+    let restarts: usize = args
+        .next()
+        .map(|arg| arg.parse().expect("number of restarts as an integer"))
+        .unwrap_or(10);
+
+    let n = sequence.len();
+    if n < 2 {
+        panic!("invalid sequence: expected at least 2 residues, got {n}");
+    }
+    let w = (n as f32).sqrt().ceil() as usize + 1;
+    let sites = w * w;
+    // The core trims the border of the patch, when that still leaves room for
+    // every H residue.
+    let h = sequence.iter().filter(|&&x| x).count();
+    let inner = |x: usize| (1..w - 1).contains(&x);
+    let core: Vec<usize> = match (w - 2) * (w - 2) >= h {
+        true => (0..sites)
+            .filter(|&p| inner(p / w) && inner(p % w))
+            .collect(),
+        false => (0..sites).collect(),
+    };
+
+    let mut problem = ConstraintProblem::new();
+    let residues: Vec<Vec<Variable>> = (0..n).map(|_| problem.variables(sites)).collect();
+    for pair in residues.windows(2) {
+        problem.constrain(&[pair[0].clone(), pair[1].clone()].concat(), bonded(w));
+    }
+    for p in 0..sites {
+        let site: Vec<Variable> = residues.iter().map(|r| r[p]).collect();
+        problem.constrain(&site, OneHotProjector::at_most_one());
+    }
+    for (residue, _) in residues.iter().zip(&sequence).filter(|(_, &h)| h) {
+        let allowed: Vec<Variable> = core.iter().map(|&p| residue[p]).collect();
+        problem.constrain(&allowed, OneHotProjector::new());
+    }
+    let problem = Arc::new(problem);
+
+    let feasibility = {
+        let problem = Arc::clone(&problem);
+        move |state: &ProblemState| match problem.decode(state) {
+            Ok(values) => violations(w, &fold(n, sites, &values)) as f32,
+            Err(_) => f32::INFINITY,
+        }
+    };
+    let solver = problem
+        .solver(1.0, 1e-3, 20000)
+        .with_feasibility(feasibility);
+
+    let mut rng = thread_rng();
+    let mut best: Option<(i32, Vec<usize>)> = None;
+    for restart in 0..restarts {
+        let values: Vec<f32> = (0..n * sites).map(|_| rng.gen()).collect();
+        let state = match solver.run(problem.initial_state(&values)?) {
+            Ok((state, _, _)) => state,
+            Err(Error::Convergence(steps, delta)) => {
+                println!("Restart {restart}: no fold after {steps} steps (delta={delta})");
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+
+        let fold = fold(n, sites, &problem.decode(&state)?);
+        let e = energy(w, &sequence, &fold);
+        println!("Restart {restart}: fold with energy {e}");
+        if best.as_ref().is_none_or(|(b, _)| e < *b) {
+            best = Some((e, fold));
+        }
+    }
+
+    let Some((e, fold)) = best else {
+        println!("No fold found");
+        return Ok(());
+    };
+
+    println!("Best energy: {e}");
+    for row in 0..w {
+        let line: String = (0..w)
+            .map(|c| match fold.iter().position(|&p| p == row * w + c) {
+                Some(i) if sequence[i] => 'H',
+                Some(_) => 'P',
+                None => '.',
+            })
+            .collect();
+        println!("{line}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,201 @@
+// The quadratic assignment problem asks for the placement of n facilities at n
+// locations minimizing sum_{i, j} flow[i][j] distance[p(i)][p(j)]. Rather than
+// minimizing directly, we ask a sequence of feasibility questions: "is there an
+// assignment with cost <= T?", lowering T after every yes.
+//
+// The cost is quadratic in the permutation matrix P but linear in the lifted
+// matrix Y = vec(P) vec(P)^T, so the state is Y: divide rounds it to the lifted
+// matrix of a nearby permutation, and concur projects onto the halfspace
+// <flows (x) distances, Y> <= T. Every divide output is a valid assignment, so
+// each question is answered yes as soon as one meets the threshold, without
+// waiting for a fixed point, and no after a fixed budget of steps.
+use drs::prelude::{
+    divide_and_concur_candidate, divide_and_concur_step_with, Error, HalfspaceProjector,
+    PermutationProjector, Projector, Result, StepWorkspace, VecState,
+};
+use rand::prelude::*;
+
+// An approximate projection onto the lifted permutation matrices: rounds the
+// marginals of Y, which for a lifted permutation matrix are 2n P, to a
+// permutation matrix P, polishes it with swaps, and returns vec(P) vec(P)^T.
+#[derive(Clone, Copy)]
+struct LiftedPermutationProjector {
+    n: usize,
+    permutation: PermutationProjector,
+}
+
+impl LiftedPermutationProjector {
+    fn new(n: usize) -> Self {
+        Self {
+            n,
+            permutation: PermutationProjector::new(n),
+        }
+    }
+
+    // The location of every facility: the assignment of the marginals, refined
+    // by pairwise swaps while they increase <Y, vec(P) vec(P)^T>.
+    fn assign(&self, y: &[f32]) -> Result<Vec<usize>> {
+        let (n, m) = (self.n, self.n * self.n);
+        let marginals: Vec<f32> = (0..m)
+            .map(|a| (0..m).map(|b| y[a * m + b] + y[b * m + a]).sum::<f32>())
+            .collect();
+        let mut placement = self.permutation.assign(&marginals)?;
+
+        let score = |placement: &[usize]| -> f32 {
+            let cells: Vec<usize> = (0..n).map(|i| i * n + placement[i]).collect();
+            cells
+                .iter()
+                .flat_map(|&a| cells.iter().map(move |&b| y[a * m + b]))
+                .sum()
+        };
+        let mut current = score(&placement);
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for i in 0..n {
+                for j in i + 1..n {
+                    placement.swap(i, j);
+                    let swapped = score(&placement);
+                    if swapped > current + 1e-6 {
+                        current = swapped;
+                        improved = true;
+                    } else {
+                        placement.swap(i, j);
+                    }
+                }
+            }
+        }
+        Ok(placement)
+    }
+}
+
+impl Projector<VecState> for LiftedPermutationProjector {
+    fn project(&self, mut state: VecState) -> Result<VecState> {
+        let (n, m) = (self.n, self.n * self.n);
+        if state.len() != m * m {
+            return Err(Error::Dimension(m * m, state.len()));
+        }
+
+        let placement = self.assign(&state)?;
+        state.iter_mut().for_each(|y| *y = 0.0);
+        for (i, &k) in placement.iter().enumerate() {
+            for (j, &l) in placement.iter().enumerate() {
+                state[(i * n + k) * m + j * n + l] = 1.0;
+            }
+        }
+        Ok(state)
+    }
+}
+
+fn cost(n: usize, flows: &[f32], distances: &[f32], placement: &[usize]) -> f32 {
+    (0..n)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .map(|(i, j)| flows[i * n + j] * distances[placement[i] * n + placement[j]])
+        .sum()
+}
+
+// <flows (x) distances, Y> <= threshold, with Y indexed by (i k, j l).
+fn cost_halfspace(
+    n: usize,
+    flows: &[f32],
+    distances: &[f32],
+    threshold: f32,
+) -> HalfspaceProjector {
+    let m = n * n;
+    let mut normal = vec![0.0; m * m];
+    for (a, b) in (0..m).flat_map(|a| (0..m).map(move |b| (a, b))) {
+        let (i, k, j, l) = (a / n, a % n, b / n, b % n);
+        normal[a * m + b] = flows[i * n + j] * distances[k * n + l];
+    }
+    HalfspaceProjector::new(normal, threshold)
+}
+
+fn symmetric(n: usize, mut entry: impl FnMut(usize, usize) -> f32) -> Vec<f32> {
+    let mut output = vec![0.0; n * n];
+    for i in 0..n {
+        for j in i + 1..n {
+            output[i * n + j] = entry(i, j);
+            output[j * n + i] = output[i * n + j];
+        }
+    }
+    output
+}
+
+fn main() -> Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let mut args = std::env::args().skip(1);
+    let n: usize = args
+        .next()
+        .map(|arg| arg.parse().expect("number of facilities as an integer"))
+        .unwrap_or(8);
+    let shrink: f32 = args
+        .next()
+        .map(|arg| arg.parse().expect("threshold factor as a number"))
+        .unwrap_or(0.97);
+    let (beta, n_steps) = (0.5, 2000);
+
+    let mut rng = thread_rng();
+    let sites: Vec<(f32, f32)> = (0..n).map(|_| (rng.gen(), rng.gen())).collect();
+    let distances = symmetric(n, |k, l| {
+        (sites[k].0 - sites[l].0).hypot(sites[k].1 - sites[l].1)
+    });
+    let flows = symmetric(n, |_, _| rng.gen_range(0..10) as f32);
+
+    let mut placement: Vec<usize> = (0..n).collect();
+    placement.shuffle(&mut rng);
+    let mut best = cost(n, &flows, &distances, &placement);
+    println!("Random assignment: cost {best:.3}");
+
+    let divide = LiftedPermutationProjector::new(n);
+    let mut failures = 0;
+    while failures < 3 {
+        let threshold = shrink * best;
+        let concur = cost_halfspace(n, &flows, &distances, threshold);
+        let mut state = VecState((0..n.pow(4)).map(|_| rng.gen()).collect());
+        let mut workspace = StepWorkspace::new();
+        let (mut previous, mut stalled) = (Vec::new(), 0);
+        let mut answer = None;
+        for t in 0..n_steps {
+            let candidate = divide_and_concur_candidate(state.clone(), &divide)?;
+            let candidate = divide.assign(&candidate)?;
+            if cost(n, &flows, &distances, &candidate) <= threshold {
+                answer = Some((candidate, t));
+                break;
+            }
+            stalled = if candidate == previous {
+                stalled + 1
+            } else {
+                0
+            };
+            previous = candidate;
+
+            state = divide_and_concur_step_with(state, &divide, &concur, beta, &mut workspace)?;
+            if stalled >= 20 {
+                // Drifting without the candidate changing: kick the state.
+                state
+                    .iter_mut()
+                    .for_each(|y| *y += rng.gen_range(-2.0..2.0));
+                stalled = 0;
+            }
+        }
+
+        match answer {
+            Some((candidate, t)) => {
+                placement = candidate;
+                best = cost(n, &flows, &distances, &placement);
+                println!("Cost <= {threshold:.3}? Yes, {best:.3} after {t} steps");
+                failures = 0;
+            }
+            None => {
+                println!("Cost <= {threshold:.3}? No answer after {n_steps} steps");
+                failures += 1;
+            }
+        }
+    }
+
+    println!("Best assignment: cost {best:.3}");
+    println!("{placement:?}");
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+// Search for a red/blue coloring of the edges of K_n with no monochromatic K_k,
+// i.e. a witness that the Ramsey number R(k, k) exceeds n. Every edge is a +-1
+// variable, and every k-subset of vertices contributes one "not all the same
+// color" constraint over its edges.
+use drs::prelude::{ConstraintProblem, ProblemState, Result, Solver, Variable, VecState};
+use rand::prelude::*;
+use std::sync::Arc;
+
+// Nearest +-1 vector that is not constant: round to signs, and if every edge
+// came out the same color, flip the one closest to the fence.
+fn not_monochromatic(mut state: VecState) -> Result<VecState> {
+    let weakest = state
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.abs().total_cmp(&b.abs()))
+        .map(|(i, _)| i);
+    state.iter_mut().for_each(|x| *x = x.signum());
+    if state.iter().all(|&x| x == state[0]) {
+        if let Some(i) = weakest {
+            state[i] = -state[i];
+        }
+    }
+    Ok(state)
+}
+
+// Every k-subset of 0..n, in lexicographic order.
+fn subsets(n: usize, k: usize) -> Vec<Vec<usize>> {
+    fn walk(
+        start: usize,
+        n: usize,
+        k: usize,
+        current: &mut Vec<usize>,
+        output: &mut Vec<Vec<usize>>,
+    ) {
+        if current.len() == k {
+            output.push(current.clone());
+            return;
+        }
+        for v in start..n {
+            current.push(v);
+            walk(v + 1, n, k, current, output);
+            current.pop();
+        }
+    }
+
+    let mut output = Vec::new();
+    walk(0, n, k, &mut Vec::with_capacity(k), &mut output);
+    output
+}
+
+// Index of edge {u, v} among the n (n - 1) / 2 edges, in row-major upper
+// triangular order.
+fn edge(n: usize, u: usize, v: usize) -> usize {
+    let (u, v) = (u.min(v), u.max(v));
+    u * n - u * (u + 1) / 2 + (v - u - 1)
+}
+
+fn monochromatic(n: usize, cliques: &[Vec<usize>], colors: &[f32]) -> usize {
+    cliques
+        .iter()
+        .filter(|clique| {
+            let mut signs = clique.iter().enumerate().flat_map(|(i, &u)| {
+                clique[i + 1..]
+                    .iter()
+                    .map(move |&v| colors[edge(n, u, v)] >= 0.0)
+            });
+            let first = signs.next();
+            signs.all(|s| Some(s) == first)
+        })
+        .count()
+}
+
+fn main() -> Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let mut args = std::env::args().skip(1);
+    let n: usize = args
+        .next()
+        .map(|arg| arg.parse().expect("number of vertices as an integer"))
+        .unwrap_or(12);
+    let k: usize = args
+        .next()
+        .map(|arg| arg.parse().expect("clique size as an integer"))
+        .unwrap_or(4);
+
+    let mut problem = ConstraintProblem::new();
+    let edges = problem.variables(n * (n - 1) / 2);
+    let cliques = Arc::new(subsets(n, k));
+    for clique in cliques.iter() {
+        let vars: Vec<Variable> = clique
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &u)| clique[i + 1..].iter().map(move |&v| (u, v)))
+            .map(|(u, v)| edges[edge(n, u, v)])
+            .collect();
+        problem.constrain(&vars, not_monochromatic);
+    }
+    let problem = Arc::new(problem);
+
+    let mut rng = thread_rng();
+    let values: Vec<f32> = (0..edges.len()).map(|_| rng.gen_range(-1.0..1.0)).collect();
+
+    let feasibility = {
+        let (problem, cliques) = (Arc::clone(&problem), Arc::clone(&cliques));
+        move |state: &ProblemState| match problem.decode(state) {
+            Ok(colors) => monochromatic(n, &cliques, &colors) as f32,
+            Err(_) => f32::INFINITY,
+        }
+    };
+    let solver = problem
+        .solver(1.0, 1e-3, 100000)
+        .with_feasibility(feasibility);
+    let (state, steps, delta) = solver.run(problem.initial_state(&values)?)?;
+
+    println!("Solved in {steps} steps, with delta={delta}");
+    let colors = problem.decode(&state)?;
+    println!(
+        "Monochromatic K_{k}: {}",
+        monochromatic(n, &cliques, &colors)
+    );
+    for u in 0..n {
+        let row: String = (0..n)
+            .map(|v| match u == v {
+                true => '.',
+                false if colors[edge(n, u, v)] >= 0.0 => 'R',
+                false => 'B',
+            })
+            .collect();
+        println!("{row}");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,190 @@
+// Heuristic tours for the traveling salesman problem. The state is an n x n
+// matrix x[city][position]; divide finds a permutation matrix near x whose tour
+// is no longer than a target length, and concur projects onto the affine set
+// where every row and column sums to one. Whenever divide produces a tour that
+// meets the target, the target is lowered below it, so the iteration keeps
+// chasing shorter tours rather than settling on the first valid one; when it
+// stalls, it restarts from a random state and keeps the best tour seen.
+use drs::prelude::{
+    divide_and_concur_candidate, divide_and_concur_step_with, PermutationProjector, Projector,
+    Result, StepWorkspace, VecState,
+};
+use rand::prelude::*;
+
+// Rounds to the nearest permutation matrix, then greedily applies the 2-opt
+// segment reversal with the best tour shortening per unit of added distance
+// from x, until the tour is at most `target` long or no reversal shortens it.
+struct TourProjector<'a> {
+    n: usize,
+    distances: &'a [f32],
+    target: f32,
+    permutation: PermutationProjector,
+}
+
+impl<'a> TourProjector<'a> {
+    fn new(n: usize, distances: &'a [f32], target: f32) -> Self {
+        Self {
+            n,
+            distances,
+            target,
+            permutation: PermutationProjector::new(n).with_scale(1e4),
+        }
+    }
+}
+
+impl Projector<VecState> for TourProjector<'_> {
+    fn project(&self, mut state: VecState) -> Result<VecState> {
+        let n = self.n;
+        let mut order = vec![0; n];
+        for (city, position) in self.permutation.assign(&state)?.into_iter().enumerate() {
+            order[position] = city;
+        }
+
+        let d =
+            |order: &[usize], a: usize, b: usize| self.distances[order[a % n] * n + order[b % n]];
+        let mut tour_length = length(n, self.distances, &order);
+        while tour_length > self.target {
+            // Reversing positions i..=j swaps edges (i-1, i) and (j, j+1) for
+            // (i-1, j) and (i, j+1), and moves every city in between.
+            let mut best: Option<(usize, usize, f32, f32)> = None;
+            for i in 1..n {
+                for j in i + 1..n {
+                    let gain = d(&order, i - 1, i) + d(&order, j, j + 1)
+                        - d(&order, i - 1, j)
+                        - d(&order, i, j + 1);
+                    if gain <= 1e-6 {
+                        continue;
+                    }
+                    let cost: f32 = (i..=j)
+                        .map(|p| 2.0 * (state[order[p] * n + p] - state[order[i + j - p] * n + p]))
+                        .sum::<f32>()
+                        .max(0.0)
+                        + 1e-6;
+                    if best.is_none_or(|(_, _, g, c)| cost / gain < c / g) {
+                        best = Some((i, j, gain, cost));
+                    }
+                }
+            }
+            let Some((i, j, gain, _)) = best else {
+                break;
+            };
+            order[i..=j].reverse();
+            tour_length -= gain;
+        }
+
+        state.iter_mut().for_each(|x| *x = 0.0);
+        for (position, &city) in order.iter().enumerate() {
+            state[city * n + position] = 1.0;
+        }
+        Ok(state)
+    }
+}
+
+// Euclidean projection onto { x : every row and every column sums to one }.
+fn doubly_stochastic(mut state: VecState) -> Result<VecState> {
+    let n = (state.len() as f32).sqrt().round() as usize;
+    let rows: Vec<f32> = (0..n)
+        .map(|a| (0..n).map(|p| state[a * n + p]).sum())
+        .collect();
+    let columns: Vec<f32> = (0..n)
+        .map(|p| (0..n).map(|a| state[a * n + p]).sum())
+        .collect();
+    let total: f32 = rows.iter().sum();
+    for a in 0..n {
+        for p in 0..n {
+            state[a * n + p] += (1.0 - rows[a]) / n as f32 + (1.0 - columns[p]) / n as f32
+                - (n as f32 - total) / (n * n) as f32;
+        }
+    }
+    Ok(state)
+}
+
+// The cities in visiting order, read off a permutation matrix.
+fn tour(n: usize, state: &VecState) -> Vec<usize> {
+    let mut order = vec![0; n];
+    for a in 0..n {
+        let p = (0..n)
+            .max_by(|&p, &q| state[a * n + p].total_cmp(&state[a * n + q]))
+            .unwrap_or(0);
+        order[p] = a;
+    }
+    order
+}
+
+fn length(n: usize, distances: &[f32], order: &[usize]) -> f32 {
+    (0..n)
+        .map(|p| distances[order[p] * n + order[(p + 1) % n]])
+        .sum()
+}
+
+// Greedy baseline: always visit the closest unvisited city next.
+fn nearest_neighbour(n: usize, distances: &[f32]) -> Vec<usize> {
+    let mut order = vec![0];
+    let mut visited = vec![false; n];
+    visited[0] = true;
+    while order.len() < n {
+        let last = order[order.len() - 1];
+        let next = (0..n)
+            .filter(|&b| !visited[b])
+            .min_by(|&a, &b| distances[last * n + a].total_cmp(&distances[last * n + b]))
+            .unwrap();
+        visited[next] = true;
+        order.push(next);
+    }
+    order
+}
+
+fn main() -> Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let mut args = std::env::args().skip(1);
+    let n: usize = args
+        .next()
+        .map(|arg| arg.parse().expect("number of cities as an integer"))
+        .unwrap_or(20);
+    let n_steps: usize = args
+        .next()
+        .map(|arg| arg.parse().expect("number of steps as an integer"))
+        .unwrap_or(2000);
+    if n < 3 {
+        panic!("invalid number of cities: expected at least 3, got {n}");
+    }
+
+    let mut rng = thread_rng();
+    let cities: Vec<(f32, f32)> = (0..n).map(|_| (rng.gen(), rng.gen())).collect();
+    let distances: Vec<f32> = cities
+        .iter()
+        .flat_map(|a| cities.iter().map(move |b| (a.0 - b.0).hypot(a.1 - b.1)))
+        .collect();
+
+    let greedy = nearest_neighbour(n, &distances);
+    let greedy_length = length(n, &distances, &greedy);
+    println!("Nearest neighbour tour: {greedy_length:.4}");
+
+    let beta = 0.5;
+    let concur = doubly_stochastic;
+    let mut workspace = StepWorkspace::new();
+    let mut state = VecState((0..n * n).map(|_| rng.gen()).collect());
+    let mut best = (greedy_length, greedy, 0);
+    for t in 0..n_steps {
+        let divide = TourProjector::new(n, &distances, 0.99 * best.0);
+        let candidate = divide_and_concur_candidate(state.clone(), &divide)?;
+        let order = tour(n, &candidate);
+        let candidate_length = length(n, &distances, &order);
+        if candidate_length < best.0 {
+            println!("Step {t}: tour of length {candidate_length:.4}");
+            best = (candidate_length, order, t);
+        }
+
+        state = match t - best.2 > 0 && (t - best.2) % 100 == 0 {
+            // No shorter tour for a while: restart from a fresh random state.
+            true => VecState((0..n * n).map(|_| rng.gen()).collect()),
+            false => divide_and_concur_step_with(state, &divide, &concur, beta, &mut workspace)?,
+        };
+    }
+
+    println!("Best tour: {:.4}, found at step {}", best.0, best.2);
+    println!("{:?}", best.1);
+
+    Ok(())
+}
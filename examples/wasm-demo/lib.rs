@@ -0,0 +1,8 @@
+// Build with
+//
+//   cargo build --release --example wasm-demo --features wasm --target wasm32-unknown-unknown
+//   wasm-bindgen --target web --out-dir examples/wasm-demo/pkg \
+//       target/wasm32-unknown-unknown/release/examples/wasm_demo.wasm
+//
+// then serve `examples/wasm-demo` over HTTP and open `index.html`.
+pub use drs::wasm::{WasmSat, WasmSudoku};
@@ -0,0 +1,156 @@
+use crate::{errors::Error, Result};
+use arrow_array::{cast::AsArray, types, Array, ArrayRef, RecordBatch};
+use arrow_ipc::reader::FileReader;
+use arrow_schema::DataType;
+use std::{fs::File, path::Path};
+
+// Measurement and constraint tables from Arrow record batches, for data that
+// starts life in a dataframe. Polars (`DataFrame.write_ipc`), pandas and
+// pyarrow (`feather.write_feather`) all write the IPC files `read_ipc` reads.
+// Columns of any integer or float type are accepted, but not nulls.
+
+pub fn read_ipc(path: impl AsRef<Path>) -> Result<Vec<RecordBatch>> {
+    let file = File::open(path).map_err(|err| Error::Unknown(Box::new(err)))?;
+    FileReader::try_new(file, None)
+        .map_err(unknown)?
+        .map(|batch| batch.map_err(unknown))
+        .collect()
+}
+
+// The named column across every batch, as `f32`.
+pub fn column(batches: &[RecordBatch], name: &str) -> Result<Vec<f32>> {
+    let mut values = Vec::with_capacity(batches.iter().map(RecordBatch::num_rows).sum());
+    for batch in batches {
+        let array = cast(batch, name, &DataType::Float32)?;
+        values.extend(array.as_primitive::<types::Float32Type>().values());
+    }
+    Ok(values)
+}
+
+// The named column across every batch, as indices, so negative values are an
+// error.
+pub fn indices(batches: &[RecordBatch], name: &str) -> Result<Vec<usize>> {
+    let mut values = Vec::with_capacity(batches.iter().map(RecordBatch::num_rows).sum());
+    for batch in batches {
+        let array = cast(batch, name, &DataType::UInt64)?;
+        values.extend(
+            array
+                .as_primitive::<types::UInt64Type>()
+                .values()
+                .iter()
+                .map(|&i| i as usize),
+        );
+    }
+    Ok(values)
+}
+
+// `(row, col, value)` triplets, as `MatrixCompletion` takes its observations.
+pub fn triplets(
+    batches: &[RecordBatch],
+    row: &str,
+    col: &str,
+    value: &str,
+) -> Result<Vec<(usize, usize, f32)>> {
+    let rows = indices(batches, row)?;
+    let cols = indices(batches, col)?;
+    let values = column(batches, value)?;
+
+    Ok(rows
+        .into_iter()
+        .zip(cols)
+        .zip(values)
+        .map(|((r, c), x)| (r, c, x))
+        .collect())
+}
+
+fn cast(batch: &RecordBatch, name: &str, to: &DataType) -> Result<ArrayRef> {
+    let array = batch
+        .column_by_name(name)
+        .ok_or_else(|| Error::Schema(format!("missing column {name}")))?;
+    if !array.data_type().is_numeric() {
+        return Err(Error::Schema(format!(
+            "expected a numeric column {name}, got {}",
+            array.data_type()
+        )));
+    }
+    if array.null_count() > 0 {
+        return Err(Error::Schema(format!(
+            "expected no nulls in column {name}, got {}",
+            array.null_count()
+        )));
+    }
+
+    // Unsafe casts, such as a negative index to `UInt64`, become nulls.
+    let cast = arrow_cast::cast(array, to).map_err(unknown)?;
+    if cast.null_count() > 0 {
+        return Err(Error::Schema(format!(
+            "expected values of column {name} to fit {to}"
+        )));
+    }
+    Ok(cast)
+}
+
+fn unknown(err: arrow_schema::ArrowError) -> Error {
+    Error::Unknown(Box::new(err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Float64Array, Int32Array, Int64Array};
+    use arrow_ipc::writer::FileWriter;
+    use std::sync::Arc;
+
+    fn observations(rows: Vec<i64>, cols: Vec<i32>, values: Vec<f64>) -> RecordBatch {
+        RecordBatch::try_from_iter([
+            ("row", Arc::new(Int64Array::from(rows)) as _),
+            ("col", Arc::new(Int32Array::from(cols)) as _),
+            ("value", Arc::new(Float64Array::from(values)) as _),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_triplets() {
+        let batches = [
+            observations(vec![0, 1], vec![1, 0], vec![0.5, -2.0]),
+            observations(vec![1], vec![1], vec![3.0]),
+        ];
+        let triplets = triplets(&batches, "row", "col", "value").unwrap();
+        assert_eq!(triplets, vec![(0, 1, 0.5), (1, 0, -2.0), (1, 1, 3.0)]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let batches = [observations(vec![0, -1], vec![0, 0], vec![1.0, 2.0])];
+        assert!(matches!(
+            indices(&batches, "row"),
+            Err(Error::Schema(message)) if message.contains("fit")
+        ));
+        assert!(matches!(
+            column(&batches, "weight"),
+            Err(Error::Schema(message)) if message == "missing column weight"
+        ));
+
+        let batch = RecordBatch::try_from_iter([(
+            "value",
+            Arc::new(Float64Array::from(vec![Some(1.0), None])) as _,
+        )])
+        .unwrap();
+        assert!(matches!(column(&[batch], "value"), Err(Error::Schema(_))));
+    }
+
+    #[test]
+    fn test_read_ipc() {
+        let batch = observations(vec![0, 2], vec![1, 1], vec![0.25, 4.0]);
+        let path = std::env::temp_dir().join(format!("drs-arrow-{}.arrow", std::process::id()));
+        let mut writer =
+            FileWriter::try_new(File::create(&path).unwrap(), &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+
+        let batches = read_ipc(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(column(&batches, "value").unwrap(), vec![0.25, 4.0]);
+    }
+}
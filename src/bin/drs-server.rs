@@ -0,0 +1,23 @@
+use drs::grpc::SolveService;
+use std::net::SocketAddr;
+
+// Serves the solve service described in `proto/drs.proto`, on the address
+// given as the first argument:
+//
+//   cargo run --features grpc --bin drs-server -- 0.0.0.0:50051
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let addr: SocketAddr = std::env::args()
+        .nth(1)
+        .as_deref()
+        .unwrap_or("127.0.0.1:50051")
+        .parse()?;
+
+    println!("Serving on {addr}");
+    tonic::transport::Server::builder()
+        .add_service(SolveService::new().into_server())
+        .serve(addr)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,252 @@
+use crate::{
+    config::SolverConfig,
+    errors::Error,
+    snapshot::{read_end, read_len, read_part, write_len, write_part, StateSnapshot},
+    tolerances::Tolerances,
+    Result,
+};
+use alloc::{boxed::Box, format, vec::Vec};
+#[cfg(feature = "std")]
+use std::io::{Read, Write};
+
+const MAGIC: &[u8; 8] = b"DRSCKPT\0";
+const VERSION: u32 = 1;
+
+// Everything needed to pick a run back up with `DivideAndConcurSolver::resume`:
+// the iterate before `step`, and the first delta that relative tolerances and
+// the divergence check measure against.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Checkpoint<S> {
+    pub config: SolverConfig,
+    pub step: usize,
+    pub initial_delta: Option<f32>,
+    pub state: S,
+}
+
+pub type CheckpointSink<S> = Box<dyn Fn(&Checkpoint<S>) + Send + Sync>;
+
+// A compact alternative to serde for large states: `MAGIC`, a little-endian
+// `VERSION`, the config and step fields, then the state's `StateSnapshot`
+// bytes, so a checkpoint costs little more than the raw state.
+impl<S: StateSnapshot> Checkpoint<S> {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let config = &self.config;
+        let mut bytes = Vec::from(&MAGIC[..]);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        for x in [
+            config.beta,
+            config.tolerances.absolute,
+            config.tolerances.relative,
+            config.tolerances.idempotency,
+            config.tolerances.feasibility,
+        ] {
+            bytes.extend_from_slice(&x.to_le_bytes());
+        }
+        write_len(&mut bytes, config.n_steps);
+        write_option(&mut bytes, config.divergence_factor);
+        // One byte of flags: catch_panics, then difference_map.
+        bytes.push(config.catch_panics as u8 | (config.difference_map as u8) << 1);
+        write_len(&mut bytes, self.step);
+        write_option(&mut bytes, self.initial_delta);
+        write_part(&mut bytes, &self.state.snapshot());
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (magic, rest) = take(bytes, MAGIC.len())?;
+        if magic != MAGIC {
+            return Err(Error::Snapshot(format!(
+                "expected checkpoint magic {MAGIC:?}, got {magic:?}"
+            )));
+        }
+        let (version, rest) = take(rest, 4)?;
+        let version = u32::from_le_bytes(version.try_into().unwrap());
+        if version != VERSION {
+            return Err(Error::Snapshot(format!(
+                "expected checkpoint version {VERSION}, got {version}"
+            )));
+        }
+
+        let (beta, rest) = read_f32(rest)?;
+        let (absolute, rest) = read_f32(rest)?;
+        let (relative, rest) = read_f32(rest)?;
+        let (idempotency, rest) = read_f32(rest)?;
+        let (feasibility, rest) = read_f32(rest)?;
+        let (n_steps, rest) = read_len(rest)?;
+        let (divergence_factor, rest) = read_option(rest)?;
+        let (flags, rest) = take(rest, 1)?;
+        let (step, rest) = read_len(rest)?;
+        let (initial_delta, rest) = read_option(rest)?;
+        let (state, rest) = read_part(rest)?;
+        read_end(rest)?;
+
+        Ok(Self {
+            config: SolverConfig {
+                beta,
+                tolerances: Tolerances {
+                    absolute,
+                    relative,
+                    idempotency,
+                    feasibility,
+                },
+                n_steps,
+                divergence_factor,
+                catch_panics: flags[0] & 1 != 0,
+                difference_map: flags[0] & 2 != 0,
+            },
+            step,
+            initial_delta,
+            state: S::restore(state)?,
+        })
+    }
+
+    #[cfg(feature = "std")]
+    pub fn write_to<W: Write>(&self, mut writer: W) -> Result<()> {
+        writer
+            .write_all(&self.to_bytes())
+            .and_then(|_| writer.flush())
+            .map_err(|err| Error::Snapshot(err.to_string()))
+    }
+
+    #[cfg(feature = "std")]
+    pub fn read_from<R: Read>(mut reader: R) -> Result<Self> {
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .map_err(|err| Error::Snapshot(err.to_string()))?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+fn take(bytes: &[u8], n: usize) -> Result<(&[u8], &[u8])> {
+    if bytes.len() < n {
+        return Err(Error::Snapshot(format!(
+            "expected {n} more bytes, got {}",
+            bytes.len()
+        )));
+    }
+
+    Ok(bytes.split_at(n))
+}
+
+fn read_f32(bytes: &[u8]) -> Result<(f32, &[u8])> {
+    let (head, rest) = take(bytes, 4)?;
+    Ok((f32::from_le_bytes(head.try_into().unwrap()), rest))
+}
+
+// A presence byte, then the value, or zeros when absent.
+fn write_option(bytes: &mut Vec<u8>, value: Option<f32>) {
+    bytes.push(value.is_some() as u8);
+    bytes.extend_from_slice(&value.unwrap_or(0.0).to_le_bytes());
+}
+
+fn read_option(bytes: &[u8]) -> Result<(Option<f32>, &[u8])> {
+    let (present, rest) = take(bytes, 1)?;
+    let (value, rest) = read_f32(rest)?;
+    Ok((if present[0] != 0 { Some(value) } else { None }, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        norms::euclidean_contiguous,
+        projectors::{box_constraints::BoxProjector, hyperplane::HyperplaneProjector},
+        solvers::divide_and_concur::DivideAndConcurSolver,
+        states::vector::VecState,
+        Solver,
+    };
+    use std::sync::{Arc, Mutex};
+
+    type LineSolver = DivideAndConcurSolver<
+        VecState,
+        BoxProjector,
+        HyperplaneProjector,
+        fn(&VecState, &VecState) -> f32,
+    >;
+
+    fn solver() -> LineSolver {
+        DivideAndConcurSolver::new(
+            BoxProjector::new(0.0, 1.0),
+            HyperplaneProjector::new(vec![1.0, 1.0], 1.5),
+            euclidean_contiguous as fn(&VecState, &VecState) -> f32,
+            0.8,
+            1e-6,
+            1000,
+        )
+    }
+
+    #[test]
+    fn test_resume() {
+        let checkpoints: Arc<Mutex<Vec<Checkpoint<VecState>>>> = Arc::default();
+        let sink = Arc::clone(&checkpoints);
+        let initial = VecState(vec![2.0, -1.0]);
+        let full = solver()
+            .with_checkpoints(4, move |checkpoint| {
+                sink.lock().unwrap().push(checkpoint.clone())
+            })
+            .run(initial)
+            .unwrap();
+
+        let checkpoints = checkpoints.lock().unwrap();
+        assert!(!checkpoints.is_empty());
+        assert!(checkpoints.iter().all(|c| c.step % 4 == 0));
+
+        let checkpoint = checkpoints[0].clone();
+        assert_eq!(checkpoint.config, solver().config());
+        let resumed = solver()
+            .with_config(checkpoint.config)
+            .resume(checkpoint)
+            .unwrap();
+        assert_eq!(resumed, full);
+    }
+
+    #[test]
+    fn test_bytes() {
+        let checkpoint = Checkpoint {
+            config: solver()
+                .with_divergence_factor(10.0)
+                .with_difference_map(true)
+                .config(),
+            step: 12,
+            initial_delta: Some(0.5),
+            state: VecState(vec![0.25, 1.25]),
+        };
+        let bytes = checkpoint.to_bytes();
+        assert_eq!(&bytes[..8], MAGIC);
+        assert_eq!(Checkpoint::from_bytes(&bytes).unwrap(), checkpoint);
+
+        let mut stale = bytes.clone();
+        stale[8] = 2;
+        assert!(matches!(
+            Checkpoint::<VecState>::from_bytes(&stale),
+            Err(Error::Snapshot(message)) if message.contains("version")
+        ));
+        assert!(matches!(
+            Checkpoint::<VecState>::from_bytes(&bytes[..40]),
+            Err(Error::Snapshot(_))
+        ));
+
+        let mut trailing = bytes;
+        trailing.extend_from_slice(b"junk");
+        assert!(matches!(
+            Checkpoint::<VecState>::from_bytes(&trailing),
+            Err(Error::Snapshot(_))
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let checkpoint = Checkpoint {
+            config: solver().config(),
+            step: 12,
+            initial_delta: Some(0.5),
+            state: VecState(vec![0.25, 1.25]),
+        };
+        let json = serde_json::to_string(&checkpoint).unwrap();
+        let restored: Checkpoint<VecState> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, checkpoint);
+    }
+}
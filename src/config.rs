@@ -0,0 +1,29 @@
+use crate::tolerances::Tolerances;
+
+// The plain-data settings of a solver, without its projectors or norm, so a run
+// can be described, stored and rebuilt with `with_config`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolverConfig {
+    pub beta: f32,
+    pub tolerances: Tolerances,
+    pub n_steps: usize,
+    pub divergence_factor: Option<f32>,
+    pub catch_panics: bool,
+    // Missing from configs saved before it existed.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub difference_map: bool,
+}
+
+impl SolverConfig {
+    pub fn new(beta: f32, epsilon: f32, n_steps: usize) -> Self {
+        Self {
+            beta,
+            tolerances: Tolerances::new(epsilon),
+            n_steps,
+            divergence_factor: None,
+            catch_panics: false,
+            difference_map: false,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{solution::Solution, states::vector::VecState};
+use alloc::{format, string::String};
+use core::fmt::Write;
+
+// Rich output for notebooks. evcxr renders any value with an inherent
+// `evcxr_display` method, so the types implementing this also have one that
+// forwards here; Jupyter frontends can use `to_html` directly.
+pub trait Html {
+    fn to_html(&self) -> String;
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn evcxr_display(html: &impl Html) {
+    println!(
+        "EVCXR_BEGIN_CONTENT text/html\n{}\nEVCXR_END_CONTENT",
+        html.to_html()
+    );
+}
+
+// Cells shared by every table, so grids line up regardless of the notebook's
+// stylesheet.
+pub(crate) const CELL_STYLE: &str =
+    "padding:2px 6px;text-align:center;font-family:monospace;border:1px solid #ccc";
+
+impl Html for VecState {
+    fn to_html(&self) -> String {
+        let mut html = String::from("<table><tr>");
+        for x in self.iter() {
+            let _ = write!(html, "<td style=\"{CELL_STYLE}\">{x:.4}</td>");
+        }
+        html.push_str("</tr></table>");
+        html
+    }
+}
+
+impl<S: Html> Html for Solution<S> {
+    fn to_html(&self) -> String {
+        format!("{}<p>{}</p>", self.state.to_html(), self.stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solution_html() {
+        let solution = Solution::from((VecState(vec![0.5, -1.0]), 3, 0.25));
+        assert_eq!(
+            solution.to_html(),
+            format!(
+                "<table><tr><td style=\"{CELL_STYLE}\">0.5000</td>\
+                 <td style=\"{CELL_STYLE}\">-1.0000</td></tr></table>\
+                 <p>3 steps, delta=2.500e-1</p>"
+            )
+        );
+    }
+}
@@ -0,0 +1,673 @@
+use crate::{
+    errors::Error,
+    norms::euclidean,
+    problem::{ConstraintProblem, ProblemState},
+    tolerances::Tolerances,
+    LinearCombine, Projector, Result, SolverSolution,
+};
+use std::{
+    io::{Read, Write},
+    net::{TcpListener, TcpStream, ToSocketAddrs},
+    sync::mpsc,
+};
+
+// Divide and concur over machines that each hold some of the constraints. A
+// worker projects its own copies (divide) and the coordinator averages every
+// variable over all of them (concur), so a step costs one round trip carrying
+// the weighted totals of the variables a worker touches, not its copies.
+//
+// Per step, each worker sends `Sums` for the reflection R_D(x) = 2 P_D(x) - x
+// of its iterate, plus the squared delta of its last step; the coordinator
+// answers with their consensus, which is P_C(R_D(x)), or `Finish` once the
+// summed delta has converged, or `Stop` when out of steps.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Message {
+    // The variables a worker touches, with their total weight on that worker.
+    Hello {
+        variables: Vec<usize>,
+        weights: Vec<f32>,
+    },
+    Sums {
+        delta_squared: f32,
+        rd: Vec<f32>,
+    },
+    Consensus {
+        rd: Vec<f32>,
+    },
+    // Asks for the candidate solution, P_D(x), which each worker has locally.
+    Finish,
+    Solution {
+        sums: Vec<f32>,
+    },
+    Stop,
+}
+
+impl Message {
+    fn name(&self) -> &'static str {
+        match self {
+            Message::Hello { .. } => "hello",
+            Message::Sums { .. } => "sums",
+            Message::Consensus { .. } => "consensus",
+            Message::Finish => "finish",
+            Message::Solution { .. } => "solution",
+            Message::Stop => "stop",
+        }
+    }
+
+    // A tag byte, then every field little-endian with vectors length-prefixed.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        match self {
+            Message::Hello { variables, weights } => {
+                bytes.push(0);
+                put_len(&mut bytes, variables.len());
+                for &j in variables {
+                    bytes.extend((j as u64).to_le_bytes());
+                }
+                put_values(&mut bytes, weights);
+            }
+            Message::Sums { delta_squared, rd } => {
+                bytes.push(1);
+                bytes.extend(delta_squared.to_le_bytes());
+                put_values(&mut bytes, rd);
+            }
+            Message::Consensus { rd } => {
+                bytes.push(2);
+                put_values(&mut bytes, rd);
+            }
+            Message::Finish => bytes.push(3),
+            Message::Solution { sums } => {
+                bytes.push(4);
+                put_values(&mut bytes, sums);
+            }
+            Message::Stop => bytes.push(5),
+        }
+        bytes
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader(bytes);
+        let message = match reader.take(1)?[0] {
+            0 => {
+                let n = reader.len()?;
+                let variables = (0..n)
+                    .map(|_| reader.u64().map(|j| j as usize))
+                    .collect::<Result<_>>()?;
+                Message::Hello {
+                    variables,
+                    weights: reader.values()?,
+                }
+            }
+            1 => Message::Sums {
+                delta_squared: reader.f32()?,
+                rd: reader.values()?,
+            },
+            2 => Message::Consensus {
+                rd: reader.values()?,
+            },
+            3 => Message::Finish,
+            4 => Message::Solution {
+                sums: reader.values()?,
+            },
+            5 => Message::Stop,
+            tag => return Err(Error::Transport(format!("unknown message tag {tag}"))),
+        };
+        if !reader.0.is_empty() {
+            return Err(Error::Transport(format!(
+                "{} trailing bytes after {}",
+                reader.0.len(),
+                message.name()
+            )));
+        }
+
+        Ok(message)
+    }
+}
+
+fn put_len(bytes: &mut Vec<u8>, len: usize) {
+    bytes.extend((len as u64).to_le_bytes());
+}
+
+fn put_values(bytes: &mut Vec<u8>, values: &[f32]) {
+    put_len(bytes, values.len());
+    for x in values {
+        bytes.extend(x.to_le_bytes());
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn take(&mut self, n: usize) -> Result<&[u8]> {
+        if self.0.len() < n {
+            return Err(Error::Transport(format!(
+                "truncated message: expected {n} more bytes, got {}",
+                self.0.len()
+            )));
+        }
+        let (head, rest) = self.0.split_at(n);
+        self.0 = rest;
+        Ok(head)
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn f32(&mut self) -> Result<f32> {
+        Ok(f32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn len(&mut self) -> Result<usize> {
+        let len = self.u64()? as usize;
+        // Checked before allocating, so a corrupt length can't exhaust memory.
+        if len > self.0.len() {
+            return Err(Error::Transport(format!(
+                "truncated message: expected {len} values, got {} bytes",
+                self.0.len()
+            )));
+        }
+        Ok(len)
+    }
+
+    fn values(&mut self) -> Result<Vec<f32>> {
+        let n = self.len()?;
+        (0..n).map(|_| self.f32()).collect()
+    }
+}
+
+// Carries messages between the coordinator and one worker.
+pub trait Transport {
+    fn send(&mut self, message: Message) -> Result<()>;
+
+    fn recv(&mut self) -> Result<Message>;
+}
+
+// For workers on threads of the same process.
+#[derive(Debug)]
+pub struct ChannelTransport {
+    sender: mpsc::Sender<Message>,
+    receiver: mpsc::Receiver<Message>,
+}
+
+// Both ends of a connection, one for the coordinator and one for a worker.
+pub fn channel() -> (ChannelTransport, ChannelTransport) {
+    let (a_sender, b_receiver) = mpsc::channel();
+    let (b_sender, a_receiver) = mpsc::channel();
+    (
+        ChannelTransport {
+            sender: a_sender,
+            receiver: a_receiver,
+        },
+        ChannelTransport {
+            sender: b_sender,
+            receiver: b_receiver,
+        },
+    )
+}
+
+impl Transport for ChannelTransport {
+    fn send(&mut self, message: Message) -> Result<()> {
+        self.sender
+            .send(message)
+            .map_err(|_| Error::Transport("channel closed".into()))
+    }
+
+    fn recv(&mut self) -> Result<Message> {
+        self.receiver
+            .recv()
+            .map_err(|_| Error::Transport("channel closed".into()))
+    }
+}
+
+// Frames above this many bytes are refused by default; a frame carries a few
+// values per variable, so this allows well over ten million variables.
+pub const MAX_FRAME: usize = 1 << 30;
+
+// Length-prefixed `Message::encode` frames over a TCP stream.
+#[derive(Debug)]
+pub struct TcpTransport {
+    stream: TcpStream,
+    max_frame: usize,
+}
+
+impl TcpTransport {
+    pub fn new(stream: TcpStream) -> Result<Self> {
+        // Each round trip is latency-bound; don't wait to batch small frames.
+        stream.set_nodelay(true).map_err(io)?;
+        Ok(Self {
+            stream,
+            max_frame: MAX_FRAME,
+        })
+    }
+
+    // The largest frame `recv` accepts, checked against the length prefix
+    // before anything is allocated.
+    pub fn with_max_frame(mut self, max_frame: usize) -> Self {
+        self.max_frame = max_frame;
+        self
+    }
+
+    pub fn connect(addr: impl ToSocketAddrs) -> Result<Self> {
+        Self::new(TcpStream::connect(addr).map_err(io)?)
+    }
+
+    pub fn accept(listener: &TcpListener) -> Result<Self> {
+        Self::new(listener.accept().map_err(io)?.0)
+    }
+}
+
+impl Transport for TcpTransport {
+    fn send(&mut self, message: Message) -> Result<()> {
+        let bytes = message.encode();
+        self.stream
+            .write_all(&(bytes.len() as u64).to_le_bytes())
+            .and_then(|_| self.stream.write_all(&bytes))
+            .map_err(io)
+    }
+
+    fn recv(&mut self) -> Result<Message> {
+        let mut len = [0; 8];
+        self.stream.read_exact(&mut len).map_err(io)?;
+        let len = u64::from_le_bytes(len);
+        if len > self.max_frame as u64 {
+            return Err(Error::Transport(format!(
+                "expected a frame of at most {} bytes, got {len}",
+                self.max_frame
+            )));
+        }
+        let mut bytes = vec![0; len as usize];
+        self.stream.read_exact(&mut bytes).map_err(io)?;
+        Message::decode(&bytes)
+    }
+}
+
+fn io(err: std::io::Error) -> Error {
+    Error::Transport(err.to_string())
+}
+
+fn unexpected(expected: &str, got: &Message) -> Error {
+    Error::Transport(format!("expected {expected}, got {}", got.name()))
+}
+
+// Holds a subset of the constraints, declared over the full set of variables
+// so indices agree across workers. Every worker starts from the same values.
+#[derive(Debug)]
+pub struct Worker {
+    problem: ConstraintProblem,
+    variables: Vec<usize>,
+    state: ProblemState,
+    beta: f32,
+}
+
+impl Worker {
+    pub fn new(problem: ConstraintProblem, values: &[f32], beta: f32) -> Result<Self> {
+        let state = problem.initial_state(values)?;
+        let (_, weights) = problem.totals(&state)?;
+        let variables = (0..weights.len()).filter(|&j| weights[j] > 0.0).collect();
+
+        Ok(Self {
+            problem,
+            variables,
+            state,
+            beta,
+        })
+    }
+
+    // Steps until the coordinator ends the run, and returns the steps taken.
+    pub fn run<T: Transport>(mut self, transport: &mut T) -> Result<usize> {
+        let (_, weights) = self.problem.totals(&self.state)?;
+        transport.send(Message::Hello {
+            variables: self.variables.clone(),
+            weights: self.gather(&weights),
+        })?;
+
+        let divide = self.problem.divide();
+        let mut delta_squared = f32::NAN;
+        let mut steps = 0;
+        loop {
+            let pd = divide.project(self.state.clone())?;
+            let rd = pd.clone().scale(2.0).axpy(-1.0, self.state.clone());
+            transport.send(Message::Sums {
+                delta_squared,
+                rd: self.gather(&self.problem.totals(&rd)?.0),
+            })?;
+
+            match transport.recv()? {
+                Message::Consensus { rd: consensus } => {
+                    let mut pcrd = rd;
+                    self.problem
+                        .broadcast(&mut pcrd, &self.scatter(&consensus)?);
+
+                    let next = self.state.clone().axpy(self.beta, pcrd.axpy(-1.0, pd));
+                    delta_squared = euclidean(&next, &self.state).powi(2);
+                    self.state = next;
+                    steps += 1;
+                }
+                Message::Finish => {
+                    let sums = self.gather(&self.problem.totals(&pd)?.0);
+                    transport.send(Message::Solution { sums })?;
+                    return Ok(steps);
+                }
+                Message::Stop => return Ok(steps),
+                other => return Err(unexpected("consensus, finish or stop", &other)),
+            }
+        }
+    }
+
+    fn gather(&self, dense: &[f32]) -> Vec<f32> {
+        self.variables.iter().map(|&j| dense[j]).collect()
+    }
+
+    fn scatter(&self, values: &[f32]) -> Result<Vec<f32>> {
+        if values.len() != self.variables.len() {
+            return Err(Error::Dimension(self.variables.len(), values.len()));
+        }
+
+        let mut dense = vec![0.0; self.problem.n_variables()];
+        for (&j, &x) in self.variables.iter().zip(values) {
+            dense[j] = x;
+        }
+        Ok(dense)
+    }
+}
+
+// Drives the workers and decides when to stop, like `DivideAndConcurSolver`
+// does for a single machine, with the delta summed over every worker's copies.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coordinator {
+    n_variables: usize,
+    tolerances: Tolerances,
+    n_steps: usize,
+}
+
+impl Coordinator {
+    pub fn new(n_variables: usize, epsilon: f32, n_steps: usize) -> Self {
+        Self {
+            n_variables,
+            tolerances: Tolerances::new(epsilon),
+            n_steps,
+        }
+    }
+
+    pub fn with_tolerances(mut self, tolerances: Tolerances) -> Self {
+        self.tolerances = tolerances;
+        self
+    }
+
+    // Returns the consensus of the workers' candidate solutions. Unless they
+    // were sent `Finish`, the workers are sent `Stop` however the run ends.
+    pub fn run<T: Transport>(&self, workers: &mut [T]) -> Result<SolverSolution<Vec<f32>>> {
+        let mut workers = StopGuard {
+            workers,
+            armed: true,
+        };
+        let mut variables = Vec::with_capacity(workers.len());
+        let mut weights = vec![0f32; self.n_variables];
+        for worker in workers.iter_mut() {
+            match worker.recv()? {
+                Message::Hello {
+                    variables: v,
+                    weights: w,
+                } => {
+                    if v.len() != w.len() {
+                        return Err(Error::Dimension(v.len(), w.len()));
+                    }
+                    if let Some(&j) = v.iter().find(|&&j| j >= self.n_variables) {
+                        return Err(Error::Dimension(self.n_variables, j + 1));
+                    }
+                    for (&j, w) in v.iter().zip(w) {
+                        weights[j] += w;
+                    }
+                    variables.push(v);
+                }
+                other => return Err(unexpected("hello", &other)),
+            }
+        }
+
+        let average = |totals: Vec<f32>| -> Vec<f32> {
+            totals
+                .into_iter()
+                .zip(&weights)
+                .map(|(t, &w)| if w > 0.0 { t / w } else { 0.0 })
+                .collect()
+        };
+        let scatter = |totals: &mut [f32], vars: &[usize], sums: &[f32]| -> Result<()> {
+            if sums.len() != vars.len() {
+                return Err(Error::Dimension(vars.len(), sums.len()));
+            }
+            for (&j, s) in vars.iter().zip(sums) {
+                totals[j] += s;
+            }
+            Ok(())
+        };
+
+        let mut initial_delta = f32::NAN;
+        let mut delta = f32::NAN;
+        for t in 0..=self.n_steps {
+            let mut rd = vec![0f32; self.n_variables];
+            let mut delta_squared = 0.0;
+            for (worker, vars) in workers.iter_mut().zip(&variables) {
+                match worker.recv()? {
+                    Message::Sums {
+                        delta_squared: d,
+                        rd: sums,
+                    } => {
+                        scatter(&mut rd, vars, &sums)?;
+                        delta_squared += d;
+                    }
+                    other => return Err(unexpected("sums", &other)),
+                }
+            }
+            // Sums at round t carry the delta of step t - 1.
+            if t > 0 {
+                delta = delta_squared.sqrt();
+                if t == 1 {
+                    initial_delta = delta;
+                }
+                if self.tolerances.converged(delta, initial_delta) {
+                    let mut solution = vec![0f32; self.n_variables];
+                    for worker in workers.iter_mut() {
+                        worker.send(Message::Finish)?;
+                    }
+                    workers.armed = false;
+                    for (worker, vars) in workers.iter_mut().zip(&variables) {
+                        match worker.recv()? {
+                            Message::Solution { sums } => scatter(&mut solution, vars, &sums)?,
+                            other => return Err(unexpected("solution", &other)),
+                        }
+                    }
+                    return Ok((average(solution), t - 1, delta));
+                }
+            }
+            if t == self.n_steps {
+                break;
+            }
+
+            let rd = average(rd);
+            for (worker, vars) in workers.iter_mut().zip(&variables) {
+                worker.send(Message::Consensus {
+                    rd: vars.iter().map(|&j| rd[j]).collect(),
+                })?;
+            }
+        }
+
+        Err(Error::Convergence(self.n_steps, delta))
+    }
+}
+
+// Sends `Stop` to every worker when dropped while armed, so no worker is left
+// waiting on a coordinator that returned early. Send errors are ignored, as
+// the worker on the other end may already be gone.
+struct StopGuard<'a, T: Transport> {
+    workers: &'a mut [T],
+    armed: bool,
+}
+
+impl<T: Transport> core::ops::Deref for StopGuard<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.workers
+    }
+}
+
+impl<T: Transport> core::ops::DerefMut for StopGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.workers
+    }
+}
+
+impl<T: Transport> Drop for StopGuard<'_, T> {
+    fn drop(&mut self) {
+        if self.armed {
+            for worker in self.workers.iter_mut() {
+                let _ = worker.send(Message::Stop);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        problem::Variable,
+        projectors::{box_constraints::BoxProjector, hyperplane::HyperplaneProjector},
+        Solver,
+    };
+    use std::thread;
+
+    // The constraints of `problem::tests::test_solve`, the first two on one
+    // worker and the box on the other, or all of them together.
+    fn problems() -> (ConstraintProblem, ConstraintProblem, ConstraintProblem) {
+        let constrain = |problem: &mut ConstraintProblem, x: &[Variable], which: &[usize]| {
+            for &i in which {
+                match i {
+                    0 => problem.constrain(&x[..2], HyperplaneProjector::new(vec![1.0, 1.0], 1.0)),
+                    1 => problem.constrain(&x[1..], HyperplaneProjector::new(vec![1.0, -1.0], 0.5)),
+                    _ => problem.constrain(x, BoxProjector::new(0.0, 1.0)),
+                };
+            }
+        };
+        let build = |which: &[usize]| {
+            let mut problem = ConstraintProblem::new();
+            let x = problem.variables(3);
+            constrain(&mut problem, &x, which);
+            problem
+        };
+        (build(&[0, 1]), build(&[2]), build(&[0, 1, 2]))
+    }
+
+    fn solve<T>(transports: Vec<(T, T)>) -> (SolverSolution<Vec<f32>>, Vec<usize>)
+    where
+        T: Transport + Send + 'static,
+    {
+        let (a, b, _) = problems();
+        let mut coordinator_ends = vec![];
+        let mut handles = vec![];
+        for ((coordinator_end, mut worker_end), problem) in transports.into_iter().zip([a, b]) {
+            coordinator_ends.push(coordinator_end);
+            handles.push(thread::spawn(move || {
+                Worker::new(problem, &[0.9, 0.9, 0.9], 1.0)
+                    .unwrap()
+                    .run(&mut worker_end)
+                    .unwrap()
+            }));
+        }
+
+        let result = Coordinator::new(3, 1e-6, 1000)
+            .run(&mut coordinator_ends)
+            .unwrap();
+        let steps = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        (result, steps)
+    }
+
+    #[test]
+    fn test_channel() {
+        let ((values, steps, _), worker_steps) = solve(vec![channel(), channel()]);
+        assert_eq!(worker_steps, vec![steps + 1; 2]);
+
+        // The same iterates as on one machine, up to rounding.
+        let (_, _, whole) = problems();
+        let state = whole.initial_state(&[0.9, 0.9, 0.9]).unwrap();
+        let (state, _, _) = whole.solver(1.0, 1e-6, 1000).run(state).unwrap();
+        for (x, y) in values.iter().zip(whole.decode(&state).unwrap()) {
+            assert!((x - y).abs() < 1e-3, "{values:?}");
+        }
+        assert!((values[0] + values[1] - 1.0).abs() < 1e-3);
+        assert!((values[1] - values[2] - 0.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_stop_on_error() {
+        // The workers declare three variables to a coordinator expecting two,
+        // which fails on the first hello; both workers are still stopped.
+        let (a, b, _) = problems();
+        let mut coordinator_ends = vec![];
+        let mut handles = vec![];
+        for problem in [a, b] {
+            let (coordinator_end, mut worker_end) = channel();
+            coordinator_ends.push(coordinator_end);
+            handles.push(thread::spawn(move || {
+                Worker::new(problem, &[0.9, 0.9, 0.9], 1.0)
+                    .unwrap()
+                    .run(&mut worker_end)
+            }));
+        }
+
+        let result = Coordinator::new(2, 1e-6, 1000).run(&mut coordinator_ends);
+        assert!(matches!(result, Err(Error::Dimension(2, 3))));
+        for handle in handles {
+            assert_eq!(handle.join().unwrap().unwrap(), 0);
+        }
+    }
+
+    #[test]
+    fn test_tcp() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let pairs = (0..2)
+            .map(|_| {
+                let worker_end = TcpTransport::connect(addr).unwrap();
+                (TcpTransport::accept(&listener).unwrap(), worker_end)
+            })
+            .collect();
+
+        let ((values, _, _), _) = solve(pairs);
+        assert!((values[0] + values[1] - 1.0).abs() < 1e-3);
+
+        // A length prefix above the cap is refused without reading the body.
+        let mut sender = TcpStream::connect(addr).unwrap();
+        let mut receiver = TcpTransport::accept(&listener).unwrap().with_max_frame(64);
+        sender.write_all(&u64::MAX.to_le_bytes()).unwrap();
+        assert!(matches!(
+            receiver.recv(),
+            Err(Error::Transport(message)) if message.contains("at most 64 bytes")
+        ));
+    }
+
+    #[test]
+    fn test_encode() {
+        let messages = [
+            Message::Hello {
+                variables: vec![0, 2],
+                weights: vec![1.0, 3.0],
+            },
+            Message::Sums {
+                delta_squared: f32::NAN,
+                rd: vec![0.5],
+            },
+            Message::Finish,
+            Message::Stop,
+        ];
+        for message in messages {
+            let decoded = Message::decode(&message.encode()).unwrap();
+            assert_eq!(decoded.encode(), message.encode());
+        }
+
+        let mut bytes = Message::Consensus { rd: vec![1.0] }.encode();
+        bytes.pop();
+        assert!(matches!(Message::decode(&bytes), Err(Error::Transport(_))));
+    }
+}
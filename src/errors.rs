@@ -1,11 +1,105 @@
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
+    #[error("cancelled error: run stopped after {0} steps")]
+    Cancelled(usize),
+
     #[error("convergence error: failed to converge, delta={1}, after {0} steps")]
     Convergence(usize, f32),
 
+    #[error("divergence error: delta grew to {1} after {0} steps")]
+    Diverged(usize, f32),
+
+    #[error("dimension error: expected {0} coordinates, got {1}")]
+    Dimension(usize, usize),
+
+    #[error("factorization error: {0}")]
+    Factorization(String),
+
+    #[error("parse error: line {0}: {1}")]
+    Parse(usize, String),
+
     #[error("projection error: {0}")]
-    Projection(Box<dyn std::error::Error>),
+    Projection(Box<dyn core::error::Error + Send + Sync>),
+
+    #[error("schema error: {0}")]
+    Schema(String),
+
+    #[error("snapshot error: {0}")]
+    Snapshot(String),
+
+    #[error("step error: {projector} projector failed at step {step}: {source}")]
+    Step {
+        projector: ProjectorKind,
+        step: usize,
+        state: Option<String>,
+        source: Box<Error>,
+    },
+
+    #[error("transport error: {0}")]
+    Transport(String),
 
     #[error("unknown error: {0}")]
-    Unknown(Box<dyn std::error::Error>),
+    Unknown(Box<dyn core::error::Error + Send + Sync>),
+}
+
+impl Error {
+    pub(crate) fn in_projector(self, projector: ProjectorKind) -> Self {
+        Error::Step {
+            projector,
+            step: 0,
+            state: None,
+            source: Box::new(self),
+        }
+    }
+
+    // Places a projector failure at step `t`, which started from `iterate`.
+    // With `state-snapshots` the iterate is kept, formatted only here, on the
+    // error path.
+    #[cfg_attr(not(feature = "state-snapshots"), allow(unused_variables))]
+    pub(crate) fn at_step<S: core::fmt::Debug>(mut self, t: usize, iterate: &S) -> Self {
+        if let Error::Step { step, .. } = &mut self {
+            *step = t;
+        }
+        #[cfg(feature = "state-snapshots")]
+        if let Error::Step { state, .. } = &mut self {
+            *state = Some(alloc::format!("{iterate:?}"));
+        }
+        self
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("projector panicked: {0}")]
+pub struct ProjectorPanic(pub String);
+
+impl From<Box<dyn core::any::Any + Send>> for ProjectorPanic {
+    fn from(payload: Box<dyn core::any::Any + Send>) -> Self {
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => match payload.downcast::<&'static str>() {
+                Ok(message) => message.to_string(),
+                Err(_) => "unknown panic payload".to_string(),
+            },
+        };
+        Self(message)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectorKind {
+    Divide,
+    Concur,
+}
+
+impl core::fmt::Display for ProjectorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ProjectorKind::Divide => write!(f, "divide"),
+            ProjectorKind::Concur => write!(f, "concur"),
+        }
+    }
 }
@@ -0,0 +1,549 @@
+use crate::{
+    errors::Error,
+    problems::{
+        qubo::Ising,
+        rng::SplitMix64,
+        sat::{Cnf, SatProblem},
+        sudoku::Sudoku,
+    },
+    schema::ProblemSpec,
+    tokio::Progress,
+    Monitor, Result, Solver,
+};
+use ::tokio::sync::{mpsc, watch};
+use proto::{
+    fetch_reply, solve_server::SolveServer, submit_request, ConstraintsSolution, FetchReply,
+    FetchRequest, ProgressRequest, ProgressUpdate, QuboSolution, SatSolution, SubmitReply,
+    SubmitRequest, SudokuSolution,
+};
+use std::{
+    collections::BTreeMap,
+    ops::ControlFlow,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+
+// Generated from `proto/drs.proto`, which non-Rust clients compile as well.
+pub mod proto {
+    tonic::include_proto!("drs");
+}
+
+// Defaults for settings a request leaves unset, as (beta, epsilon, n_steps).
+const SAT_SETTINGS: (f32, f32, usize) = (1.0, 0.4, 10000);
+const SUDOKU_SETTINGS: (f32, f32, usize) = (0.9, 1.0, 100000);
+const QUBO_SETTINGS: (f32, f32, usize) = (0.5, 1e-4, 10000);
+
+// Most variables a request may ask for, since each one is allocated up front.
+const MAX_VARIABLES: usize = 1 << 24;
+
+// Most steps a solve may run for; larger requests are clamped to it.
+const MAX_STEPS: usize = 10_000_000;
+
+type Outcome = std::result::Result<FetchReply, Status>;
+
+// A solve running on the blocking pool. `outcome` stays `None` until it ends.
+struct Job {
+    progress: watch::Receiver<Progress>,
+    outcome: watch::Receiver<Option<Outcome>>,
+}
+
+// Finished solves kept for fetching, by default; see `with_max_finished`.
+const MAX_FINISHED: usize = 1024;
+
+// Serves `proto::solve_server::Solve` for SAT, sudoku and QUBO problems, and
+// feasibility problems in the JSON schema of `ProblemSpec`. Each solve runs on
+// tokio's blocking pool. A finished solve is dropped once its outcome is
+// fetched, or, never fetched, once `max_finished` newer ones have finished.
+pub struct SolveService {
+    jobs: Mutex<BTreeMap<u64, Job>>,
+    next_id: AtomicU64,
+    max_finished: usize,
+}
+
+impl Default for SolveService {
+    fn default() -> Self {
+        Self {
+            jobs: Mutex::default(),
+            next_id: AtomicU64::default(),
+            max_finished: MAX_FINISHED,
+        }
+    }
+}
+
+impl SolveService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_finished(mut self, max_finished: usize) -> Self {
+        self.max_finished = max_finished;
+        self
+    }
+
+    pub fn into_server(self) -> SolveServer<Self> {
+        SolveServer::new(self)
+    }
+
+    fn job<T>(&self, id: &str, f: impl FnOnce(&Job) -> T) -> std::result::Result<T, Status> {
+        let jobs = self.jobs.lock().unwrap();
+        id.parse()
+            .ok()
+            .and_then(|id| jobs.get(&id))
+            .map(f)
+            .ok_or_else(|| Status::not_found(format!("no solve with id {id}")))
+    }
+}
+
+#[tonic::async_trait]
+impl proto::solve_server::Solve for SolveService {
+    async fn submit(
+        &self,
+        request: Request<SubmitRequest>,
+    ) -> std::result::Result<Response<SubmitReply>, Status> {
+        let request = request.into_inner();
+        let job = match &request.problem {
+            Some(submit_request::Problem::Sat(sat)) => {
+                let cnf = Cnf::parse(&sat.dimacs).map_err(status)?;
+                variables(cnf.n_variables() as u64)?;
+                let problem = SatProblem::new(cnf);
+                let (beta, epsilon, n_steps) = settings(&request, SAT_SETTINGS);
+                let values = uniform(request.seed, problem.cnf().n_variables(), -1.0);
+                spawn(move |monitor| {
+                    let state = problem.initial_state(&values)?;
+                    let solver = problem.solver(beta, epsilon, n_steps);
+                    let (state, steps, delta) = solver.with_monitor(monitor).run(state)?;
+
+                    let assignment = problem.decode(&state)?;
+                    let unsatisfied = problem.cnf().unsatisfied(&assignment) as u64;
+                    Ok(reply(
+                        fetch_reply::Solution::Sat(SatSolution {
+                            assignment,
+                            unsatisfied,
+                        }),
+                        steps,
+                        delta,
+                    ))
+                })
+            }
+            Some(submit_request::Problem::Sudoku(sudoku)) => {
+                let puzzle = Sudoku::parse(&sudoku.puzzle).map_err(status)?;
+                let size = puzzle.n().checked_pow(3).map_or(u64::MAX, |n| n as u64);
+                variables(size)?;
+                let (beta, epsilon, n_steps) = settings(&request, SUDOKU_SETTINGS);
+                let values = uniform(request.seed, puzzle.n().pow(3), 0.0);
+                spawn(move |monitor| {
+                    let mut values = values.into_iter();
+                    let state = puzzle.initial_state(|| values.next().unwrap());
+                    let solver = Sudoku::solver(beta, epsilon, n_steps);
+                    let (state, steps, delta) = solver.with_monitor(monitor).run(state)?;
+
+                    let solution = state.solution();
+                    Ok(reply(
+                        fetch_reply::Solution::Sudoku(SudokuSolution {
+                            cells: solution.cells().iter().map(|&v| v as u32).collect(),
+                            violations: solution.violations(&puzzle) as u64,
+                        }),
+                        steps,
+                        delta,
+                    ))
+                })
+            }
+            Some(submit_request::Problem::Qubo(qubo)) => {
+                let n = variables(qubo.n)?;
+                let q = &qubo.q;
+                let size = n.checked_mul(n).ok_or_else(|| {
+                    Status::invalid_argument(format!("expected a QUBO size that fits, got {n}"))
+                })?;
+                if q.len() != size {
+                    return Err(status(Error::Dimension(size, q.len())));
+                }
+                let ising = Ising::from_qubo(n, q);
+                // Fixed points are only local minima, so this searches for the
+                // whole budget and replies with the lowest energy it saw. The
+                // search has no delta, and reports its progress once, at the end.
+                let (beta, _, n_steps) = settings(&request, QUBO_SETTINGS);
+                let values = uniform(request.seed, n, -1.0);
+                spawn(move |monitor| {
+                    let state = ising.initial_state(&values)?;
+                    let ground = ising.search(state, f32::NEG_INFINITY, beta, n_steps)?;
+                    let _ = monitor(ground.step, f32::NAN);
+
+                    Ok(reply(
+                        fetch_reply::Solution::Qubo(QuboSolution {
+                            bits: ground.bits(),
+                            energy: ground.energy,
+                        }),
+                        ground.step,
+                        f32::NAN,
+                    ))
+                })
+            }
+            Some(submit_request::Problem::Constraints(constraints)) => {
+                let spec = ProblemSpec::from_json(&constraints.json).map_err(status)?;
+                variables(spec.variables as u64)?;
+                let problem = spec.build().map_err(status)?;
+                let solver = spec.solver;
+                let (beta, epsilon, n_steps) =
+                    settings(&request, (solver.beta, solver.epsilon, solver.n_steps));
+                let values = match spec.initial {
+                    Some(values) => values,
+                    None => uniform(request.seed, spec.variables, -1.0),
+                };
+                spawn(move |monitor| {
+                    let state = problem.initial_state(&values)?;
+                    let solver = problem.solver(beta, epsilon, n_steps);
+                    let (state, steps, delta) = solver.with_monitor(monitor).run(state)?;
+
+                    Ok(reply(
+                        fetch_reply::Solution::Constraints(ConstraintsSolution {
+                            values: problem.decode(&state)?,
+                        }),
+                        steps,
+                        delta,
+                    ))
+                })
+            }
+            None => return Err(Status::invalid_argument("missing problem")),
+        };
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let mut jobs = self.jobs.lock().unwrap();
+        jobs.insert(id, job);
+
+        // Oldest first, since ids only grow.
+        let finished: Vec<u64> = jobs
+            .iter()
+            .filter(|(_, job)| job.outcome.borrow().is_some())
+            .map(|(&id, _)| id)
+            .collect();
+        let excess = finished.len().saturating_sub(self.max_finished);
+        for id in &finished[..excess] {
+            jobs.remove(id);
+        }
+
+        Ok(Response::new(SubmitReply { id: id.to_string() }))
+    }
+
+    type ProgressStream = ReceiverStream<std::result::Result<ProgressUpdate, Status>>;
+
+    async fn progress(
+        &self,
+        request: Request<ProgressRequest>,
+    ) -> std::result::Result<Response<Self::ProgressStream>, Status> {
+        let (mut progress, mut outcome) = self.job(&request.into_inner().id, |job| {
+            (job.progress.clone(), job.outcome.clone())
+        })?;
+
+        let (sender, receiver) = mpsc::channel(16);
+        ::tokio::spawn(async move {
+            loop {
+                let done = outcome.borrow_and_update().is_some();
+                let latest = *progress.borrow_and_update();
+                if latest.step.is_some() || done {
+                    let update = ProgressUpdate {
+                        step: latest.step.unwrap_or(0) as u64,
+                        delta: latest.delta,
+                        done,
+                    };
+                    if sender.send(Ok(update)).await.is_err() || done {
+                        break;
+                    }
+                }
+
+                ::tokio::select! {
+                    changed = progress.changed() => {
+                        // The run ended, with its outcome about to be set.
+                        if changed.is_err() {
+                            let _ = outcome.changed().await;
+                        }
+                    }
+                    _ = outcome.changed() => {}
+                }
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(receiver)))
+    }
+
+    async fn fetch(
+        &self,
+        request: Request<FetchRequest>,
+    ) -> std::result::Result<Response<FetchReply>, Status> {
+        let FetchRequest { id, wait } = request.into_inner();
+        let mut outcome = self.job(&id, |job| job.outcome.clone())?;
+
+        let outcome = match wait {
+            true => outcome
+                .wait_for(Option::is_some)
+                .await
+                .map_err(|_| Status::internal("solve was lost"))?
+                .clone(),
+            false => outcome.borrow().clone(),
+        };
+        match outcome {
+            Some(outcome) => {
+                if let Ok(id) = id.parse::<u64>() {
+                    self.jobs.lock().unwrap().remove(&id);
+                }
+                outcome.map(Response::new)
+            }
+            None => Err(Status::unavailable(format!("solve {id} is still running"))),
+        }
+    }
+}
+
+// Runs `solve` on the blocking pool, publishing its steps to the job.
+fn spawn<F>(solve: F) -> Job
+where
+    F: FnOnce(Monitor) -> Result<FetchReply> + Send + 'static,
+{
+    let (progress_sender, progress) = watch::channel(Progress {
+        step: None,
+        delta: f32::NAN,
+    });
+    let (outcome_sender, outcome) = watch::channel(None);
+
+    let monitor: Monitor = Box::new(move |t, delta| {
+        progress_sender.send_replace(Progress {
+            step: Some(t),
+            delta,
+        });
+        ControlFlow::Continue(())
+    });
+    ::tokio::spawn(async move {
+        let outcome = match ::tokio::task::spawn_blocking(move || solve(monitor)).await {
+            Ok(result) => result.map_err(status),
+            Err(err) => Err(Status::internal(err.to_string())),
+        };
+        outcome_sender.send_replace(Some(outcome));
+    });
+
+    Job { progress, outcome }
+}
+
+fn settings(request: &SubmitRequest, defaults: (f32, f32, usize)) -> (f32, f32, usize) {
+    (
+        request.beta.unwrap_or(defaults.0),
+        request.epsilon.unwrap_or(defaults.1),
+        request
+            .n_steps
+            .map_or(defaults.2, |n| n as usize)
+            .min(MAX_STEPS),
+    )
+}
+
+fn variables(n: u64) -> std::result::Result<usize, Status> {
+    if n > MAX_VARIABLES as u64 {
+        return Err(Status::invalid_argument(format!(
+            "expected at most {MAX_VARIABLES} variables, got {n}"
+        )));
+    }
+    Ok(n as usize)
+}
+
+// `n` values drawn uniformly from (low, 1].
+fn uniform(seed: u64, n: usize, low: f32) -> Vec<f32> {
+    let mut rng = SplitMix64::new(seed);
+    (0..n).map(|_| low + (1.0 - low) * rng.uniform()).collect()
+}
+
+fn reply(solution: fetch_reply::Solution, steps: usize, delta: f32) -> FetchReply {
+    FetchReply {
+        solution: Some(solution),
+        steps: steps as u64,
+        delta,
+    }
+}
+
+fn status(err: Error) -> Status {
+    match err {
+        Error::Dimension(..) | Error::Parse(..) | Error::Schema(..) => {
+            Status::invalid_argument(err.to_string())
+        }
+        _ => Status::aborted(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proto::{solve_server::Solve, Constraints, Qubo, Sat};
+    use tokio_stream::StreamExt;
+
+    async fn submit(service: &SolveService, problem: submit_request::Problem) -> String {
+        let request = SubmitRequest {
+            problem: Some(problem),
+            seed: 7,
+            ..Default::default()
+        };
+        let reply = service.submit(Request::new(request)).await.unwrap();
+        reply.into_inner().id
+    }
+
+    async fn fetch(service: &SolveService, id: &str) -> Outcome {
+        let request = FetchRequest {
+            id: id.to_string(),
+            wait: true,
+        };
+        service
+            .fetch(Request::new(request))
+            .await
+            .map(Response::into_inner)
+    }
+
+    #[::tokio::test]
+    async fn test_sat() {
+        let service = SolveService::new();
+        let dimacs = "p cnf 3 3\n1 2 0\n-1 3 0\n-2 -3 0\n";
+        let id = submit(
+            &service,
+            submit_request::Problem::Sat(Sat {
+                dimacs: dimacs.to_string(),
+            }),
+        )
+        .await;
+
+        let request = Request::new(ProgressRequest { id: id.clone() });
+        let updates: Vec<_> = service
+            .progress(request)
+            .await
+            .unwrap()
+            .into_inner()
+            .collect()
+            .await;
+        let last = updates.last().unwrap().as_ref().unwrap();
+        assert!(last.done);
+
+        let reply = fetch(&service, &id).await.unwrap();
+        assert_eq!(reply.steps, last.step);
+        match reply.solution {
+            Some(fetch_reply::Solution::Sat(sat)) => {
+                assert_eq!(sat.assignment.len(), 3);
+                assert_eq!(sat.unsatisfied, 0);
+            }
+            other => panic!("expected a SAT solution, got {other:?}"),
+        }
+    }
+
+    #[::tokio::test]
+    async fn test_qubo() {
+        // Rewards either bit alone and penalizes both.
+        let service = SolveService::new();
+        let q = vec![-1.0, 2.0, 0.0, -1.0];
+        let id = submit(&service, submit_request::Problem::Qubo(Qubo { n: 2, q })).await;
+
+        match fetch(&service, &id).await.unwrap().solution {
+            Some(fetch_reply::Solution::Qubo(qubo)) => {
+                assert_eq!(qubo.energy, -1.0);
+                assert_eq!(qubo.bits.iter().filter(|&&b| b).count(), 1);
+            }
+            other => panic!("expected a QUBO solution, got {other:?}"),
+        }
+    }
+
+    #[::tokio::test]
+    async fn test_constraints() {
+        let json = r#"{
+            "variables": 2,
+            "constraints": [
+                {"variables": [0, 1], "projector": {"type": "hyperplane", "a": [1, 1], "b": 1.5}},
+                {"variables": [0, 1], "projector": {"type": "box", "lower": 0, "upper": 1}}
+            ]
+        }"#;
+        let service = SolveService::new();
+        let id = submit(
+            &service,
+            submit_request::Problem::Constraints(Constraints {
+                json: json.to_string(),
+            }),
+        )
+        .await;
+
+        match fetch(&service, &id).await.unwrap().solution {
+            Some(fetch_reply::Solution::Constraints(solution)) => {
+                let x = solution.values;
+                assert!((x[0] + x[1] - 1.5).abs() < 1e-3);
+            }
+            other => panic!("expected a constraints solution, got {other:?}"),
+        }
+    }
+
+    #[::tokio::test]
+    async fn test_errors() {
+        let service = SolveService::new();
+        let request = SubmitRequest {
+            problem: Some(submit_request::Problem::Qubo(Qubo { n: 2, q: vec![1.0] })),
+            ..Default::default()
+        };
+        let err = service.submit(Request::new(request)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+        // Sizes whose square or allocation would overflow are refused up front.
+        for n in [1 << 32, u64::MAX] {
+            let request = SubmitRequest {
+                problem: Some(submit_request::Problem::Qubo(Qubo { n, q: vec![] })),
+                ..Default::default()
+            };
+            let err = service.submit(Request::new(request)).await.unwrap_err();
+            assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        }
+        let request = SubmitRequest {
+            problem: Some(submit_request::Problem::Constraints(Constraints {
+                json: r#"{"variables": 1099511627776, "constraints": []}"#.to_string(),
+            })),
+            ..Default::default()
+        };
+        let err = service.submit(Request::new(request)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+        let request = SubmitRequest {
+            problem: Some(submit_request::Problem::Sat(Sat {
+                dimacs: "p cnf 1099511627776 1\n1 0\n".to_string(),
+            })),
+            ..Default::default()
+        };
+        let err = service.submit(Request::new(request)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+
+        let err = fetch(&service, "missing").await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::NotFound);
+    }
+
+    #[::tokio::test]
+    async fn test_eviction() {
+        let service = SolveService::new().with_max_finished(1);
+        let qubo = || {
+            submit_request::Problem::Qubo(Qubo {
+                n: 1,
+                q: vec![-1.0],
+            })
+        };
+
+        // A fetched solve is gone.
+        let id = submit(&service, qubo()).await;
+        assert!(fetch(&service, &id).await.is_ok());
+        assert_eq!(
+            fetch(&service, &id).await.unwrap_err().code(),
+            tonic::Code::NotFound
+        );
+
+        // Of two finished and unfetched solves, only the newer one survives the
+        // next submit.
+        let first = submit(&service, qubo()).await;
+        let second = submit(&service, qubo()).await;
+        for id in [&first, &second] {
+            let request = Request::new(ProgressRequest { id: id.clone() });
+            let updates = service.progress(request).await.unwrap().into_inner();
+            let _: Vec<_> = updates.collect().await;
+        }
+        let _ = submit(&service, qubo()).await;
+        assert_eq!(
+            fetch(&service, &first).await.unwrap_err().code(),
+            tonic::Code::NotFound
+        );
+        assert!(fetch(&service, &second).await.is_ok());
+    }
+}
@@ -0,0 +1,155 @@
+#[cfg(feature = "std")]
+use crate::{errors::Error, Result};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::Write;
+
+#[cfg(feature = "plot")]
+mod plot;
+
+// One step of a run recorded by `DivideAndConcurSolver::run_with_history`.
+// `violation` is the feasibility of the candidate read off the iterate, when
+// the solver has a feasibility check.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Record {
+    pub step: usize,
+    pub delta: f32,
+    pub violation: Option<f32>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct History {
+    records: Vec<Record>,
+}
+
+impl History {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, record: Record) {
+        self.records.push(record);
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    // A `step,delta,violation` header, then a row per step; missing and
+    // non-finite values are empty cells, which pandas reads as NaN.
+    #[cfg(feature = "std")]
+    pub fn write_csv<W: Write>(&self, mut writer: W) -> Result<()> {
+        writeln!(writer, "step,delta,violation").map_err(io)?;
+        for record in &self.records {
+            writeln!(
+                writer,
+                "{},{},{}",
+                record.step,
+                csv_cell(Some(record.delta)),
+                csv_cell(record.violation),
+            )
+            .map_err(io)?;
+        }
+        writer.flush().map_err(io)
+    }
+
+    // An array of `{"step", "delta", "violation"}` objects, the records layout
+    // of `pandas.read_json`; missing and non-finite values are `null`.
+    #[cfg(feature = "std")]
+    pub fn write_json<W: Write>(&self, mut writer: W) -> Result<()> {
+        write!(writer, "[").map_err(io)?;
+        for (i, record) in self.records.iter().enumerate() {
+            write!(
+                writer,
+                "{}{{\"step\":{},\"delta\":{},\"violation\":{}}}",
+                if i == 0 { "" } else { "," },
+                record.step,
+                json_value(Some(record.delta)),
+                json_value(record.violation),
+            )
+            .map_err(io)?;
+        }
+        writeln!(writer, "]").map_err(io)?;
+        writer.flush().map_err(io)
+    }
+}
+
+impl core::ops::Index<usize> for History {
+    type Output = Record;
+
+    fn index(&self, i: usize) -> &Record {
+        &self.records[i]
+    }
+}
+
+#[cfg(feature = "std")]
+fn csv_cell(value: Option<f32>) -> String {
+    match value.filter(|v| v.is_finite()) {
+        Some(v) => v.to_string(),
+        None => String::new(),
+    }
+}
+
+#[cfg(feature = "std")]
+fn json_value(value: Option<f32>) -> String {
+    match value.filter(|v| v.is_finite()) {
+        Some(v) => v.to_string(),
+        None => "null".to_string(),
+    }
+}
+
+#[cfg(feature = "std")]
+fn io(err: std::io::Error) -> Error {
+    Error::Unknown(Box::new(err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history() -> History {
+        let mut history = History::new();
+        history.push(Record {
+            step: 0,
+            delta: 0.5,
+            violation: Some(1.0),
+        });
+        history.push(Record {
+            step: 1,
+            delta: f32::NAN,
+            violation: None,
+        });
+        history
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut bytes = vec![];
+        history().write_csv(&mut bytes).unwrap();
+        assert_eq!(
+            String::from_utf8(bytes).unwrap(),
+            "step,delta,violation\n0,0.5,1\n1,,\n"
+        );
+    }
+
+    #[test]
+    fn test_write_json() {
+        let mut bytes = vec![];
+        history().write_json(&mut bytes).unwrap();
+        assert_eq!(
+            String::from_utf8(bytes).unwrap(),
+            "[{\"step\":0,\"delta\":0.5,\"violation\":1},\
+             {\"step\":1,\"delta\":null,\"violation\":null}]\n"
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{errors::Error, history::History, Result};
+use plotters::{coord::Shift, prelude::*};
+use std::path::Path;
+
+// Log scales can't show zero, so smaller values are drawn at this floor.
+const FLOOR: f32 = 1e-12;
+
+impl History {
+    // Draws delta against the step on a log scale and, when violations were
+    // recorded, the violation below it on the same steps.
+    pub fn render_png(&self, path: impl AsRef<Path>) -> Result<()> {
+        let violations: Vec<(usize, f32)> = self
+            .records()
+            .iter()
+            .filter_map(|r| r.violation.map(|v| (r.step, v)))
+            .collect();
+        let deltas: Vec<(usize, f32)> = self.records().iter().map(|r| (r.step, r.delta)).collect();
+
+        let height = if violations.is_empty() { 450 } else { 800 };
+        let root = BitMapBackend::new(path.as_ref(), (800, height)).into_drawing_area();
+        root.fill(&WHITE).map_err(plot)?;
+        match violations.is_empty() {
+            true => draw(&root, "delta", &deltas, &BLUE)?,
+            false => {
+                let (top, bottom) = root.split_vertically(height / 2);
+                draw(&top, "delta", &deltas, &BLUE)?;
+                draw(&bottom, "violation", &violations, &RED)?;
+            }
+        }
+        root.present().map_err(plot)
+    }
+}
+
+fn draw(
+    area: &DrawingArea<BitMapBackend, Shift>,
+    label: &str,
+    points: &[(usize, f32)],
+    color: &RGBColor,
+) -> Result<()> {
+    let points: Vec<(usize, f32)> = points
+        .iter()
+        .filter(|(_, v)| v.is_finite())
+        .map(|&(t, v)| (t, v.max(FLOOR)))
+        .collect();
+    let steps = points.first().map_or(0, |p| p.0)..points.last().map_or(1, |p| p.0 + 1);
+    let (lo, hi) = points
+        .iter()
+        .fold((f32::INFINITY, FLOOR), |(lo, hi), &(_, v)| {
+            (lo.min(v), hi.max(v))
+        });
+
+    let mut chart = ChartBuilder::on(area)
+        .caption(format!("{label} per step"), ("sans-serif", 20))
+        .margin(10)
+        .x_label_area_size(30)
+        .y_label_area_size(70)
+        .build_cartesian_2d(steps, (lo.min(hi)..hi * 10.0).log_scale())
+        .map_err(plot)?;
+    chart
+        .configure_mesh()
+        .x_desc("step")
+        .y_desc(label)
+        .y_label_formatter(&|v| format!("{v:.0e}"))
+        .draw()
+        .map_err(plot)?;
+    chart
+        .draw_series(LineSeries::new(points, color))
+        .map_err(plot)?;
+    Ok(())
+}
+
+fn plot<E>(err: DrawingAreaErrorKind<E>) -> Error
+where
+    E: std::error::Error + Send + Sync,
+{
+    Error::Unknown(err.to_string().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        history::{History, Record},
+        norms::euclidean_contiguous,
+        projectors::{box_constraints::BoxProjector, hyperplane::HyperplaneProjector},
+        solvers::divide_and_concur::DivideAndConcurSolver,
+        states::vector::VecState,
+    };
+
+    #[test]
+    fn test_render_png() {
+        let solver = DivideAndConcurSolver::new(
+            BoxProjector::new(0.0, 1.0),
+            HyperplaneProjector::new(vec![1.0, 1.0], 1.5),
+            euclidean_contiguous,
+            0.8,
+            1e-6,
+            1000,
+        )
+        .with_feasibility(|s: &VecState| (s[0] + s[1] - 1.5).abs());
+        let (result, history) = solver.run_with_history(VecState(vec![2.0, -1.0]));
+        result.unwrap();
+
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("drs-history-{}.png", std::process::id()));
+        history.render_png(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(&bytes[1..4], b"PNG");
+
+        // Nothing recorded still draws empty axes.
+        let path = dir.join(format!("drs-empty-{}.png", std::process::id()));
+        History::new().render_png(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let mut history = History::new();
+        history.push(Record {
+            step: 0,
+            delta: 0.0,
+            violation: Some(0.0),
+        });
+        let path = dir.join(format!("drs-zero-{}.png", std::process::id()));
+        history.render_png(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+    }
+}
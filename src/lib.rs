@@ -1,20 +1,342 @@
+// Without the default `std` feature only the solver core builds: the traits,
+// errors, norms, states and `DivideAndConcurSolver`, on top of `alloc`.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod checkpoint;
+pub mod config;
+pub mod display;
+#[cfg(feature = "distributed")]
+pub mod distributed;
 pub mod errors;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod history;
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+pub mod linalg;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod norms;
+#[cfg(feature = "npy")]
+pub mod npy;
+#[cfg(feature = "rayon")]
+pub mod parallel;
 pub mod prelude;
+#[cfg(feature = "std")]
+pub mod problem;
+#[cfg(feature = "std")]
+pub mod problems;
+#[cfg(feature = "std")]
+pub mod projectors;
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
+#[cfg(feature = "std")]
+pub mod prox;
+#[cfg(feature = "r")]
+pub mod r;
+#[cfg(feature = "serde")]
+pub mod schema;
+pub mod snapshot;
+pub mod solution;
 pub mod solvers;
+pub mod states;
+#[cfg(feature = "otel")]
+pub mod telemetry;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "tokio")]
+pub mod tokio;
+pub mod tolerances;
+pub mod validation;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-use std::ops::{Add, Mul};
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    iter::Sum,
+    ops::{
+        Add, AddAssign, ControlFlow, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Sub,
+        SubAssign,
+    },
+};
 
-pub type Result<T> = std::result::Result<T, crate::errors::Error>;
+pub type Result<T> = core::result::Result<T, crate::errors::Error>;
 pub type SolverSolution<T> = (T, usize, f32);
+pub type Feasibility<S> = Box<dyn Fn(&S) -> f32 + Send + Sync>;
+pub type Monitor = Box<dyn Fn(usize, f32) -> ControlFlow<()> + Send + Sync>;
+pub type BoundedNorm<S> = Box<dyn Fn(&S, &S, f32) -> f32 + Send + Sync>;
+
+// The only arithmetic the solvers need. Anything with `Add` and `Mul<f32>` gets it
+// for free; other backends can implement the fused forms directly.
+pub trait LinearCombine: Sized {
+    fn scale(self, alpha: f32) -> Self;
+
+    // self + alpha * y
+    fn axpy(self, alpha: f32, y: Self) -> Self;
+}
+
+impl<T> LinearCombine for T
+where
+    T: Add<Output = T> + Mul<f32, Output = T>,
+{
+    fn scale(self, alpha: f32) -> Self {
+        self * alpha
+    }
+
+    fn axpy(self, alpha: f32, y: Self) -> Self {
+        self + y * alpha
+    }
+}
+
+pub trait State: Clone + core::fmt::Debug + LinearCombine {
+    // Work vectors for solvers and accelerators; override when scaling a clone by
+    // zero is wasteful or when the state may hold non-finite values.
+    fn zeros_like(&self) -> Self {
+        self.clone().scale(0.0)
+    }
+
+    // self + alpha * y, leaving y's buffer with the caller so the step can
+    // reuse it. The default clones y; override where the update runs in place.
+    fn axpy_ref(self, alpha: f32, y: &Self) -> Self {
+        self.axpy(alpha, y.clone())
+    }
+}
+
+// The floating-point types the generic projectors and problems work in, each
+// with the contiguous state that holds it. Only the coordinates take the
+// scalar: step coefficients (beta), tolerances (epsilon), norms and so deltas
+// stay f32 whatever it is, so an f64 solve converges to f32 deltas.
+pub trait Scalar:
+    Copy
+    + Send
+    + Sync
+    + Debug
+    + Display
+    + PartialOrd
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + Sum
+    + 'static
+{
+    type Vector: State
+        + AsRef<[Self]>
+        + AsMut<[Self]>
+        + Deref<Target = Vec<Self>>
+        + DerefMut
+        + From<Vec<Self>>
+        + Send
+        + Sync;
+
+    const ZERO: Self;
+
+    const ONE: Self;
+
+    fn from_f32(x: f32) -> Self;
+
+    fn from_usize(n: usize) -> Self;
+
+    fn to_f32(self) -> f32;
+
+    fn to_f64(self) -> f64;
+
+    fn sqrt(self) -> Self;
+
+    fn abs(self) -> Self;
+
+    fn signum(self) -> Self;
+
+    fn max(self, other: Self) -> Self;
+
+    fn min(self, other: Self) -> Self;
+
+    fn round(self) -> Self;
+
+    fn floor(self) -> Self;
+
+    fn ceil(self) -> Self;
+
+    fn is_finite(self) -> bool;
+
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+// `round`, `floor` and `ceil` live in `std`, so `no_std` builds go through
+// `libm` as `norms::sqrt` does.
+macro_rules! scalar {
+    ($t:ident, $vector:ty, $sqrt:path, $round:path, $floor:path, $ceil:path) => {
+        impl Scalar for $t {
+            type Vector = $vector;
+
+            const ZERO: Self = 0.0;
+
+            const ONE: Self = 1.0;
+
+            fn from_f32(x: f32) -> Self {
+                x as $t
+            }
+
+            fn from_usize(n: usize) -> Self {
+                n as $t
+            }
 
-pub trait State: Clone + std::fmt::Debug + Add<Output = Self> + Mul<f32, Output = Self> {}
+            fn to_f32(self) -> f32 {
+                self as f32
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn sqrt(self) -> Self {
+                $sqrt(self)
+            }
+
+            fn abs(self) -> Self {
+                $t::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                $t::signum(self)
+            }
+
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+
+            #[cfg(feature = "std")]
+            fn round(self) -> Self {
+                $t::round(self)
+            }
+
+            #[cfg(not(feature = "std"))]
+            fn round(self) -> Self {
+                $round(self)
+            }
+
+            #[cfg(feature = "std")]
+            fn floor(self) -> Self {
+                $t::floor(self)
+            }
+
+            #[cfg(not(feature = "std"))]
+            fn floor(self) -> Self {
+                $floor(self)
+            }
+
+            #[cfg(feature = "std")]
+            fn ceil(self) -> Self {
+                $t::ceil(self)
+            }
+
+            #[cfg(not(feature = "std"))]
+            fn ceil(self) -> Self {
+                $ceil(self)
+            }
+
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                $t::total_cmp(self, other)
+            }
+        }
+    };
+}
+
+scalar!(
+    f32,
+    states::vector::VecState,
+    norms::sqrt,
+    libm::roundf,
+    libm::floorf,
+    libm::ceilf
+);
+scalar!(
+    f64,
+    states::double::VecState64,
+    norms::sqrt64,
+    libm::round,
+    libm::floor,
+    libm::ceil
+);
+
+// Optional coordinate access, enabling the generic norms and checks in `norms`.
+pub trait ScalarAccess {
+    fn scalars(&self) -> impl Iterator<Item = f32> + '_;
+
+    fn scalars_mut(&mut self) -> impl Iterator<Item = &mut f32> + '_;
+}
+
+impl ScalarAccess for Vec<f32> {
+    fn scalars(&self) -> impl Iterator<Item = f32> + '_ {
+        self.iter().copied()
+    }
+
+    fn scalars_mut(&mut self) -> impl Iterator<Item = &mut f32> + '_ {
+        self.iter_mut()
+    }
+}
+
+impl<const N: usize> ScalarAccess for [f32; N] {
+    fn scalars(&self) -> impl Iterator<Item = f32> + '_ {
+        self.iter().copied()
+    }
+
+    fn scalars_mut(&mut self) -> impl Iterator<Item = &mut f32> + '_ {
+        self.iter_mut()
+    }
+}
+
+pub trait Projector<S> {
+    fn project(&self, state: S) -> Result<S>;
+}
+
+impl<S, F> Projector<S> for F
+where
+    F: Fn(S) -> Result<S>,
+{
+    fn project(&self, state: S) -> Result<S> {
+        self(state)
+    }
+}
+
+impl<S> Projector<S> for Box<dyn Projector<S>> {
+    fn project(&self, state: S) -> Result<S> {
+        (**self).project(state)
+    }
+}
+
+impl<S> Projector<S> for Box<dyn Projector<S> + Send + Sync> {
+    fn project(&self, state: S) -> Result<S> {
+        (**self).project(state)
+    }
+}
 
 pub trait Solver<S, D, C, N>
 where
     S: State,
-    D: Fn(S) -> Result<S>,
-    C: Fn(S) -> Result<S>,
+    D: Projector<S>,
+    C: Projector<S>,
     N: Fn(&S, &S) -> f32,
 {
     fn run(&self, initial_state: S) -> Result<SolverSolution<S>>;
+
+    fn validate(&self, sample_state: &S) -> Result<crate::validation::ValidationReport>;
 }
@@ -0,0 +1,105 @@
+use crate::{errors::Error, Result};
+
+#[cfg(feature = "faer")]
+mod faer;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+#[cfg(feature = "faer")]
+pub use self::faer::Faer;
+#[cfg(feature = "nalgebra")]
+pub use self::nalgebra::Nalgebra;
+
+// The backend the projectors use unless given one with `new_in`: nalgebra when
+// its feature is on, faer otherwise.
+#[cfg(feature = "nalgebra")]
+pub type DefaultBackend = Nalgebra;
+#[cfg(not(feature = "nalgebra"))]
+pub type DefaultBackend = Faer;
+
+// The dense factorizations behind the affine, PSD and rank projectors, on
+// column-major matrices of `T`. Implementors are zero-sized markers, and both
+// backends provide them at f32 and f64.
+pub trait LinalgBackend<T = f32>:
+    core::fmt::Debug + Clone + Copy + Default + PartialEq + Eq + Send + Sync + 'static
+{
+    // A factorization of A Aᵀ, computed once and reused by every projection.
+    type Gram: core::fmt::Debug + Clone + Send + Sync;
+
+    // Fails when A does not have full row rank.
+    fn factor_gram(nrows: usize, ncols: usize, a: &[T]) -> Result<Self::Gram>;
+
+    // Overwrites `rhs` with (A Aᵀ)⁻¹ rhs.
+    fn solve_gram(gram: &Self::Gram, rhs: &mut [T]);
+
+    // Clips the eigenvalues of the symmetric part of the n × n matrix at zero.
+    fn project_psd(n: usize, values: &mut [T]) -> Result<()>;
+
+    // Keeps only the `k` largest singular values, with `k` below the full rank.
+    fn truncate_rank(k: usize, nrows: usize, ncols: usize, values: &mut [T]) -> Result<()>;
+}
+
+pub(crate) fn not_full_rank() -> Error {
+    Error::Factorization("A Aᵀ is not positive definite, A must have full row rank".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-4, "{actual:?} != {expected:?}");
+        }
+    }
+
+    // The same answers from every backend that is built.
+    fn check<B: LinalgBackend<f32> + LinalgBackend<f64>>() {
+        // [[1, 0, 0], [0, 1, -1]]
+        let a = [1.0, 0.0, 0.0, 1.0, 0.0, -1.0];
+        let gram = <B as LinalgBackend<f32>>::factor_gram(2, 3, &a).unwrap();
+        let mut rhs = [1.0, 4.0];
+        <B as LinalgBackend<f32>>::solve_gram(&gram, &mut rhs);
+        assert_close(&rhs, &[1.0, 2.0]);
+        assert!(matches!(
+            <B as LinalgBackend<f32>>::factor_gram(2, 2, &[1.0, 0.0, 0.0, 0.0]),
+            Err(Error::Factorization(_))
+        ));
+
+        let a = a.map(f64::from);
+        let gram = <B as LinalgBackend<f64>>::factor_gram(2, 3, &a).unwrap();
+        let mut rhs = [1.0f64, 4.0];
+        <B as LinalgBackend<f64>>::solve_gram(&gram, &mut rhs);
+        assert!((rhs[0] - 1.0).abs() < 1e-12 && (rhs[1] - 2.0).abs() < 1e-12);
+
+        let mut values = [1.0f32, 2.0, 2.0, 1.0];
+        B::project_psd(2, &mut values).unwrap();
+        assert_close(&values, &[1.5, 1.5, 1.5, 1.5]);
+        let mut values = [1.0f64, 2.0, 2.0, 1.0];
+        B::project_psd(2, &mut values).unwrap();
+        assert!(values.iter().all(|x| (x - 1.5).abs() < 1e-12));
+
+        let mut values = [1.0f32, 0.0, 0.0, 2.0, 0.0, 0.0];
+        B::truncate_rank(1, 2, 3, &mut values).unwrap();
+        assert_close(&values, &[0.0, 0.0, 0.0, 2.0, 0.0, 0.0]);
+        let mut values = [1.0f64, 0.0, 0.0, 2.0, 0.0, 0.0];
+        B::truncate_rank(1, 2, 3, &mut values).unwrap();
+        assert!(values
+            .iter()
+            .all(|x| (x - 2.0).abs() < 1e-12 || x.abs() < 1e-12));
+        assert!((values[3] - 2.0).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_nalgebra() {
+        check::<Nalgebra>();
+    }
+
+    #[cfg(feature = "faer")]
+    #[test]
+    fn test_faer() {
+        check::<Faer>();
+    }
+}
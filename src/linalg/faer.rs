@@ -0,0 +1,72 @@
+use crate::{
+    errors::Error,
+    linalg::{not_full_rank, LinalgBackend},
+    Result, Scalar,
+};
+use ::faer::{
+    linalg::solvers::{Llt, Solve},
+    traits::RealField,
+    Mat, MatMut, MatRef, Side,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Faer;
+
+impl<T> LinalgBackend<T> for Faer
+where
+    T: Scalar + RealField,
+{
+    type Gram = Llt<T>;
+
+    fn factor_gram(nrows: usize, ncols: usize, a: &[T]) -> Result<Self::Gram> {
+        let a = MatRef::from_column_major_slice(a, nrows, ncols);
+        (a * a.transpose())
+            .llt(Side::Lower)
+            .map_err(|_| not_full_rank())
+    }
+
+    fn solve_gram(gram: &Self::Gram, rhs: &mut [T]) {
+        let n = rhs.len();
+        gram.solve_in_place(MatMut::from_column_major_slice_mut(rhs, n, 1));
+    }
+
+    fn project_psd(n: usize, values: &mut [T]) -> Result<()> {
+        let half = <T as Scalar>::from_f32(0.5);
+        let matrix = MatRef::from_column_major_slice(values, n, n);
+        let symmetric = Mat::from_fn(n, n, |i, j| half * (matrix[(i, j)] + matrix[(j, i)]));
+        let eigen = symmetric
+            .self_adjoint_eigen(Side::Lower)
+            .map_err(|err| Error::Factorization(format!("{err:?}")))?;
+
+        let u = eigen.U();
+        let eigenvalues = eigen.S().column_vector();
+        let scaled = Mat::from_fn(n, n, |i, j| {
+            u[(i, j)] * Scalar::max(eigenvalues[j], T::ZERO)
+        });
+        write(values, scaled * u.transpose());
+        Ok(())
+    }
+
+    fn truncate_rank(k: usize, nrows: usize, ncols: usize, values: &mut [T]) -> Result<()> {
+        let matrix = MatRef::from_column_major_slice(values, nrows, ncols);
+        let svd = matrix
+            .thin_svd()
+            .map_err(|err| Error::Factorization(format!("{err:?}")))?;
+
+        // Sorted in nonincreasing order, so the first `k` are kept.
+        let (u, v) = (svd.U().subcols(0, k), svd.V().subcols(0, k));
+        let singular_values = svd.S().column_vector();
+        let scaled = Mat::from_fn(nrows, k, |i, j| u[(i, j)] * singular_values[j]);
+        write(values, scaled * v.transpose());
+        Ok(())
+    }
+}
+
+fn write<T: Copy>(values: &mut [T], matrix: Mat<T>) {
+    let nrows = matrix.nrows();
+    for (j, column) in values.chunks_exact_mut(nrows).enumerate() {
+        for (i, x) in column.iter_mut().enumerate() {
+            *x = matrix[(i, j)];
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{
+    errors::Error,
+    linalg::{not_full_rank, LinalgBackend},
+    Result, Scalar,
+};
+use ::nalgebra::{linalg::Cholesky, DMatrix, DVectorViewMut, Dyn, RealField};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Nalgebra;
+
+impl<T> LinalgBackend<T> for Nalgebra
+where
+    T: Scalar + RealField,
+{
+    type Gram = Cholesky<T, Dyn>;
+
+    fn factor_gram(nrows: usize, ncols: usize, a: &[T]) -> Result<Self::Gram> {
+        let a = DMatrix::from_column_slice(nrows, ncols, a);
+        Cholesky::new(&a * a.transpose()).ok_or_else(not_full_rank)
+    }
+
+    fn solve_gram(gram: &Self::Gram, rhs: &mut [T]) {
+        let n = rhs.len();
+        gram.solve_mut(&mut DVectorViewMut::from_slice(rhs, n));
+    }
+
+    fn project_psd(n: usize, values: &mut [T]) -> Result<()> {
+        let matrix = DMatrix::from_column_slice(n, n, values);
+        let symmetric = (&matrix + matrix.transpose()) * <T as Scalar>::from_f32(0.5);
+        let mut eigen = symmetric.symmetric_eigen();
+        eigen.eigenvalues.apply(|l| *l = Scalar::max(*l, T::ZERO));
+
+        values.copy_from_slice(eigen.recompose().as_slice());
+        Ok(())
+    }
+
+    fn truncate_rank(k: usize, nrows: usize, ncols: usize, values: &mut [T]) -> Result<()> {
+        let matrix = DMatrix::from_column_slice(nrows, ncols, values);
+        let mut svd = matrix.svd(true, true);
+
+        // nalgebra doesn't sort the singular values.
+        let mut order: Vec<usize> = (0..nrows.min(ncols)).collect();
+        order.sort_by(|&i, &j| Scalar::total_cmp(&svd.singular_values[j], &svd.singular_values[i]));
+        for &i in &order[k..] {
+            svd.singular_values[i] = T::ZERO;
+        }
+
+        let projected = svd
+            .recompose()
+            .map_err(|err| Error::Factorization(err.to_string()))?;
+        values.copy_from_slice(projected.as_slice());
+        Ok(())
+    }
+}
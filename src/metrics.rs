@@ -0,0 +1,102 @@
+use crate::{
+    errors::{Error, ProjectorKind},
+    Result, SolverSolution,
+};
+use ::metrics::{counter, histogram};
+use std::time::Duration;
+
+// Emitted through the `metrics` facade, so they go to whichever recorder the
+// application installs, under these names.
+
+// Counter of steps taken by solver runs, including argmin-driven ones.
+pub const ITERATIONS: &str = "drs_iterations_total";
+// Counter of finished runs, labelled by `outcome`: `converged`, `convergence`,
+// `divergence`, `cancelled` or `error`.
+pub const RUNS: &str = "drs_runs_total";
+// Histogram of the delta of each of those steps.
+pub const DELTA: &str = "drs_delta";
+// Histogram of seconds spent in each projection, labelled by `phase`: `divide`
+// or `concur`.
+pub const PROJECTION_SECONDS: &str = "drs_projection_seconds";
+// Counter of restarts and kicks by the searches that perturb a stalled
+// iterate, labelled by `search`.
+pub const RESTARTS: &str = "drs_restarts_total";
+
+pub(crate) fn step(delta: f32) {
+    counter!(ITERATIONS).increment(1);
+    histogram!(DELTA).record(delta as f64);
+}
+
+pub(crate) fn projection(kind: ProjectorKind, elapsed: Duration) {
+    let phase = match kind {
+        ProjectorKind::Divide => "divide",
+        ProjectorKind::Concur => "concur",
+    };
+    histogram!(PROJECTION_SECONDS, "phase" => phase).record(elapsed.as_secs_f64());
+}
+
+pub(crate) fn run<S>(result: &Result<SolverSolution<S>>) {
+    let outcome = match result {
+        Ok(_) => "converged",
+        Err(Error::Convergence(..)) => "convergence",
+        Err(Error::Diverged(..)) => "diverged",
+        Err(Error::Cancelled(_)) => "cancelled",
+        Err(_) => "error",
+    };
+    counter!(RUNS, "outcome" => outcome).increment(1);
+}
+
+pub(crate) fn restart(search: &'static str) {
+    counter!(RESTARTS, "search" => search).increment(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        norms::euclidean_contiguous,
+        projectors::{box_constraints::BoxProjector, hyperplane::HyperplaneProjector},
+        solvers::divide_and_concur::DivideAndConcurSolver,
+        states::vector::VecState,
+        Solver,
+    };
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+    #[test]
+    fn test_run_metrics() {
+        let solver = DivideAndConcurSolver::new(
+            BoxProjector::new(0.0, 1.0),
+            HyperplaneProjector::new(vec![1.0, 1.0], 1.5),
+            euclidean_contiguous,
+            0.8,
+            1e-6,
+            1000,
+        );
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let (_, steps, _) =
+            ::metrics::with_local_recorder(&recorder, || solver.run(VecState(vec![2.0, -1.0])))
+                .unwrap();
+
+        let mut divides = 0;
+        for (key, _, _, value) in snapshotter.snapshot().into_vec() {
+            let (_, key) = key.into_parts();
+            let label = key.labels().next().map(|l| l.value().to_string());
+            match (key.name(), label.as_deref(), value) {
+                (ITERATIONS, None, DebugValue::Counter(n)) => assert_eq!(n, steps as u64 + 1),
+                (RUNS, Some(outcome), DebugValue::Counter(n)) => {
+                    assert_eq!((outcome, n), ("converged", 1))
+                }
+                (DELTA, None, DebugValue::Histogram(deltas)) => {
+                    assert_eq!(deltas.len(), steps + 1)
+                }
+                (PROJECTION_SECONDS, Some("divide"), DebugValue::Histogram(times)) => {
+                    divides = times.len()
+                }
+                _ => {}
+            }
+        }
+        // One per step; the solution is the last step's divide projection.
+        assert_eq!(divides, steps + 1);
+    }
+}
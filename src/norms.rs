@@ -0,0 +1,181 @@
+use crate::{states::kernels, ScalarAccess};
+use core::ops::Sub;
+
+pub fn euclidean<S>(current: &S, previous: &S) -> f32
+where
+    S: ScalarAccess,
+{
+    let squared = current
+        .scalars()
+        .zip(previous.scalars())
+        .map(|(c, p)| (c - p) * (c - p))
+        .sum::<f32>();
+    sqrt(squared)
+}
+
+// Slice-backed fast path, parallel for large states under the `rayon` feature.
+pub fn euclidean_contiguous<S>(current: &S, previous: &S) -> f32
+where
+    S: AsRef<[f32]>,
+{
+    sqrt(kernels::squared_distance(
+        current.as_ref(),
+        previous.as_ref(),
+    ))
+}
+
+// Compensated summation over vectorizable lanes, for large states where the
+// rounding of a plain f32 sum starts to show in delta.
+pub fn euclidean_compensated<S>(current: &S, previous: &S) -> f32
+where
+    S: AsRef<[f32]>,
+{
+    sqrt(kernels::compensated_squared_distance(
+        current.as_ref(),
+        previous.as_ref(),
+    ))
+}
+
+// For `with_bounded_norm`: sums block by block and stops as soon as the
+// distance reaches `bound`, returning the partial distance.
+pub fn euclidean_bounded<S>(current: &S, previous: &S, bound: f32) -> f32
+where
+    S: AsRef<[f32]>,
+{
+    const BLOCK: usize = 4096;
+    let limit = bound * bound;
+    let mut squared = 0.0;
+    for (c, p) in current
+        .as_ref()
+        .chunks(BLOCK)
+        .zip(previous.as_ref().chunks(BLOCK))
+    {
+        squared += kernels::squared_distance(c, p);
+        if squared >= limit {
+            break;
+        }
+    }
+    sqrt(squared)
+}
+
+pub fn infinity<S>(current: &S, previous: &S) -> f32
+where
+    S: ScalarAccess,
+{
+    current
+        .scalars()
+        .zip(previous.scalars())
+        .map(|(c, p)| (c - p).abs())
+        .fold(0.0, f32::max)
+}
+
+pub fn l2<S>(state: &S) -> f32
+where
+    S: ScalarAccess,
+{
+    sqrt(state.scalars().map(|x| x * x).sum::<f32>())
+}
+
+pub fn max_abs<S>(state: &S) -> f32
+where
+    S: ScalarAccess,
+{
+    state.scalars().map(f32::abs).fold(0.0, f32::max)
+}
+
+// For states with a borrowed `Sub`: forms `current - previous` once and measures
+// it, instead of a norm walking both states pairwise. The state type usually has
+// to be named, as in `of_difference::<MyState, _>(l2)`.
+pub fn of_difference<S, M>(magnitude: M) -> impl Fn(&S, &S) -> f32
+where
+    for<'a> &'a S: Sub<&'a S, Output = S>,
+    M: Fn(&S) -> f32,
+{
+    move |current, previous| magnitude(&(current - previous))
+}
+
+pub fn all_finite<S>(state: &S) -> bool
+where
+    S: ScalarAccess,
+{
+    state.scalars().all(f32::is_finite)
+}
+
+// Accumulates in f64 and only narrows the final distance for the solver.
+pub fn euclidean64<S>(current: &S, previous: &S) -> f32
+where
+    S: AsRef<[f64]>,
+{
+    sqrt64(kernels::squared_distance(
+        current.as_ref(),
+        previous.as_ref(),
+    )) as f32
+}
+
+// `f32::sqrt` lives in `std`, so `no_std` builds go through `libm`.
+#[cfg(feature = "std")]
+pub(crate) fn sqrt(x: f32) -> f32 {
+    x.sqrt()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn sqrt(x: f32) -> f32 {
+    libm::sqrtf(x)
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn sqrt64(x: f64) -> f64 {
+    x.sqrt()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn sqrt64(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_euclidean() {
+        assert_eq!(euclidean(&vec![3.0, 0.0], &vec![0.0, 4.0]), 5.0);
+        assert_eq!(euclidean(&[1.0f32, 2.0], &[1.0, 2.0]), 0.0);
+        assert_eq!(euclidean64(&vec![3.0f64, 0.0], &vec![0.0, 4.0]), 5.0);
+        assert_eq!(euclidean_contiguous(&vec![3.0, 0.0], &vec![0.0, 4.0]), 5.0);
+        assert_eq!(euclidean_compensated(&vec![3.0, 0.0], &vec![0.0, 4.0]), 5.0);
+    }
+
+    #[test]
+    fn test_euclidean_bounded() {
+        let (a, b) = (vec![1.0; 10_000], vec![0.0; 10_000]);
+        assert_eq!(euclidean_bounded(&a, &b, 1000.0), 100.0);
+
+        // Stops after the first block, which already reaches the bound.
+        let early = euclidean_bounded(&a, &b, 10.0);
+        assert!((10.0..100.0).contains(&early));
+    }
+
+    #[test]
+    fn test_infinity() {
+        assert_eq!(infinity(&vec![3.0, 0.0], &vec![0.0, -4.0]), 4.0);
+        assert_eq!(infinity(&[1.0f32], &[1.0]), 0.0);
+    }
+
+    #[test]
+    fn test_of_difference() {
+        use crate::states::vector::VecState;
+
+        let (a, b) = (VecState(vec![3.0, 0.0]), VecState(vec![0.0, -4.0]));
+        assert_eq!(of_difference::<VecState, _>(l2)(&a, &b), 5.0);
+        assert_eq!(of_difference::<VecState, _>(max_abs)(&a, &b), 4.0);
+        assert_eq!(&a - &b, VecState(vec![3.0, 4.0]));
+    }
+
+    #[test]
+    fn test_all_finite() {
+        assert!(all_finite(&vec![0.0, 1.0]));
+        assert!(!all_finite(&vec![0.0, f32::NAN]));
+        assert!(!all_finite(&[f32::INFINITY]));
+    }
+}
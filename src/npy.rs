@@ -0,0 +1,372 @@
+use crate::{errors::Error, states::vector::VecState, Result};
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Seek, Write},
+    path::Path,
+};
+use zip::{write::SimpleFileOptions, ZipArchive, ZipWriter};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+// An n-dimensional array in C order, the layout NumPy defaults to. Arrays of
+// any real or boolean dtype read into `f32`; arrays are written as `<f4`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Array {
+    pub shape: Vec<usize>,
+    pub data: Vec<f32>,
+}
+
+impl Array {
+    pub fn new(shape: Vec<usize>, data: Vec<f32>) -> Self {
+        let len: usize = shape.iter().product();
+        if data.len() != len {
+            panic!(
+                "invalid array: expected {len} values for shape {shape:?}, got {}",
+                data.len()
+            );
+        }
+
+        Self { shape, data }
+    }
+
+    pub fn vector(data: Vec<f32>) -> Self {
+        Self {
+            shape: vec![data.len()],
+            data,
+        }
+    }
+}
+
+impl From<VecState> for Array {
+    fn from(state: VecState) -> Self {
+        Self::vector(state.0)
+    }
+}
+
+// Flattened in C order.
+impl From<Array> for VecState {
+    fn from(array: Array) -> Self {
+        VecState(array.data)
+    }
+}
+
+pub fn read_npy<R: Read>(mut reader: R) -> Result<Array> {
+    let mut preamble = [0u8; 8];
+    reader.read_exact(&mut preamble).map_err(io)?;
+    if &preamble[..6] != MAGIC {
+        return Err(invalid("missing magic string"));
+    }
+    let header_len = match preamble[6] {
+        1 => {
+            let mut len = [0u8; 2];
+            reader.read_exact(&mut len).map_err(io)?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0u8; 4];
+            reader.read_exact(&mut len).map_err(io)?;
+            u32::from_le_bytes(len) as usize
+        }
+        major => return Err(invalid(format!("unsupported version {major}"))),
+    };
+    let mut header = vec![0u8; header_len];
+    reader.read_exact(&mut header).map_err(io)?;
+    let header = String::from_utf8_lossy(&header);
+
+    let dtype = Dtype::parse(&field(&header, "descr")?)?;
+    let fortran_order = match field(&header, "fortran_order")?.as_str() {
+        "True" => true,
+        "False" => false,
+        other => return Err(invalid(format!("fortran_order {other}"))),
+    };
+    let shape = field(&header, "shape")?
+        .trim_matches(|c| c == '(' || c == ')')
+        .split(',')
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(|d| d.parse().map_err(|_| invalid(format!("dimension {d}"))))
+        .collect::<Result<Vec<usize>>>()?;
+
+    // Read to the end of the data rather than into a buffer sized from the
+    // header, so a bogus shape can't allocate more than the file holds.
+    let size = shape
+        .iter()
+        .try_fold(dtype.size, |size, &d| size.checked_mul(d))
+        .ok_or_else(|| invalid(format!("shape {shape:?} overflows")))?;
+    let mut bytes = Vec::new();
+    reader
+        .take(size as u64)
+        .read_to_end(&mut bytes)
+        .map_err(io)?;
+    if bytes.len() != size {
+        return Err(io(std::io::ErrorKind::UnexpectedEof.into()));
+    }
+    let values: Vec<f32> = bytes
+        .chunks_exact(dtype.size)
+        .map(|b| dtype.read(b))
+        .collect();
+
+    let data = match fortran_order {
+        true => to_c_order(&shape, &values),
+        false => values,
+    };
+    Ok(Array { shape, data })
+}
+
+// Version 1.0, with the header padded so the data starts 64-byte aligned.
+pub fn write_npy<W: Write>(mut writer: W, array: &Array) -> Result<()> {
+    let shape = match array.shape.as_slice() {
+        [n] => format!("({n},)"),
+        dims => format!(
+            "({})",
+            dims.iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let mut header = format!("{{'descr': '<f4', 'fortran_order': False, 'shape': {shape}, }}");
+    let unpadded = MAGIC.len() + 4 + header.len() + 1;
+    header.push_str(&" ".repeat(unpadded.next_multiple_of(64) - unpadded));
+    header.push('\n');
+
+    writer.write_all(MAGIC).map_err(io)?;
+    writer.write_all(&[1, 0]).map_err(io)?;
+    writer
+        .write_all(&(header.len() as u16).to_le_bytes())
+        .map_err(io)?;
+    writer.write_all(header.as_bytes()).map_err(io)?;
+    for x in &array.data {
+        writer.write_all(&x.to_le_bytes()).map_err(io)?;
+    }
+    writer.flush().map_err(io)
+}
+
+pub fn load_npy(path: impl AsRef<Path>) -> Result<Array> {
+    read_npy(BufReader::new(File::open(path).map_err(io)?))
+}
+
+pub fn save_npy(path: impl AsRef<Path>, array: &Array) -> Result<()> {
+    write_npy(BufWriter::new(File::create(path).map_err(io)?), array)
+}
+
+// The arrays of an `np.savez` or `np.savez_compressed` archive, in archive
+// order, named as NumPy names them, without the `.npy` suffix.
+pub fn read_npz<R: Read + Seek>(reader: R) -> Result<Vec<(String, Array)>> {
+    let mut archive = ZipArchive::new(reader).map_err(unknown)?;
+    (0..archive.len())
+        .map(|i| {
+            let file = archive.by_index(i).map_err(unknown)?;
+            let name = file.name().trim_end_matches(".npy").to_string();
+            Ok((name, read_npy(file)?))
+        })
+        .collect()
+}
+
+// Uncompressed, like `np.savez`.
+pub fn write_npz<W: Write + Seek>(writer: W, arrays: &[(&str, &Array)]) -> Result<()> {
+    let mut archive = ZipWriter::new(writer);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+    for (name, array) in arrays {
+        archive
+            .start_file(format!("{name}.npy"), options)
+            .map_err(unknown)?;
+        write_npy(&mut archive, array)?;
+    }
+    archive.finish().map_err(unknown)?;
+    Ok(())
+}
+
+pub fn load_npz(path: impl AsRef<Path>) -> Result<Vec<(String, Array)>> {
+    read_npz(BufReader::new(File::open(path).map_err(io)?))
+}
+
+pub fn save_npz(path: impl AsRef<Path>, arrays: &[(&str, &Array)]) -> Result<()> {
+    write_npz(BufWriter::new(File::create(path).map_err(io)?), arrays)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Dtype {
+    kind: u8,
+    size: usize,
+    big_endian: bool,
+}
+
+impl Dtype {
+    fn parse(descr: &str) -> Result<Self> {
+        let bytes = descr.as_bytes();
+        let (big_endian, kind, size) = match bytes {
+            [order @ (b'<' | b'>' | b'|' | b'='), kind, size @ ..] => {
+                (*order == b'>', *kind, std::str::from_utf8(size).ok())
+            }
+            _ => return Err(invalid(format!("dtype {descr}"))),
+        };
+        let size = size.and_then(|s| s.parse().ok());
+        match (kind, size) {
+            (b'f', Some(size @ (4 | 8)))
+            | (b'i' | b'u', Some(size @ (1 | 2 | 4 | 8)))
+            | (b'b', Some(size @ 1)) => Ok(Self {
+                kind,
+                size,
+                big_endian,
+            }),
+            _ => Err(invalid(format!("unsupported dtype {descr}"))),
+        }
+    }
+
+    fn read(&self, bytes: &[u8]) -> f32 {
+        let mut buf = [0u8; 8];
+        buf[..self.size].copy_from_slice(bytes);
+        if self.big_endian {
+            buf[..self.size].reverse();
+        }
+
+        match (self.kind, self.size) {
+            (b'f', 4) => f32::from_le_bytes(buf[..4].try_into().unwrap()),
+            (b'f', _) => f64::from_le_bytes(buf) as f32,
+            (b'u' | b'b', _) => u64::from_le_bytes(buf) as f32,
+            // Sign-extend from the top byte of the value.
+            (_, size) => {
+                let shift = 64 - 8 * size as u32;
+                ((i64::from_le_bytes(buf) << shift) >> shift) as f32
+            }
+        }
+    }
+}
+
+// Reads the value of `key` out of the header's Python dict literal.
+fn field(header: &str, key: &str) -> Result<String> {
+    let pattern = format!("'{key}':");
+    let start = header
+        .find(&pattern)
+        .ok_or_else(|| invalid(format!("missing {key}")))?
+        + pattern.len();
+    let rest = header[start..].trim_start();
+
+    let end = match rest.as_bytes().first() {
+        Some(b'\'') => rest[1..].find('\'').map(|i| i + 2),
+        Some(b'(') => rest.find(')').map(|i| i + 1),
+        _ => rest.find([',', '}']),
+    }
+    .ok_or_else(|| invalid(format!("unterminated {key}")))?;
+    Ok(rest[..end].trim().trim_matches('\'').to_string())
+}
+
+fn to_c_order(shape: &[usize], values: &[f32]) -> Vec<f32> {
+    let mut data = vec![0f32; values.len()];
+    let mut index = vec![0usize; shape.len()];
+    for &x in values {
+        let offset = index.iter().zip(shape).fold(0, |acc, (i, n)| acc * n + i);
+        data[offset] = x;
+        // Fortran order: the first axis varies fastest.
+        for (i, &n) in index.iter_mut().zip(shape) {
+            *i += 1;
+            if *i < n {
+                break;
+            }
+            *i = 0;
+        }
+    }
+    data
+}
+
+fn invalid(message: impl Into<String>) -> Error {
+    Error::Parse(1, format!("invalid npy header: {}", message.into()))
+}
+
+fn io(err: std::io::Error) -> Error {
+    Error::Unknown(Box::new(err))
+}
+
+fn unknown(err: zip::result::ZipError) -> Error {
+    Error::Unknown(Box::new(err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    // What `np.save` writes, with the dtype and order given.
+    fn numpy_bytes(descr: &str, fortran_order: bool, shape: &str, data: &[u8]) -> Vec<u8> {
+        let order = if fortran_order { "True" } else { "False" };
+        let mut header =
+            format!("{{'descr': '{descr}', 'fortran_order': {order}, 'shape': {shape}, }}");
+        while (10 + header.len() + 1) % 64 != 0 {
+            header.push(' ');
+        }
+        header.push('\n');
+
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend([1, 0]);
+        bytes.extend((header.len() as u16).to_le_bytes());
+        bytes.extend(header.as_bytes());
+        bytes.extend(data);
+        bytes
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let array = Array::new(vec![2, 3], vec![1.0, -2.0, 3.5, 0.0, 1e-8, 7.0]);
+        let mut bytes = vec![];
+        write_npy(&mut bytes, &array).unwrap();
+        assert_eq!((bytes.len() - 6 * 4) % 64, 0);
+        assert_eq!(read_npy(bytes.as_slice()).unwrap(), array);
+
+        let state = VecState(vec![0.25, 0.5]);
+        let mut bytes = vec![];
+        write_npy(&mut bytes, &state.clone().into()).unwrap();
+        assert!(String::from_utf8_lossy(&bytes).contains("'shape': (2,)"));
+        assert_eq!(VecState::from(read_npy(bytes.as_slice()).unwrap()), state);
+    }
+
+    #[test]
+    fn test_read_dtypes() {
+        let data: Vec<u8> = [1.5f64, -2.0]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect();
+        let array = read_npy(numpy_bytes("<f8", false, "(2,)", &data).as_slice()).unwrap();
+        assert_eq!(array.data, vec![1.5, -2.0]);
+
+        let data: Vec<u8> = [-3i16, 4].iter().flat_map(|x| x.to_be_bytes()).collect();
+        let array = read_npy(numpy_bytes(">i2", false, "(2,)", &data).as_slice()).unwrap();
+        assert_eq!(array.data, vec![-3.0, 4.0]);
+
+        let array = read_npy(numpy_bytes("|b1", false, "()", &[1]).as_slice()).unwrap();
+        assert_eq!((array.shape, array.data), (vec![], vec![1.0]));
+
+        // [[1, 2, 3], [4, 5, 6]] stored column by column.
+        let data: Vec<u8> = [1i32, 4, 2, 5, 3, 6]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect();
+        let array = read_npy(numpy_bytes("<i4", true, "(2, 3)", &data).as_slice()).unwrap();
+        assert_eq!(
+            array,
+            Array::new(vec![2, 3], vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+        );
+
+        let bytes = numpy_bytes("<c8", false, "(1,)", &[0; 8]);
+        assert!(matches!(read_npy(bytes.as_slice()), Err(Error::Parse(..))));
+
+        let shape = format!("({}, {})", usize::MAX, 2);
+        let bytes = numpy_bytes("<f4", false, &shape, &[]);
+        assert!(matches!(read_npy(bytes.as_slice()), Err(Error::Parse(..))));
+
+        let bytes = numpy_bytes("<f4", false, "(1000000000000,)", &[0; 8]);
+        assert!(matches!(read_npy(bytes.as_slice()), Err(Error::Unknown(_))));
+    }
+
+    #[test]
+    fn test_npz() {
+        let x = Array::vector(vec![1.0, 2.0]);
+        let y = Array::new(vec![1, 1], vec![3.0]);
+        let mut archive = Cursor::new(vec![]);
+        write_npz(&mut archive, &[("x", &x), ("y", &y)]).unwrap();
+
+        archive.set_position(0);
+        let arrays = read_npz(archive).unwrap();
+        assert_eq!(arrays, vec![("x".to_string(), x), ("y".to_string(), y)]);
+    }
+}
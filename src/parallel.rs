@@ -0,0 +1,71 @@
+use rayon::ThreadPool;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, RwLock,
+};
+
+// Below this many coordinates the rayon split costs more than it saves; see
+// `benches/parallel.rs` for where the crossover sits on a given machine.
+static THRESHOLD: AtomicUsize = AtomicUsize::new(1 << 16);
+
+// Product-space divides project their blocks in parallel from this many blocks
+// up. Blocks are whole projections, so far fewer of them pay for the split.
+static BLOCK_THRESHOLD: AtomicUsize = AtomicUsize::new(64);
+
+static POOL: RwLock<Option<Arc<ThreadPool>>> = RwLock::new(None);
+
+pub fn threshold() -> usize {
+    THRESHOLD.load(Ordering::Relaxed)
+}
+
+pub fn set_threshold(threshold: usize) {
+    THRESHOLD.store(threshold, Ordering::Relaxed);
+}
+
+pub fn block_threshold() -> usize {
+    BLOCK_THRESHOLD.load(Ordering::Relaxed)
+}
+
+pub fn set_block_threshold(threshold: usize) {
+    BLOCK_THRESHOLD.store(threshold, Ordering::Relaxed);
+}
+
+// Runs all of the crate's parallel work on `pool` instead of rayon's global
+// pool; `None` goes back to the global one.
+pub fn set_thread_pool(pool: Option<Arc<ThreadPool>>) {
+    *POOL.write().unwrap_or_else(|err| err.into_inner()) = pool;
+}
+
+pub fn thread_pool() -> Option<Arc<ThreadPool>> {
+    POOL.read().unwrap_or_else(|err| err.into_inner()).clone()
+}
+
+pub(crate) fn install<R, F>(op: F) -> R
+where
+    F: FnOnce() -> R + Send,
+    R: Send,
+{
+    match thread_pool() {
+        Some(pool) => pool.install(op),
+        None => op(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rayon::ThreadPoolBuilder;
+
+    #[test]
+    fn test_thread_pool() {
+        let pool = Arc::new(ThreadPoolBuilder::new().num_threads(3).build().unwrap());
+        set_thread_pool(Some(Arc::clone(&pool)));
+        let threads = install(rayon::current_num_threads);
+        let inside = install(|| rayon::current_thread_index().is_some());
+        set_thread_pool(None);
+
+        assert_eq!(threads, 3);
+        assert!(inside);
+        assert!(thread_pool().is_none());
+    }
+}
@@ -1,5 +1,131 @@
-pub use crate::errors::Error;
+#[cfg(feature = "arrow")]
+pub use crate::arrow::{column, indices, read_ipc, triplets};
+pub use crate::checkpoint::Checkpoint;
+pub use crate::config::SolverConfig;
+pub use crate::display::Html;
+#[cfg(feature = "distributed")]
+pub use crate::distributed::{
+    channel, ChannelTransport, Coordinator, Message, TcpTransport, Transport, Worker,
+};
+pub use crate::errors::{Error, ProjectorKind, ProjectorPanic};
+#[cfg(feature = "grpc")]
+pub use crate::grpc::SolveService;
+pub use crate::history::{History, Record};
+#[cfg(feature = "faer")]
+pub use crate::linalg::Faer;
+#[cfg(feature = "nalgebra")]
+pub use crate::linalg::Nalgebra;
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+pub use crate::linalg::{DefaultBackend, LinalgBackend};
+pub use crate::norms::{
+    all_finite, euclidean, euclidean64, euclidean_bounded, euclidean_compensated,
+    euclidean_contiguous, infinity, l2, max_abs, of_difference,
+};
+#[cfg(feature = "npy")]
+pub use crate::npy::{
+    load_npy, load_npz, read_npy, read_npz, save_npy, save_npz, write_npy, write_npz, Array,
+};
+#[cfg(feature = "std")]
+pub use crate::problem::{ConstraintProblem, ProblemState, Variable};
+#[cfg(feature = "fft")]
+pub use crate::problems::phase_retrieval::PhaseRetrieval;
+#[cfg(feature = "nalgebra")]
+pub use crate::problems::{
+    binary_feasibility::BinaryFeasibility,
+    compressed_sensing::CompressedSensing,
+    matrix_completion::{MatrixCompletion, ObservedProjector},
+};
+#[cfg(feature = "std")]
+pub use crate::problems::{
+    exact_cover::ExactCover,
+    latin_square::{AssignmentProjector, LatinSquare},
+    linear_feasibility::LinearFeasibility,
+    magic_square::MagicSquare,
+    n_queens::NQueens,
+    nonogram::{Nonogram, RunLengthProjector},
+    packing::{Container, OverlapProjector, Packing},
+    qubo::{EnergyProjector, Ising},
+    sat::{Assignment, ClauseProjector, Cnf, Incumbent, SatProblem},
+    sudoku::{Sudoku, SudokuSolution, SudokuState},
+    sudoku_generator::{GeneratedSudoku, SudokuGenerator},
+};
+#[cfg(feature = "fft")]
+pub use crate::projectors::fourier::{fourier_magnitudes, FourierMagnitudeProjector};
+#[cfg(feature = "nalgebra-sparse")]
+pub use crate::projectors::sparse_affine::SparseAffineProjector;
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+pub use crate::projectors::{affine::AffineProjector, psd::PsdProjector, rank::RankProjector};
+#[cfg(feature = "std")]
+pub use crate::projectors::{
+    approximate::ApproximateProjector,
+    ball::{L2BallProjector, SphereProjector},
+    binary::BinaryProjector,
+    box_constraints::BoxProjector,
+    combinators::{
+        average, compose, masked, reflect, Average, Composition, Masked, ReflectorProjector,
+    },
+    doubly_stochastic::DoublyStochasticProjector,
+    halfspace::HalfspaceProjector,
+    hyperplane::{BatchedHyperplaneProjector, HyperplaneProjector},
+    integer::IntegerProjector,
+    lift::{lift, lift_weighted, ProductConcur, ProductDivide},
+    nonnegative::NonNegativeProjector,
+    one_hot::OneHotProjector,
+    permutation::PermutationProjector,
+    simplex::SimplexProjector,
+    soc::SocProjector,
+    sparsity::SparsityProjector,
+    support::SupportProjector,
+};
+#[cfg(feature = "prometheus")]
+pub use crate::prometheus::{LiveSolve, LiveSolves};
+#[cfg(feature = "std")]
+pub use crate::prox::{ElasticNetProx, Indicator, L1Prox, SquaredL2Prox};
+#[cfg(feature = "r")]
+pub use crate::r::RProblem;
+#[cfg(feature = "serde")]
+pub use crate::schema::{ConstraintSpec, ProblemSpec, ProjectorSpec, SolverSpec};
+pub use crate::snapshot::StateSnapshot;
+pub use crate::solution::{RunStats, Solution};
+#[cfg(feature = "argmin")]
+pub use crate::solvers::divide_and_concur::argmin::ArgminState;
+#[allow(deprecated)]
+pub use crate::solvers::divide_and_concur::solution as divide_and_concur_solution;
 pub use crate::solvers::divide_and_concur::{
-    solution as divide_and_concur_solution, step as divide_and_concur_step, DivideAndConcurSolver,
+    candidate as divide_and_concur_candidate,
+    difference_map_step as divide_and_concur_difference_map_step, step as divide_and_concur_step,
+    step_mut as divide_and_concur_step_mut, step_with as divide_and_concur_step_with,
+    step_with_solution as divide_and_concur_step_with_solution, DivideAndConcurSolver,
+};
+pub use crate::solvers::workspace::StepWorkspace;
+#[cfg(feature = "complex")]
+pub use crate::states::complex::ComplexState;
+#[cfg(feature = "gpu")]
+pub use crate::states::gpu::{GpuContext, GpuState};
+#[cfg(feature = "half")]
+pub use crate::states::half_precision::{widened, HalfState, Widened};
+#[cfg(feature = "sprs")]
+pub use crate::states::sparse::SparseState;
+#[cfg(feature = "candle")]
+pub use crate::states::tensor::{on_host, OnHost, TensorState};
+pub use crate::states::{
+    aligned::AlignedVecState,
+    array::ArrayState,
+    constraint_matrix::ConstraintMatrixState,
+    double::{ArrayState64, VecState64},
+    product_space::ProductSpace,
+    product_state::{ProductProjector, ProductState},
+    vector::VecState,
+};
+#[cfg(feature = "otel")]
+pub use crate::telemetry::Telemetry;
+#[cfg(feature = "tokio")]
+pub use crate::tokio::{solve_on_blocking, BlockingSolve, Progress};
+pub use crate::tolerances::Tolerances;
+pub use crate::validation::ValidationReport;
+#[cfg(feature = "wasm")]
+pub use crate::wasm::{WasmSat, WasmSudoku};
+pub use crate::{
+    BoundedNorm, Feasibility, LinearCombine, Monitor, Projector, Result, Scalar, ScalarAccess,
+    Solver, State,
 };
-pub use crate::{Result, Solver, State};
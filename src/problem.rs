@@ -0,0 +1,381 @@
+use crate::{
+    errors::Error, solvers::divide_and_concur::DivideAndConcurSolver,
+    states::product_space::ProductSpace, Projector, Result, Scalar,
+};
+use std::{fmt, ops::Deref};
+
+// One copy per constraint, holding that constraint's view of its variables: a
+// `VecState` each at f32, a `VecState64` at f64.
+pub type ProblemState<T = f32> = ProductSpace<<T as Scalar>::Vector>;
+
+pub type ProblemSolver<'a, T = f32> = DivideAndConcurSolver<
+    ProblemState<T>,
+    Divide<'a, T>,
+    Concur<'a, T>,
+    fn(&ProblemState<T>, &ProblemState<T>) -> f32,
+>;
+
+type ConstraintProjector<T> = Box<dyn Projector<<T as Scalar>::Vector> + Send + Sync>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Variable(pub(crate) usize);
+
+impl Variable {
+    pub fn index(&self) -> usize {
+        self.0
+    }
+}
+
+struct Constraint<T: Scalar> {
+    variables: Vec<usize>,
+    projector: ConstraintProjector<T>,
+    weight: f32,
+}
+
+// Declarative front end to the divide-and-concur lifting: each constraint gets
+// its own copy of the variables it touches, divide projects every copy onto its
+// constraint, and concur replaces each variable by the weighted average of its
+// copies. `new` works in f32; `ConstraintProblem::<f64>::default()` gives the
+// same lifting over f64 copies.
+pub struct ConstraintProblem<T: Scalar = f32> {
+    n_variables: usize,
+    constraints: Vec<Constraint<T>>,
+}
+
+impl<T: Scalar> Default for ConstraintProblem<T> {
+    fn default() -> Self {
+        Self {
+            n_variables: 0,
+            constraints: Vec::new(),
+        }
+    }
+}
+
+impl ConstraintProblem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: Scalar> ConstraintProblem<T> {
+    pub fn variable(&mut self) -> Variable {
+        self.n_variables += 1;
+        Variable(self.n_variables - 1)
+    }
+
+    pub fn variables(&mut self, n: usize) -> Vec<Variable> {
+        (0..n).map(|_| self.variable()).collect()
+    }
+
+    pub fn n_variables(&self) -> usize {
+        self.n_variables
+    }
+
+    pub fn n_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+
+    // The projector sees the listed variables, in order, as a `VecState` (or a
+    // `VecState64` at f64).
+    pub fn constrain<P>(&mut self, variables: &[Variable], projector: P) -> &mut Self
+    where
+        P: Projector<T::Vector> + Send + Sync + 'static,
+    {
+        self.constrain_weighted(variables, projector, 1.0)
+    }
+
+    pub fn constrain_weighted<P>(
+        &mut self,
+        variables: &[Variable],
+        projector: P,
+        weight: f32,
+    ) -> &mut Self
+    where
+        P: Projector<T::Vector> + Send + Sync + 'static,
+    {
+        if variables.is_empty() {
+            panic!("invalid constraint: expected at least one variable");
+        }
+        if let Some(v) = variables.iter().find(|v| v.0 >= self.n_variables) {
+            panic!(
+                "invalid variable: expected index below {}, got {}",
+                self.n_variables, v.0
+            );
+        }
+        if weight <= 0.0 {
+            panic!("invalid weight: expected positive weight, got {weight}");
+        }
+
+        self.constraints.push(Constraint {
+            variables: variables.iter().map(Variable::index).collect(),
+            projector: Box::new(projector),
+            weight,
+        });
+        self
+    }
+
+    pub fn initial_state(&self, values: &[T]) -> Result<ProblemState<T>> {
+        if values.len() != self.n_variables {
+            return Err(Error::Dimension(self.n_variables, values.len()));
+        }
+
+        Ok(ProductSpace::new(
+            self.constraints
+                .iter()
+                .map(|c| {
+                    let copy: Vec<T> = c.variables.iter().map(|&j| values[j]).collect();
+                    copy.into()
+                })
+                .collect(),
+        ))
+    }
+
+    pub fn divide(&self) -> Divide<'_, T> {
+        Divide { problem: self }
+    }
+
+    pub fn concur(&self) -> Concur<'_, T> {
+        Concur { problem: self }
+    }
+
+    pub fn solver(&self, beta: f32, epsilon: f32, n_steps: usize) -> ProblemSolver<'_, T> {
+        DivideAndConcurSolver::new(
+            self.divide(),
+            self.concur(),
+            norm as fn(&ProblemState<T>, &ProblemState<T>) -> f32,
+            beta,
+            epsilon,
+            n_steps,
+        )
+    }
+
+    // Weighted consensus value of every variable; unconstrained variables read 0.
+    pub fn decode(&self, state: &ProblemState<T>) -> Result<Vec<T>> {
+        let (totals, weights) = self.totals(state)?;
+        Ok(totals
+            .into_iter()
+            .zip(weights)
+            .map(|(t, w)| if w > T::ZERO { t / w } else { T::ZERO })
+            .collect())
+    }
+
+    // Weighted sum of every variable's copies, and the sum of their weights.
+    pub(crate) fn totals(&self, state: &ProblemState<T>) -> Result<(Vec<T>, Vec<T>)> {
+        self.check(state)?;
+
+        let mut totals = vec![T::ZERO; self.n_variables];
+        let mut weights = vec![T::ZERO; self.n_variables];
+        for (constraint, copy) in self.constraints.iter().zip(&state.copies) {
+            let weight = T::from_f32(constraint.weight);
+            for (&j, &x) in constraint.variables.iter().zip(copy.iter()) {
+                totals[j] += weight * x;
+                weights[j] += weight;
+            }
+        }
+
+        Ok((totals, weights))
+    }
+
+    // Sets every copy of each variable to its entry in `values`.
+    pub(crate) fn broadcast(&self, state: &mut ProblemState<T>, values: &[T]) {
+        for (constraint, copy) in self.constraints.iter().zip(&mut state.copies) {
+            for (&j, x) in constraint.variables.iter().zip(copy.iter_mut()) {
+                *x = values[j];
+            }
+        }
+    }
+
+    fn check(&self, state: &ProblemState<T>) -> Result<()> {
+        if state.len() != self.constraints.len() {
+            return Err(Error::Dimension(self.constraints.len(), state.len()));
+        }
+
+        for (constraint, copy) in self.constraints.iter().zip(&state.copies) {
+            if copy.len() != constraint.variables.len() {
+                return Err(Error::Dimension(constraint.variables.len(), copy.len()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Scalar> fmt::Debug for ConstraintProblem<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConstraintProblem")
+            .field("n_variables", &self.n_variables)
+            .field("n_constraints", &self.constraints.len())
+            .finish()
+    }
+}
+
+// The Euclidean distance over every copy, summed in the problem's scalar.
+pub fn norm<V, T>(current: &ProductSpace<V>, previous: &ProductSpace<V>) -> f32
+where
+    V: Deref<Target = Vec<T>>,
+    T: Scalar,
+{
+    let squared: T = current
+        .copies
+        .iter()
+        .zip(&previous.copies)
+        .flat_map(|(c, p)| c.iter().zip(p.iter()))
+        .map(|(&c, &p)| (c - p) * (c - p))
+        .sum();
+    squared.sqrt().to_f32()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Divide<'a, T: Scalar = f32> {
+    problem: &'a ConstraintProblem<T>,
+}
+
+impl<T: Scalar> Projector<ProblemState<T>> for Divide<'_, T> {
+    fn project(&self, state: ProblemState<T>) -> Result<ProblemState<T>> {
+        self.problem.check(&state)?;
+
+        // Every block sees only its own copy, so they project independently.
+        #[cfg(feature = "rayon")]
+        if state.len() >= crate::parallel::block_threshold() {
+            use rayon::prelude::*;
+            let copies = crate::parallel::install(|| {
+                state
+                    .copies
+                    .into_par_iter()
+                    .zip(&self.problem.constraints)
+                    .map(|(copy, constraint)| project_block(constraint, copy))
+                    .collect::<Result<_>>()
+            })?;
+            return Ok(ProductSpace { copies });
+        }
+
+        let copies = state
+            .copies
+            .into_iter()
+            .zip(&self.problem.constraints)
+            .map(|(copy, constraint)| project_block(constraint, copy))
+            .collect::<Result<_>>()?;
+
+        Ok(ProductSpace { copies })
+    }
+}
+
+fn project_block<T: Scalar>(constraint: &Constraint<T>, copy: T::Vector) -> Result<T::Vector> {
+    let projected = constraint.projector.project(copy)?;
+    if projected.len() != constraint.variables.len() {
+        return Err(Error::Dimension(
+            constraint.variables.len(),
+            projected.len(),
+        ));
+    }
+    Ok(projected)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Concur<'a, T: Scalar = f32> {
+    problem: &'a ConstraintProblem<T>,
+}
+
+impl<T: Scalar> Projector<ProblemState<T>> for Concur<'_, T> {
+    fn project(&self, mut state: ProblemState<T>) -> Result<ProblemState<T>> {
+        let consensus = self.problem.decode(&state)?;
+        self.problem.broadcast(&mut state, &consensus);
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        projectors::{box_constraints::BoxProjector, hyperplane::HyperplaneProjector},
+        states::{double::VecState64, vector::VecState},
+        Solver,
+    };
+
+    #[test]
+    fn test_lifting() {
+        let mut problem = ConstraintProblem::new();
+        let x = problem.variables(3);
+        problem
+            .constrain(&x[..2], HyperplaneProjector::new(vec![1.0, 1.0], 1.0))
+            .constrain_weighted(&[x[2], x[0]], BoxProjector::new(0.0, 0.5), 3.0);
+
+        let state = problem.initial_state(&[1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(state.copies[1], VecState(vec![3.0, 1.0]));
+
+        let state = problem.divide().project(state).unwrap();
+        assert_eq!(state.copies[0], VecState(vec![0.0, 1.0]));
+        assert_eq!(state.copies[1], VecState(vec![0.5, 0.5]));
+
+        let state = problem.concur().project(state).unwrap();
+        assert_eq!(problem.decode(&state).unwrap(), vec![0.375, 1.0, 0.5]);
+        assert_eq!(state.copies[1], VecState(vec![0.5, 0.375]));
+
+        assert!(matches!(
+            problem.initial_state(&[0.0; 2]),
+            Err(Error::Dimension(3, 2))
+        ));
+    }
+
+    #[test]
+    fn test_solve() {
+        let mut problem = ConstraintProblem::new();
+        let x = problem.variables(3);
+        problem
+            .constrain(&x[..2], HyperplaneProjector::new(vec![1.0, 1.0], 1.0))
+            .constrain(&x[1..], HyperplaneProjector::new(vec![1.0, -1.0], 0.5))
+            .constrain(&x, BoxProjector::new(0.0, 1.0));
+
+        let state = problem.initial_state(&[0.9, 0.9, 0.9]).unwrap();
+        let (state, _, _) = problem.solver(1.0, 1e-6, 1000).run(state).unwrap();
+        let values = problem.decode(&state).unwrap();
+        assert!((values[0] + values[1] - 1.0).abs() < 1e-3);
+        assert!((values[1] - values[2] - 0.5).abs() < 1e-3);
+        assert!(values.iter().all(|&v| (-1e-3..=1.0 + 1e-3).contains(&v)));
+    }
+
+    #[test]
+    fn test_solve_f64() {
+        let mut problem = ConstraintProblem::<f64>::default();
+        let x = problem.variables(2);
+        problem
+            .constrain(&x, HyperplaneProjector::new(vec![1.0, 1.0], 1.0))
+            .constrain(&x, HyperplaneProjector::new(vec![1.0, -1.0], 0.5))
+            .constrain(&x, BoxProjector::new(0.0, 1.0));
+
+        let state = problem.initial_state(&[0.9, 0.9]).unwrap();
+        assert_eq!(state.copies[0], VecState64(vec![0.9, 0.9]));
+        let (state, _, _) = problem.solver(1.0, 1e-9, 10000).run(state).unwrap();
+        let values = problem.decode(&state).unwrap();
+        assert!((values[0] - 0.75).abs() < 1e-8, "{values:?}");
+        assert!((values[1] - 0.25).abs() < 1e-8, "{values:?}");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid variable: expected index below 1, got 1")]
+    fn test_unknown_variable() {
+        let mut problem = ConstraintProblem::new();
+        problem.variable();
+        problem.constrain(&[Variable(1)], BoxProjector::new(0.0, 1.0));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_divide() {
+        // Enough blocks to take the parallel path at the default threshold.
+        let mut problem = ConstraintProblem::new();
+        let x = problem.variables(200);
+        for pair in x.chunks(2) {
+            problem.constrain(pair, BoxProjector::new(0.0, 1.0));
+        }
+        assert!(problem.n_constraints() >= crate::parallel::block_threshold());
+
+        let values: Vec<f32> = (0..200).map(|i| i as f32 / 50.0 - 2.0).collect();
+        let state = problem.initial_state(&values).unwrap();
+        let state = problem.divide().project(state).unwrap();
+        let expected: Vec<f32> = values.iter().map(|v| v.clamp(0.0, 1.0)).collect();
+        assert_eq!(problem.decode(&state).unwrap(), expected);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{
+    errors::Error,
+    norms::euclidean_contiguous,
+    projectors::{affine::AffineProjector, binary::BinaryProjector},
+    solvers::divide_and_concur::DivideAndConcurSolver,
+    states::vector::VecState,
+    Result,
+};
+use nalgebra::{DMatrix, DVector};
+use std::{io::BufRead, str::FromStr};
+
+// Equations count as satisfied within this absolute tolerance.
+const TOLERANCE: f32 = 1e-3;
+
+pub type BinaryFeasibilitySolver = DivideAndConcurSolver<
+    VecState,
+    BinaryProjector,
+    AffineProjector,
+    fn(&VecState, &VecState) -> f32,
+>;
+
+// Find x in {0, 1}^n with A x = b: divide rounds to bits, concur projects onto
+// the affine set. A must have full row rank.
+#[derive(Debug, Clone)]
+pub struct BinaryFeasibility {
+    a: DMatrix<f32>,
+    b: DVector<f32>,
+    affine: AffineProjector,
+}
+
+impl BinaryFeasibility {
+    pub fn new(a: DMatrix<f32>, b: DVector<f32>) -> Result<Self> {
+        if a.nrows() != b.len() {
+            return Err(Error::Dimension(a.nrows(), b.len()));
+        }
+
+        let affine = AffineProjector::new(a.clone(), b.clone())?;
+        Ok(Self { a, b, affine })
+    }
+
+    // Reads a header line `m n` followed by m rows of n coefficients and the
+    // right-hand side, separated by whitespace or commas. Blank lines and `#`
+    // comments are skipped.
+    pub fn read<R>(reader: R) -> Result<Self>
+    where
+        R: BufRead,
+    {
+        let mut shape = None;
+        let mut rows: Vec<Vec<f32>> = Vec::new();
+        let mut last = 0;
+        for (i, line) in reader.lines().enumerate() {
+            let line = line.map_err(|err| Error::Parse(i + 1, err.to_string()))?;
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            last = i + 1;
+
+            let fields: Vec<&str> = line
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|f| !f.is_empty())
+                .collect();
+            let Some((m, n)) = shape else {
+                let invalid = || Error::Parse(i + 1, format!("expected `m n`, got `{line}`"));
+                let [m, n] = fields[..] else {
+                    return Err(invalid());
+                };
+                shape = Some((
+                    m.parse::<usize>().map_err(|_| invalid())?,
+                    n.parse::<usize>().map_err(|_| invalid())?,
+                ));
+                continue;
+            };
+
+            if rows.len() == m {
+                return Err(Error::Parse(i + 1, format!("expected {m} rows, got more")));
+            }
+            if fields.len() != n + 1 {
+                return Err(Error::Parse(
+                    i + 1,
+                    format!("expected {} values, got {}", n + 1, fields.len()),
+                ));
+            }
+            let row = fields
+                .iter()
+                .map(|f| {
+                    f.parse()
+                        .map_err(|_| Error::Parse(i + 1, format!("invalid number `{f}`")))
+                })
+                .collect::<Result<_>>()?;
+            rows.push(row);
+        }
+
+        let Some((m, n)) = shape else {
+            return Err(Error::Parse(last, "missing `m n` header".to_string()));
+        };
+        if rows.len() != m {
+            return Err(Error::Parse(
+                last,
+                format!("expected {m} rows, got {}", rows.len()),
+            ));
+        }
+
+        let a = DMatrix::from_fn(m, n, |r, c| rows[r][c]);
+        let b = DVector::from_fn(m, |r, _| rows[r][n]);
+        Self::new(a, b)
+    }
+
+    pub fn matrix(&self) -> &DMatrix<f32> {
+        &self.a
+    }
+
+    pub fn rhs(&self) -> &DVector<f32> {
+        &self.b
+    }
+
+    pub fn n_variables(&self) -> usize {
+        self.a.ncols()
+    }
+
+    pub fn initial_state(&self, values: &[f32]) -> Result<VecState> {
+        if values.len() != self.n_variables() {
+            return Err(Error::Dimension(self.n_variables(), values.len()));
+        }
+
+        Ok(VecState(values.to_vec()))
+    }
+
+    // Only accepts a fixed point whose bits satisfy every equation.
+    pub fn solver(&self, beta: f32, epsilon: f32, n_steps: usize) -> BinaryFeasibilitySolver {
+        let (a, b) = (self.a.clone(), self.b.clone());
+        DivideAndConcurSolver::new(
+            BinaryProjector::bits(),
+            self.affine.clone(),
+            euclidean_contiguous as fn(&VecState, &VecState) -> f32,
+            beta,
+            epsilon,
+            n_steps,
+        )
+        .with_feasibility(move |state: &VecState| violations(&a, &b, &decode(state)) as f32)
+    }
+
+    pub fn decode(&self, state: &VecState) -> Result<Vec<bool>> {
+        if state.len() != self.n_variables() {
+            return Err(Error::Dimension(self.n_variables(), state.len()));
+        }
+
+        Ok(decode(state))
+    }
+
+    // Equations that x does not satisfy.
+    pub fn violations(&self, x: &[bool]) -> Result<usize> {
+        if x.len() != self.n_variables() {
+            return Err(Error::Dimension(self.n_variables(), x.len()));
+        }
+
+        Ok(violations(&self.a, &self.b, x))
+    }
+}
+
+impl FromStr for BinaryFeasibility {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::read(s.as_bytes())
+    }
+}
+
+fn decode(state: &VecState) -> Vec<bool> {
+    state.iter().map(|&x| x >= 0.5).collect()
+}
+
+fn violations(a: &DMatrix<f32>, b: &DVector<f32>, x: &[bool]) -> usize {
+    let x = DVector::from_iterator(x.len(), x.iter().map(|&bit| if bit { 1.0 } else { 0.0 }));
+    (a * x - b).iter().filter(|r| r.abs() > TOLERANCE).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{problems::rng::SplitMix64, Solver};
+
+    #[test]
+    fn test_read() {
+        let input = "# two equations\n2 3\n1 1 0 1\n0, 1, 1, 2\n";
+        let problem: BinaryFeasibility = input.parse().unwrap();
+        assert_eq!(problem.matrix().shape(), (2, 3));
+        assert_eq!(problem.rhs().as_slice(), &[1.0, 2.0]);
+        assert_eq!(problem.violations(&[false, true, true]).unwrap(), 0);
+        assert_eq!(problem.violations(&[true, true, false]).unwrap(), 2);
+
+        assert!(matches!(
+            "2 3\n1 1 0\n".parse::<BinaryFeasibility>(),
+            Err(Error::Parse(2, _))
+        ));
+        assert!(matches!(
+            "2 3\n1 1 0 1\n".parse::<BinaryFeasibility>(),
+            Err(Error::Parse(2, _))
+        ));
+        assert!(matches!(
+            "# nothing\n".parse::<BinaryFeasibility>(),
+            Err(Error::Parse(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_solve() {
+        let (m, n) = (6, 16);
+        let mut rng = SplitMix64::new(9);
+        let mut next = || rng.uniform();
+
+        let truth: Vec<f32> = (0..n).map(|_| next().round()).collect();
+        let a = DMatrix::from_fn(m, n, |_, _| (4.0 * next()).floor());
+        let b = &a * DVector::from_column_slice(&truth);
+        let problem = BinaryFeasibility::new(a, b).unwrap();
+
+        let values: Vec<f32> = (0..n).map(|_| next()).collect();
+        let state = problem.initial_state(&values).unwrap();
+        let (state, _, _) = problem.solver(1.0, 1e-4, 20000).run(state).unwrap();
+        let x = problem.decode(&state).unwrap();
+        assert_eq!(problem.violations(&x).unwrap(), 0);
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{
+    errors::Error,
+    norms::euclidean_contiguous,
+    problems::rng::SplitMix64,
+    projectors::{affine::AffineProjector, sparsity::SparsityProjector},
+    solvers::divide_and_concur::DivideAndConcurSolver,
+    states::vector::VecState,
+    Result,
+};
+use nalgebra::{DMatrix, DVector};
+
+pub type CompressedSensingSolver = DivideAndConcurSolver<
+    VecState,
+    SparsityProjector,
+    AffineProjector,
+    fn(&VecState, &VecState) -> f32,
+>;
+
+// Recover a k-sparse x from measurements y = A x: divide keeps the k largest
+// coordinates, concur projects onto the affine set {x : A x = y}.
+#[derive(Debug, Clone)]
+pub struct CompressedSensing {
+    a: DMatrix<f32>,
+    y: DVector<f32>,
+    k: usize,
+    affine: AffineProjector,
+}
+
+impl CompressedSensing {
+    pub fn new(a: DMatrix<f32>, y: DVector<f32>, k: usize) -> Result<Self> {
+        let affine = AffineProjector::new(a.clone(), y.clone())?;
+        Ok(Self { a, y, k, affine })
+    }
+
+    // A Gaussian m x n sensing matrix scaled by 1 / sqrt(m) and a k-sparse
+    // signal with Gaussian entries, reproducible from `seed`. Returns the
+    // problem together with the true signal.
+    pub fn synthetic(m: usize, n: usize, k: usize, seed: u64) -> Result<(Self, Vec<f32>)> {
+        if k > n {
+            panic!("invalid sparsity: expected k <= {n}, got {k}");
+        }
+
+        let mut rng = SplitMix64::new(seed);
+        let scale = 1.0 / (m as f32).sqrt();
+        let a = DMatrix::from_fn(m, n, |_, _| scale * rng.gaussian());
+
+        let mut support: Vec<usize> = (0..n).collect();
+        for i in 0..k {
+            let j = i + (rng.next() % (n - i) as u64) as usize;
+            support.swap(i, j);
+        }
+        let mut x = vec![0f32; n];
+        for &i in &support[..k] {
+            x[i] = rng.gaussian();
+        }
+
+        let y = &a * DVector::from_column_slice(&x);
+        Ok((Self::new(a, y, k)?, x))
+    }
+
+    pub fn matrix(&self) -> &DMatrix<f32> {
+        &self.a
+    }
+
+    pub fn measurements(&self) -> &DVector<f32> {
+        &self.y
+    }
+
+    pub fn k(&self) -> usize {
+        self.k
+    }
+
+    // Starts from the back-projection A^T y.
+    pub fn initial_state(&self) -> VecState {
+        VecState(self.a.tr_mul(&self.y).as_slice().to_vec())
+    }
+
+    pub fn solver(&self, beta: f32, epsilon: f32, n_steps: usize) -> CompressedSensingSolver {
+        DivideAndConcurSolver::new(
+            SparsityProjector::new(self.k),
+            self.affine.clone(),
+            euclidean_contiguous as fn(&VecState, &VecState) -> f32,
+            beta,
+            epsilon,
+            n_steps,
+        )
+    }
+
+    // ||A x - y||
+    pub fn residual(&self, x: &[f32]) -> Result<f32> {
+        let residual = self.affine.residual(x)?;
+        Ok(crate::projectors::dot(&residual, &residual).sqrt())
+    }
+}
+
+// ||estimate - truth|| / ||truth||
+pub fn recovery_error(estimate: &[f32], truth: &[f32]) -> Result<f32> {
+    if estimate.len() != truth.len() {
+        return Err(Error::Dimension(truth.len(), estimate.len()));
+    }
+
+    let error: f32 = estimate
+        .iter()
+        .zip(truth)
+        .map(|(e, t)| (e - t).powi(2))
+        .sum();
+    let norm: f32 = truth.iter().map(|t| t * t).sum();
+    Ok((error / norm).sqrt())
+}
+
+// Whether the entries above `tolerance` in magnitude sit at the same indices.
+pub fn same_support(estimate: &[f32], truth: &[f32], tolerance: f32) -> bool {
+    estimate.len() == truth.len()
+        && estimate
+            .iter()
+            .zip(truth)
+            .all(|(e, t)| (e.abs() > tolerance) == (t.abs() > tolerance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solver;
+
+    #[test]
+    fn test_synthetic() {
+        let (problem, x) = CompressedSensing::synthetic(20, 50, 4, 1).unwrap();
+        assert_eq!(x.iter().filter(|&&v| v != 0.0).count(), 4);
+        assert_eq!(problem.matrix().shape(), (20, 50));
+        assert!(problem.residual(&x).unwrap() < 1e-4);
+
+        let (_, again) = CompressedSensing::synthetic(20, 50, 4, 1).unwrap();
+        assert_eq!(x, again);
+    }
+
+    #[test]
+    fn test_solve() {
+        let (problem, truth) = CompressedSensing::synthetic(40, 100, 5, 7).unwrap();
+        let (x, _, _) = problem
+            .solver(0.5, 1e-6, 5000)
+            .run(problem.initial_state())
+            .unwrap();
+
+        assert!(recovery_error(&x, &truth).unwrap() < 1e-2);
+        assert!(same_support(&x, &truth, 1e-2));
+    }
+}
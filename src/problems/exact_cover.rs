@@ -0,0 +1,172 @@
+use crate::{
+    problem::{ConstraintProblem, ProblemSolver, ProblemState},
+    projectors::simplex::SimplexProjector,
+    Result,
+};
+use std::sync::Arc;
+
+// Choose subsets of `0..universe` so every element is covered exactly once.
+// Each subset is a 0/1 variable; each element constrains the subsets containing
+// it to a one-hot vector.
+#[derive(Debug)]
+pub struct ExactCover {
+    universe: usize,
+    subsets: Vec<Vec<usize>>,
+    containing: Arc<[Vec<usize>]>,
+    problem: Arc<ConstraintProblem>,
+}
+
+impl ExactCover {
+    pub fn new(universe: usize, subsets: Vec<Vec<usize>>) -> Self {
+        let mut containing = vec![Vec::new(); universe];
+        let subsets: Vec<Vec<usize>> = subsets
+            .into_iter()
+            .map(|mut subset| {
+                subset.sort_unstable();
+                subset.dedup();
+                subset
+            })
+            .collect();
+
+        for (s, subset) in subsets.iter().enumerate() {
+            for &e in subset {
+                if e >= universe {
+                    panic!("invalid element: expected index below {universe}, got {e}");
+                }
+                containing[e].push(s);
+            }
+        }
+        if let Some(e) = containing.iter().position(Vec::is_empty) {
+            panic!("invalid exact cover: expected every element in a subset, got none for {e}");
+        }
+
+        let mut problem = ConstraintProblem::new();
+        let variables = problem.variables(subsets.len());
+        for members in &containing {
+            let vars: Vec<_> = members.iter().map(|&s| variables[s]).collect();
+            problem.constrain(&vars, SimplexProjector::sparse(1.0, 1));
+        }
+
+        Self {
+            universe,
+            subsets,
+            containing: containing.into(),
+            problem: Arc::new(problem),
+        }
+    }
+
+    pub fn universe(&self) -> usize {
+        self.universe
+    }
+
+    pub fn subsets(&self) -> &[Vec<usize>] {
+        &self.subsets
+    }
+
+    pub fn problem(&self) -> &ConstraintProblem {
+        &self.problem
+    }
+
+    // One value per subset, roughly in [0, 1].
+    pub fn initial_state(&self, values: &[f32]) -> Result<ProblemState> {
+        self.problem.initial_state(values)
+    }
+
+    // Only accepts a fixed point whose decoded selection is an exact cover.
+    pub fn solver(&self, beta: f32, epsilon: f32, n_steps: usize) -> ProblemSolver<'_> {
+        let (containing, problem) = (Arc::clone(&self.containing), Arc::clone(&self.problem));
+        self.problem
+            .solver(beta, epsilon, n_steps)
+            .with_feasibility(move |state: &ProblemState| match problem.decode(state) {
+                Ok(values) => violations(&containing, &selected(&values)) as f32,
+                Err(_) => f32::INFINITY,
+            })
+    }
+
+    // Indices of the selected subsets.
+    pub fn decode(&self, state: &ProblemState) -> Result<Vec<usize>> {
+        Ok(selected(&self.problem.decode(state)?)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(s, x)| x.then_some(s))
+            .collect())
+    }
+
+    // Elements covered zero or several times by the chosen subsets, plus one
+    // for each chosen index that names no subset.
+    pub fn violations(&self, chosen: &[usize]) -> usize {
+        let mut selected = vec![false; self.subsets.len()];
+        let mut unknown = 0;
+        for &s in chosen {
+            match selected.get_mut(s) {
+                Some(x) => *x = true,
+                None => unknown += 1,
+            }
+        }
+        violations(&self.containing, &selected) + unknown
+    }
+
+    pub fn is_cover(&self, chosen: &[usize]) -> bool {
+        self.violations(chosen) == 0
+    }
+}
+
+fn selected(values: &[f32]) -> Vec<bool> {
+    values.iter().map(|&x| x >= 0.5).collect()
+}
+
+fn violations(containing: &[Vec<usize>], selected: &[bool]) -> usize {
+    containing
+        .iter()
+        .filter(|members| members.iter().filter(|&&s| selected[s]).count() != 1)
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solver;
+
+    // Knuth's example from "Dancing Links"; the only cover is {1, 3, 5}.
+    fn knuth() -> ExactCover {
+        ExactCover::new(
+            7,
+            vec![
+                vec![0, 3, 6],
+                vec![0, 3],
+                vec![3, 4, 6],
+                vec![2, 4, 5],
+                vec![1, 2, 5, 6],
+                vec![1, 6],
+            ],
+        )
+    }
+
+    #[test]
+    fn test_violations() {
+        let problem = knuth();
+        assert!(problem.is_cover(&[1, 3, 5]));
+        assert_eq!(problem.violations(&[0, 3, 5]), 1);
+        assert_eq!(problem.violations(&[]), 7);
+        assert_eq!(problem.violations(&[1, 3, 5, 6]), 1);
+        assert!(!problem.is_cover(&[1, 3, 5, 6]));
+    }
+
+    #[test]
+    fn test_solve() {
+        let problem = knuth();
+        let state = problem
+            .initial_state(&[0.5, 0.4, 0.3, 0.6, 0.2, 0.7])
+            .unwrap();
+        let (state, _, _) = problem.solver(0.5, 1e-3, 10000).run(state).unwrap();
+        assert_eq!(problem.decode(&state).unwrap(), vec![1, 3, 5]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "invalid exact cover: expected every element in a subset, got none for 2"
+    )]
+    fn test_uncovered_element() {
+        ExactCover::new(3, vec![vec![0, 1]]);
+    }
+}
@@ -0,0 +1,386 @@
+use crate::{
+    errors::Error,
+    norms::euclidean_contiguous,
+    projectors::permutation::PermutationProjector,
+    solvers::divide_and_concur::DivideAndConcurSolver,
+    states::{
+        masked::{frozen, Frozen, MaskedState},
+        vector::VecState,
+    },
+    Projector, Result,
+};
+use std::{str::FromStr, sync::Arc};
+
+pub type LatinSquareSolver = DivideAndConcurSolver<
+    MaskedState<VecState>,
+    Frozen<AssignmentProjector>,
+    Frozen<fn(VecState) -> Result<VecState>>,
+    fn(&MaskedState<VecState>, &MaskedState<VecState>) -> f32,
+>;
+
+// Projects two replicas of an n x n x n indicator array x[r][c][s], stored back
+// to back, so that every row of the first replica and every column of the
+// second is an assignment of symbols to cells.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AssignmentProjector {
+    n: usize,
+    permutation: PermutationProjector,
+}
+
+impl AssignmentProjector {
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            permutation: PermutationProjector::new(n),
+        }
+    }
+}
+
+impl<S> Projector<S> for AssignmentProjector
+where
+    S: AsMut<[f32]>,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let n = self.n;
+        let values = state.as_mut();
+        if values.len() != 2 * n * n * n {
+            return Err(Error::Dimension(2 * n * n * n, values.len()));
+        }
+
+        let (rows, columns) = values.split_at_mut(n * n * n);
+
+        // Rows and columns are independent assignments. A column's cells are
+        // strided, so in parallel each gathers its own copy and they are
+        // written back afterwards.
+        #[cfg(feature = "rayon")]
+        if 2 * n >= crate::parallel::block_threshold() {
+            use rayon::prelude::*;
+            let projected = crate::parallel::install(|| {
+                rows.par_chunks_mut(n * n)
+                    .try_for_each(|row| self.permutation.project(row).map(drop))?;
+                (0..n)
+                    .into_par_iter()
+                    .map(|c| {
+                        let block: Vec<f32> = (0..n)
+                            .flat_map(|r| &columns[(r * n + c) * n..(r * n + c + 1) * n])
+                            .copied()
+                            .collect();
+                        self.permutation.project(block)
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })?;
+            for (c, block) in projected.iter().enumerate() {
+                for r in 0..n {
+                    let cell = (r * n + c) * n;
+                    columns[cell..cell + n].copy_from_slice(&block[r * n..(r + 1) * n]);
+                }
+            }
+            return Ok(state);
+        }
+
+        for row in rows.chunks_mut(n * n) {
+            self.permutation.project(&mut *row)?;
+        }
+
+        let mut block = vec![0f32; n * n];
+        for c in 0..n {
+            for r in 0..n {
+                let cell = (r * n + c) * n;
+                block[r * n..(r + 1) * n].copy_from_slice(&columns[cell..cell + n]);
+            }
+            self.permutation.project(&mut block[..])?;
+            for r in 0..n {
+                let cell = (r * n + c) * n;
+                columns[cell..cell + n].copy_from_slice(&block[r * n..(r + 1) * n]);
+            }
+        }
+
+        Ok(state)
+    }
+}
+
+// Averages the two replicas.
+fn consensus(mut state: VecState) -> Result<VecState> {
+    let half = state.len() / 2;
+    let (rows, columns) = state.split_at_mut(half);
+    for (a, b) in rows.iter_mut().zip(columns.iter_mut()) {
+        *a = 0.5 * (*a + *b);
+        *b = *a;
+    }
+    Ok(state)
+}
+
+// A partial n x n Latin square, cells in row-major order with symbols 1..=n and
+// 0 for an empty cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatinSquare {
+    n: usize,
+    cells: Arc<[usize]>,
+}
+
+impl LatinSquare {
+    pub fn new(n: usize, cells: Vec<usize>) -> Self {
+        if n == 0 {
+            panic!("invalid square: expected at least 1 row, got 0");
+        }
+        if cells.len() != n * n {
+            panic!(
+                "invalid square: expected {} cells, got {}",
+                n * n,
+                cells.len()
+            );
+        }
+        if let Some(&v) = cells.iter().find(|&&v| v > n) {
+            panic!("invalid cell: expected 0..={n}, got {v}");
+        }
+
+        Self {
+            n,
+            cells: cells.into(),
+        }
+    }
+
+    // One row per non-empty line of whitespace-separated symbols, with `.`, `_`
+    // or `0` for an empty cell.
+    pub fn parse(input: &str) -> Result<Self> {
+        let rows: Vec<(usize, Vec<&str>)> = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.split_whitespace().collect::<Vec<_>>()))
+            .filter(|(_, tokens)| !tokens.is_empty())
+            .collect();
+
+        let n = rows.len();
+        if n == 0 {
+            return Err(Error::Parse(
+                input.lines().count(),
+                "expected at least 1 row".into(),
+            ));
+        }
+        let mut cells = Vec::with_capacity(n * n);
+        for (line, tokens) in rows {
+            if tokens.len() != n {
+                return Err(Error::Parse(
+                    line,
+                    format!("expected {n} cells, got {}", tokens.len()),
+                ));
+            }
+            for token in tokens {
+                let value = match token {
+                    "." | "_" => 0,
+                    _ => token.parse().ok().filter(|&v| v <= n).ok_or_else(|| {
+                        Error::Parse(line, format!("expected 0..={n} or `.`, got `{token}`"))
+                    })?,
+                };
+                cells.push(value);
+            }
+        }
+
+        Ok(Self::new(n, cells))
+    }
+
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    pub fn cells(&self) -> &[usize] {
+        &self.cells
+    }
+
+    // The indicators of every given cell, in both replicas.
+    fn givens(&self) -> Vec<Option<f32>> {
+        let n = self.n;
+        let mut givens = vec![None; 2 * n * n * n];
+        for (cell, &v) in self.cells.iter().enumerate().filter(|(_, &v)| v > 0) {
+            for replica in 0..2 {
+                for s in 0..n {
+                    let value = if s + 1 == v { 1.0 } else { 0.0 };
+                    givens[replica * n * n * n + cell * n + s] = Some(value);
+                }
+            }
+        }
+        givens
+    }
+
+    // One value per indicator x[r][c][s], copied into both replicas; the given
+    // cells are clamped.
+    pub fn initial_state(&self, values: &[f32]) -> Result<MaskedState<VecState>> {
+        let size = self.n * self.n * self.n;
+        if values.len() != size {
+            return Err(Error::Dimension(size, values.len()));
+        }
+
+        let state = VecState(values.iter().chain(values).copied().collect());
+        Ok(MaskedState::new(state, self.givens()))
+    }
+
+    // Only accepts a fixed point whose decoded square is complete and Latin.
+    pub fn solver(&self, beta: f32, epsilon: f32, n_steps: usize) -> LatinSquareSolver {
+        let square = self.clone();
+        DivideAndConcurSolver::new(
+            frozen(AssignmentProjector::new(self.n)),
+            frozen(consensus as fn(VecState) -> Result<VecState>),
+            euclidean_contiguous as fn(&MaskedState<VecState>, &MaskedState<VecState>) -> f32,
+            beta,
+            epsilon,
+            n_steps,
+        )
+        .with_feasibility(move |state: &MaskedState<VecState>| {
+            square.violations(&decode(square.n, state.as_ref())) as f32
+        })
+    }
+
+    // The most likely symbol in each cell across both replicas, row-major.
+    pub fn decode(&self, state: &MaskedState<VecState>) -> Result<Vec<usize>> {
+        let size = 2 * self.n * self.n * self.n;
+        if state.as_ref().len() != size {
+            return Err(Error::Dimension(size, state.as_ref().len()));
+        }
+
+        Ok(decode(self.n, state.as_ref()))
+    }
+
+    // Repeated symbols in a row or column, empty cells, and cells that
+    // disagree with a given.
+    pub fn violations(&self, square: &[usize]) -> usize {
+        let n = self.n;
+        let mut count = square.iter().filter(|&&v| v == 0 || v > n).count();
+        count += self
+            .cells
+            .iter()
+            .zip(square)
+            .filter(|(&given, &v)| given > 0 && given != v)
+            .count();
+
+        for i in 0..n {
+            let row = (0..n).map(|j| square[i * n + j]);
+            let column = (0..n).map(|j| square[j * n + i]);
+            count += repeats(n, row) + repeats(n, column);
+        }
+        count
+    }
+}
+
+impl FromStr for LatinSquare {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+fn decode(n: usize, values: &[f32]) -> Vec<usize> {
+    let size = n * n * n;
+    (0..n * n)
+        .map(|cell| {
+            (0..n)
+                .map(|s| values[cell * n + s] + values[size + cell * n + s])
+                .enumerate()
+                .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map_or(0, |(s, _)| s + 1)
+        })
+        .collect()
+}
+
+fn repeats(n: usize, line: impl Iterator<Item = usize>) -> usize {
+    let mut seen = vec![false; n + 1];
+    line.filter(|&v| (1..=n).contains(&v))
+        .filter(|&v| std::mem::replace(&mut seen[v], true))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{problems::rng::SplitMix64, Solver};
+
+    #[test]
+    fn test_parse() {
+        let square: LatinSquare = "1 . 3\n. 3 .\n3 . 2\n".parse().unwrap();
+        assert_eq!(square.n(), 3);
+        assert_eq!(square.cells(), &[1, 0, 3, 0, 3, 0, 3, 0, 2]);
+
+        assert!(matches!(
+            "1 2\n2\n".parse::<LatinSquare>(),
+            Err(Error::Parse(2, _))
+        ));
+        assert!(matches!(
+            "1 3\n2 1\n".parse::<LatinSquare>(),
+            Err(Error::Parse(1, _))
+        ));
+        assert!(matches!(
+            " \n\n".parse::<LatinSquare>(),
+            Err(Error::Parse(2, _))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid square: expected at least 1 row, got 0")]
+    fn test_empty() {
+        LatinSquare::new(0, vec![]);
+    }
+
+    #[test]
+    fn test_violations() {
+        let square = LatinSquare::new(3, vec![1, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(square.violations(&[1, 2, 3, 2, 3, 1, 3, 1, 2]), 0);
+        // The given disagrees, and both the first row and column repeat a 2.
+        assert_eq!(square.violations(&[2, 2, 3, 2, 3, 1, 3, 1, 2]), 3);
+    }
+
+    #[test]
+    fn test_assignment_projector() {
+        let n = 2;
+        let projector = AssignmentProjector::new(n);
+        let mut values = vec![0.0; 2 * n * n * n];
+        // Rows replica leans towards [[1, 2], [2, 1]], columns replica too.
+        for (cell, s) in [(0, 0), (1, 1), (2, 1), (3, 0)] {
+            values[cell * n + s] = 0.6;
+            values[n * n * n + cell * n + s] = 0.6;
+        }
+        let output = projector.project(values).unwrap();
+        assert_eq!(decode(n, &output), vec![1, 2, 2, 1]);
+        assert_eq!(output.iter().filter(|&&x| x == 1.0).count(), 8);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_assignment_projector() {
+        // 32 rows and 32 columns, enough for the parallel path by default.
+        let n = 32;
+        assert!(2 * n >= crate::parallel::block_threshold());
+        let square: Vec<usize> = (0..n * n).map(|i| (i / n + i % n) % n + 1).collect();
+        let (mut values, mut expected) = (vec![0.1; 2 * n * n * n], vec![0.0; 2 * n * n * n]);
+        for (cell, &s) in square.iter().enumerate() {
+            for replica in [0, n * n * n] {
+                values[replica + cell * n + s - 1] = 0.6;
+                expected[replica + cell * n + s - 1] = 1.0;
+            }
+        }
+        let output = AssignmentProjector::new(n).project(values).unwrap();
+        assert_eq!(output, expected);
+        assert_eq!(decode(n, &output), square);
+    }
+
+    #[test]
+    fn test_solve() {
+        let square: LatinSquare = "\
+            1 . . . .
+            . . 3 . .
+            . 4 . . .
+            . . . . 2
+            . . . 5 .
+        "
+        .parse()
+        .unwrap();
+
+        let mut rng = SplitMix64::new(3);
+        let values: Vec<f32> = (0..125).map(|_| rng.uniform()).collect();
+
+        let state = square.initial_state(&values).unwrap();
+        let (state, _, _) = square.solver(0.5, 1e-3, 20000).run(state).unwrap();
+        let solution = square.decode(&state).unwrap();
+        assert_eq!(square.violations(&solution), 0);
+        assert_eq!(solution[0], 1);
+    }
+}
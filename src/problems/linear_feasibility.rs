@@ -0,0 +1,241 @@
+use crate::{
+    problem::{ConstraintProblem, ProblemSolver, ProblemState, Variable},
+    projectors::halfspace::HalfspaceProjector,
+    Result, Scalar,
+};
+use std::sync::Arc;
+
+// Inequalities count as satisfied within this absolute tolerance.
+const TOLERANCE: f32 = 1e-4;
+
+// Find x with A x <= b. Each inequality becomes a halfspace constraint over the
+// variables it actually involves, and concur averages the copies, so sparse
+// rows stay cheap. Works in f32 or, when that loses too much accuracy, f64.
+#[derive(Debug)]
+pub struct LinearFeasibility<T: Scalar = f32> {
+    n: usize,
+    a: Arc<[Vec<(usize, T)>]>,
+    b: Arc<[T]>,
+    problem: Arc<ConstraintProblem<T>>,
+}
+
+impl<T: Scalar> LinearFeasibility<T> {
+    // `a` is a row-major m x n matrix with m = b.len().
+    pub fn new(n: usize, a: Vec<T>, b: Vec<T>) -> Self {
+        if a.len() != b.len() * n {
+            panic!(
+                "invalid matrix: expected {} values for {} rows of {n}, got {}",
+                b.len() * n,
+                b.len(),
+                a.len()
+            );
+        }
+
+        let rows = a
+            .chunks(n.max(1))
+            .map(|row| row.iter().copied().enumerate().collect())
+            .collect();
+        Self::from_rows(n, rows, b)
+    }
+
+    // For many inequalities with few variables each, without a dense A.
+    #[cfg(feature = "nalgebra-sparse")]
+    pub fn from_csr(a: &nalgebra_sparse::CsrMatrix<T>, b: Vec<T>) -> Self {
+        if a.nrows() != b.len() {
+            panic!(
+                "invalid matrix: expected {} rows, got {}",
+                b.len(),
+                a.nrows()
+            );
+        }
+
+        let rows = a
+            .row_iter()
+            .map(|row| {
+                let entries = row.col_indices().iter().zip(row.values());
+                entries.map(|(&j, &c)| (j, c)).collect()
+            })
+            .collect();
+        Self::from_rows(a.ncols(), rows, b)
+    }
+
+    fn from_rows(n: usize, rows: Vec<Vec<(usize, T)>>, b: Vec<T>) -> Self {
+        // Only the non-zero coefficients matter from here on.
+        let rows: Vec<Vec<(usize, T)>> = rows
+            .into_iter()
+            .map(|row| row.into_iter().filter(|&(_, c)| c != T::ZERO).collect())
+            .collect();
+
+        let mut problem = ConstraintProblem::default();
+        let x = problem.variables(n);
+        for (i, (row, &bi)) in rows.iter().zip(&b).enumerate() {
+            if row.is_empty() {
+                if bi < T::ZERO {
+                    panic!("invalid row {i}: expected b >= 0 for an empty row, got {bi}");
+                }
+                continue;
+            }
+            let (vars, coefficients): (Vec<Variable>, Vec<T>) =
+                row.iter().map(|&(j, c)| (x[j], c)).unzip();
+            problem.constrain(&vars, HalfspaceProjector::new(coefficients, bi));
+        }
+
+        Self {
+            n,
+            a: rows.into(),
+            b: b.into(),
+            problem: Arc::new(problem),
+        }
+    }
+
+    pub fn n_variables(&self) -> usize {
+        self.n
+    }
+
+    pub fn n_inequalities(&self) -> usize {
+        self.b.len()
+    }
+
+    pub fn problem(&self) -> &ConstraintProblem<T> {
+        &self.problem
+    }
+
+    pub fn initial_state(&self, values: &[T]) -> Result<ProblemState<T>> {
+        self.problem.initial_state(values)
+    }
+
+    // Only accepts a fixed point whose consensus satisfies every inequality.
+    pub fn solver(&self, beta: f32, epsilon: f32, n_steps: usize) -> ProblemSolver<'_, T> {
+        let problem = Arc::clone(&self.problem);
+        let (a, b) = (Arc::clone(&self.a), Arc::clone(&self.b));
+        self.problem
+            .solver(beta, epsilon, n_steps)
+            .with_feasibility(move |state: &ProblemState<T>| match problem.decode(state) {
+                Ok(x) => residuals(&a, &b, &x)
+                    .iter()
+                    .filter(|&&r| r > T::from_f32(TOLERANCE))
+                    .count() as f32,
+                Err(_) => f32::INFINITY,
+            })
+    }
+
+    pub fn decode(&self, state: &ProblemState<T>) -> Result<Vec<T>> {
+        self.problem.decode(state)
+    }
+
+    // max(0, a_i . x - b_i) for every row.
+    pub fn residuals(&self, x: &[T]) -> Vec<T> {
+        residuals(&self.a, &self.b, x)
+    }
+
+    pub fn max_violation(&self, x: &[T]) -> T {
+        self.residuals(x)
+            .into_iter()
+            .fold(T::ZERO, |m, r| if r > m { r } else { m })
+    }
+
+    pub fn is_feasible(&self, x: &[T]) -> bool {
+        x.len() == self.n && self.max_violation(x) <= T::from_f32(TOLERANCE)
+    }
+}
+
+fn residuals<T: Scalar>(a: &[Vec<(usize, T)>], b: &[T], x: &[T]) -> Vec<T> {
+    a.iter()
+        .zip(b)
+        .map(|(row, &bi)| {
+            let ax: T = row
+                .iter()
+                .map(|&(j, c)| c * x.get(j).copied().unwrap_or(T::ZERO))
+                .sum();
+            let r = ax - bi;
+            if r > T::ZERO {
+                r
+            } else {
+                T::ZERO
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{states::double::VecState64, Solver};
+
+    #[test]
+    fn test_residuals() {
+        // x + y <= 1, -x <= 0, -y <= 0
+        let lp = LinearFeasibility::new(
+            2,
+            vec![1.0f32, 1.0, -1.0, 0.0, 0.0, -1.0],
+            vec![1.0, 0.0, 0.0],
+        );
+        assert_eq!(lp.residuals(&[1.0, 1.0]), vec![1.0, 0.0, 0.0]);
+        assert_eq!(lp.max_violation(&[-0.5, 0.25]), 0.5);
+        assert!(lp.is_feasible(&[0.25, 0.5]));
+        // Each row only touches its non-zero coefficients.
+        assert_eq!(lp.problem().n_constraints(), 3);
+    }
+
+    #[test]
+    fn test_solve() {
+        // A triangle cut by two more halfspaces, starting well outside it.
+        let a = vec![1.0f32, 1.0, -1.0, 0.0, 0.0, -1.0, 1.0, -2.0, -2.0, 1.0];
+        let b = vec![4.0, 0.0, 0.0, 0.5, 0.5];
+        let lp = LinearFeasibility::new(2, a, b);
+
+        let state = lp.initial_state(&[10.0, -7.0]).unwrap();
+        let (state, _, _) = lp.solver(0.5, 1e-6, 10000).run(state).unwrap();
+        let x = lp.decode(&state).unwrap();
+        assert!(lp.is_feasible(&x));
+    }
+
+    #[test]
+    fn test_solve_f64() {
+        // A sliver 1e-9 wide, x + y in [1 - 1e-9, 1]: f32 can't even tell its
+        // edges apart, its spacing near 1 being 6e-8.
+        let a = vec![1.0, 1.0, -1.0, -1.0, -1.0, 0.0, 0.0, -1.0];
+        let b = vec![1.0, 1e-9 - 1.0, 0.0, 0.0];
+        let lp = LinearFeasibility::<f64>::new(2, a, b);
+
+        let state = lp.initial_state(&[3.0, -2.0]).unwrap();
+        assert_eq!(state.copies[0], VecState64(vec![3.0, -2.0]));
+        let (state, _, _) = lp.solver(0.5, 1e-9, 100_000).run(state).unwrap();
+        let x = lp.decode(&state).unwrap();
+        assert!(lp.max_violation(&x) < 1e-9, "{x:?}");
+        assert!(x.iter().all(|&xi| xi >= 0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid row 1: expected b >= 0 for an empty row, got -1")]
+    fn test_infeasible_empty_row() {
+        LinearFeasibility::new(2, vec![1.0, 0.0, 0.0, 0.0], vec![1.0, -1.0]);
+    }
+
+    #[cfg(feature = "nalgebra-sparse")]
+    #[test]
+    fn test_from_csr() {
+        use nalgebra_sparse::{CooMatrix, CsrMatrix};
+
+        // The same triangle and cuts as `test_solve`, given by their non-zeros.
+        let mut a = CooMatrix::new(5, 2);
+        for (i, j, c) in [
+            (0, 0, 1.0),
+            (0, 1, 1.0),
+            (1, 0, -1.0),
+            (2, 1, -1.0),
+            (3, 0, 1.0),
+            (3, 1, -2.0),
+            (4, 0, -2.0),
+            (4, 1, 1.0),
+        ] {
+            a.push(i, j, c);
+        }
+        let lp = LinearFeasibility::from_csr(&CsrMatrix::from(&a), vec![4.0, 0.0, 0.0, 0.5, 0.5]);
+        assert_eq!(lp.problem().n_constraints(), 5);
+
+        let state = lp.initial_state(&[10.0, -7.0]).unwrap();
+        let (state, _, _) = lp.solver(0.5, 1e-6, 10000).run(state).unwrap();
+        assert!(lp.is_feasible(&lp.decode(&state).unwrap()));
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    problem::{ConstraintProblem, ProblemSolver, ProblemState, Variable},
+    projectors::{hyperplane::HyperplaneProjector, integer::IntegerProjector},
+    states::vector::VecState,
+    Result,
+};
+use std::sync::Arc;
+
+// Search for an n x n magic square: every row, column and main diagonal sums to
+// n (n^2 + 1) / 2. Each line is a hyperplane, each cell lies on the integer
+// lattice in [1, n^2], and by default the cells are also held to a
+// rearrangement of 1..=n^2.
+#[derive(Debug)]
+pub struct MagicSquare {
+    n: usize,
+    problem: Arc<ConstraintProblem>,
+}
+
+impl MagicSquare {
+    pub fn new(n: usize) -> Self {
+        Self::build(n, true)
+    }
+
+    // Integer entries in [1, n^2] with the magic sums, repeats allowed.
+    pub fn with_repeats(n: usize) -> Self {
+        Self::build(n, false)
+    }
+
+    fn build(n: usize, distinct: bool) -> Self {
+        if n == 0 {
+            panic!("invalid square: expected n >= 1, got {n}");
+        }
+
+        let mut problem = ConstraintProblem::new();
+        let cells = problem.variables(n * n);
+        let sum = magic_sum(n) as f32;
+
+        let lines = lines(n);
+        for line in &lines {
+            let vars: Vec<Variable> = line.iter().map(|&i| cells[i]).collect();
+            problem.constrain(&vars, HyperplaneProjector::new(vec![1.0; n], sum));
+        }
+
+        let max = (n * n) as f32;
+        for &cell in &cells {
+            problem.constrain(&[cell], IntegerProjector::bounded(1.0, max));
+        }
+        if distinct {
+            problem.constrain(&cells, rearrange);
+        }
+
+        Self {
+            n,
+            problem: Arc::new(problem),
+        }
+    }
+
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    pub fn problem(&self) -> &ConstraintProblem {
+        &self.problem
+    }
+
+    // One value per cell, row-major.
+    pub fn initial_state(&self, values: &[f32]) -> Result<ProblemState> {
+        self.problem.initial_state(values)
+    }
+
+    // Only accepts a fixed point whose rounded square is magic.
+    pub fn solver(&self, beta: f32, epsilon: f32, n_steps: usize) -> ProblemSolver<'_> {
+        let problem = Arc::clone(&self.problem);
+        let n = self.n;
+        self.problem
+            .solver(beta, epsilon, n_steps)
+            .with_feasibility(move |state: &ProblemState| match problem.decode(state) {
+                Ok(values) => violations(n, &round(&values)) as f32,
+                Err(_) => f32::INFINITY,
+            })
+    }
+
+    // Rounded cell values, row-major.
+    pub fn decode(&self, state: &ProblemState) -> Result<Vec<usize>> {
+        Ok(round(&self.problem.decode(state)?))
+    }
+}
+
+pub fn magic_sum(n: usize) -> usize {
+    n * (n * n + 1) / 2
+}
+
+// Rows, columns and both diagonals whose sum differs from the magic sum.
+pub fn violations(n: usize, square: &[usize]) -> usize {
+    lines(n)
+        .iter()
+        .filter(|line| line.iter().map(|&i| square[i]).sum::<usize>() != magic_sum(n))
+        .count()
+}
+
+pub fn is_magic(n: usize, square: &[usize]) -> bool {
+    let mut seen = vec![false; n * n + 1];
+    let distinct = square.iter().all(|&v| {
+        let fresh = (1..=n * n).contains(&v) && !seen[v];
+        if fresh {
+            seen[v] = true;
+        }
+        fresh
+    });
+    square.len() == n * n && distinct && violations(n, square) == 0
+}
+
+fn lines(n: usize) -> Vec<Vec<usize>> {
+    let rows = (0..n).map(|r| (0..n).map(|c| r * n + c).collect());
+    let columns = (0..n).map(|c| (0..n).map(|r| r * n + c).collect());
+    let diagonals = [
+        (0..n).map(|i| i * n + i).collect(),
+        (0..n).map(|i| i * n + n - 1 - i).collect(),
+    ];
+    rows.chain(columns).chain(diagonals).collect()
+}
+
+fn round(values: &[f32]) -> Vec<usize> {
+    values.iter().map(|x| x.round().max(0.0) as usize).collect()
+}
+
+// Nearest rearrangement of 1..=len: the k-th smallest coordinate becomes k.
+fn rearrange(mut state: VecState) -> Result<VecState> {
+    let mut order: Vec<usize> = (0..state.len()).collect();
+    order.sort_unstable_by(|&i, &j| state[i].total_cmp(&state[j]));
+    for (rank, i) in order.into_iter().enumerate() {
+        state[i] = (rank + 1) as f32;
+    }
+    Ok(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{problems::rng::SplitMix64, Solver};
+
+    #[test]
+    fn test_is_magic() {
+        let lo_shu = [2, 7, 6, 9, 5, 1, 4, 3, 8];
+        assert!(is_magic(3, &lo_shu));
+        assert_eq!(violations(3, &[1, 2, 3, 4, 5, 6, 7, 8, 9]), 4);
+        assert!(!is_magic(3, &[5; 9]));
+        assert_eq!(violations(3, &[5; 9]), 0);
+    }
+
+    #[test]
+    fn test_rearrange() {
+        let output = rearrange(VecState(vec![0.3, -1.0, 7.5, 2.0])).unwrap();
+        assert_eq!(output, VecState(vec![2.0, 1.0, 4.0, 3.0]));
+    }
+
+    #[test]
+    fn test_solve() {
+        let square = MagicSquare::new(3);
+        let mut rng = SplitMix64::new(3);
+        let values: Vec<f32> = (0..9).map(|_| 1.0 + 8.0 * rng.uniform()).collect();
+
+        let state = square.initial_state(&values).unwrap();
+        let (state, _, _) = square.solver(1.0, 1e-3, 20000).run(state).unwrap();
+        assert!(is_magic(3, &square.decode(&state).unwrap()));
+    }
+}
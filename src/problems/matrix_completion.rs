@@ -0,0 +1,216 @@
+use crate::{
+    errors::Error, norms::euclidean_contiguous, projectors::rank::RankProjector,
+    solvers::divide_and_concur::DivideAndConcurSolver, states::vector::VecState, Projector, Result,
+};
+use nalgebra::DMatrix;
+use std::io::BufRead;
+
+pub type MatrixCompletionSolver = DivideAndConcurSolver<
+    VecState,
+    RankProjector,
+    ObservedProjector,
+    fn(&VecState, &VecState) -> f32,
+>;
+
+// Fixes the observed entries of a column-major nrows x ncols matrix and leaves
+// the rest alone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObservedProjector {
+    len: usize,
+    entries: Vec<(usize, f32)>,
+}
+
+impl ObservedProjector {
+    pub fn new(nrows: usize, ncols: usize, observations: &[(usize, usize, f32)]) -> Self {
+        let entries = observations
+            .iter()
+            .map(|&(r, c, value)| {
+                if r >= nrows || c >= ncols {
+                    panic!(
+                        "invalid observation: expected an entry inside {nrows}x{ncols}, got ({r}, {c})"
+                    );
+                }
+                (c * nrows + r, value)
+            })
+            .collect();
+
+        Self {
+            len: nrows * ncols,
+            entries,
+        }
+    }
+}
+
+impl<S> Projector<S> for ObservedProjector
+where
+    S: AsMut<[f32]>,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        if values.len() != self.len {
+            return Err(Error::Dimension(self.len, values.len()));
+        }
+
+        for &(i, value) in &self.entries {
+            values[i] = value;
+        }
+
+        Ok(state)
+    }
+}
+
+// Recover a rank-k matrix from a subset of its entries: divide is the rank-k
+// projector, concur pins the observations.
+#[derive(Debug, Clone)]
+pub struct MatrixCompletion {
+    nrows: usize,
+    ncols: usize,
+    rank: usize,
+    observations: Vec<(usize, usize, f32)>,
+    observed: ObservedProjector,
+}
+
+impl MatrixCompletion {
+    pub fn new(
+        nrows: usize,
+        ncols: usize,
+        rank: usize,
+        observations: Vec<(usize, usize, f32)>,
+    ) -> Self {
+        if rank == 0 {
+            panic!("invalid rank: expected k >= 1, got {rank}");
+        }
+        let observed = ObservedProjector::new(nrows, ncols, &observations);
+
+        Self {
+            nrows,
+            ncols,
+            rank,
+            observations,
+            observed,
+        }
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+
+    pub fn observations(&self) -> &[(usize, usize, f32)] {
+        &self.observations
+    }
+
+    // The observed entries with zeros elsewhere, column-major.
+    pub fn initial_state(&self) -> VecState {
+        self.observed
+            .project(VecState(vec![0.0; self.nrows * self.ncols]))
+            .expect("state sized to the matrix")
+    }
+
+    pub fn solver(&self, beta: f32, epsilon: f32, n_steps: usize) -> MatrixCompletionSolver {
+        DivideAndConcurSolver::new(
+            RankProjector::new(self.rank, self.nrows, self.ncols),
+            self.observed.clone(),
+            euclidean_contiguous as fn(&VecState, &VecState) -> f32,
+            beta,
+            epsilon,
+            n_steps,
+        )
+    }
+
+    pub fn decode(&self, state: &VecState) -> Result<DMatrix<f32>> {
+        if state.len() != self.nrows * self.ncols {
+            return Err(Error::Dimension(self.nrows * self.ncols, state.len()));
+        }
+
+        Ok(DMatrix::from_column_slice(self.nrows, self.ncols, state))
+    }
+}
+
+// Reads `row col value` triplets, separated by whitespace or commas, skipping
+// blank lines and `#` comments.
+pub fn read_triplets<R>(reader: R) -> Result<Vec<(usize, usize, f32)>>
+where
+    R: BufRead,
+{
+    let mut triplets = Vec::new();
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.map_err(|err| Error::Parse(i + 1, err.to_string()))?;
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let fields: Vec<&str> = line
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|f| !f.is_empty())
+            .collect();
+        let invalid = || Error::Parse(i + 1, format!("expected `row col value`, got `{line}`"));
+        let [r, c, value] = fields[..] else {
+            return Err(invalid());
+        };
+
+        triplets.push((
+            r.parse().map_err(|_| invalid())?,
+            c.parse().map_err(|_| invalid())?,
+            value.parse().map_err(|_| invalid())?,
+        ));
+    }
+
+    Ok(triplets)
+}
+
+pub fn rmse(estimate: &DMatrix<f32>, truth: &DMatrix<f32>) -> f32 {
+    ((estimate - truth).norm_squared() / truth.len() as f32).sqrt()
+}
+
+// ||estimate - truth||_F / ||truth||_F
+pub fn relative_error(estimate: &DMatrix<f32>, truth: &DMatrix<f32>) -> f32 {
+    (estimate - truth).norm() / truth.norm()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solver;
+    use nalgebra::DVector;
+
+    #[test]
+    fn test_read_triplets() {
+        let input = "# row col value\n0 1 2.5\n\n3, 0, -1\n";
+        let triplets = read_triplets(input.as_bytes()).unwrap();
+        assert_eq!(triplets, vec![(0, 1, 2.5), (3, 0, -1.0)]);
+
+        assert!(matches!(
+            read_triplets("0 1\n".as_bytes()),
+            Err(Error::Parse(1, _))
+        ));
+    }
+
+    #[test]
+    fn test_observed_projector() {
+        let projector = ObservedProjector::new(2, 2, &[(1, 0, 5.0), (0, 1, -1.0)]);
+        let output = projector.project(vec![0.0; 4]).unwrap();
+        assert_eq!(output, vec![0.0, 5.0, -1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_solve() {
+        let u = DVector::from_vec(vec![1.0, 2.0, -1.0, 0.5, 1.5]);
+        let v = DVector::from_vec(vec![2.0, -1.0, 1.0, 3.0, 0.5, 1.0]);
+        let truth = &u * v.transpose();
+
+        let observations = (0..truth.len())
+            .filter(|i| i % 3 != 1)
+            .map(|i| (i % 5, i / 5, truth[i]))
+            .collect();
+        let problem = MatrixCompletion::new(5, 6, 1, observations);
+
+        let (state, _, _) = problem
+            .solver(1.0, 1e-6, 5000)
+            .run(problem.initial_state())
+            .unwrap();
+        let estimate = problem.decode(&state).unwrap();
+        assert!(relative_error(&estimate, &truth) < 1e-3);
+        assert!(rmse(&estimate, &truth) < 1e-3);
+    }
+}
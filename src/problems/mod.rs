@@ -0,0 +1,24 @@
+#[cfg(feature = "nalgebra")]
+pub mod binary_feasibility;
+#[cfg(feature = "nalgebra")]
+pub mod compressed_sensing;
+pub mod exact_cover;
+pub mod latin_square;
+pub mod linear_feasibility;
+pub mod magic_square;
+#[cfg(feature = "nalgebra")]
+pub mod matrix_completion;
+pub mod n_queens;
+pub mod nonogram;
+pub mod packing;
+#[cfg(feature = "fft")]
+pub mod phase_retrieval;
+pub mod qubo;
+pub mod sat;
+pub mod sudoku;
+pub mod sudoku_generator;
+
+// Shared by the synthetic instance generators, not all of which are enabled in
+// every feature combination.
+#[allow(dead_code)]
+pub(crate) mod rng;
@@ -0,0 +1,136 @@
+use crate::{
+    problem::{ConstraintProblem, ProblemSolver, ProblemState},
+    projectors::{one_hot::OneHotProjector, permutation::PermutationProjector},
+    Result,
+};
+use std::sync::Arc;
+
+// Place n non-attacking queens on an n x n board. Cell (r, c) is a 0/1 variable
+// at index r * n + c; rows and columns together form a permutation matrix, and
+// every diagonal holds at most one queen.
+#[derive(Debug)]
+pub struct NQueens {
+    n: usize,
+    problem: Arc<ConstraintProblem>,
+}
+
+impl NQueens {
+    pub fn new(n: usize) -> Self {
+        if n == 0 {
+            panic!("invalid board: expected n >= 1, got {n}");
+        }
+
+        let mut problem = ConstraintProblem::new();
+        let cells = problem.variables(n * n);
+        problem.constrain(&cells, PermutationProjector::new(n));
+
+        let mut diagonals = vec![Vec::new(); 2 * n - 1];
+        let mut anti_diagonals = vec![Vec::new(); 2 * n - 1];
+        for r in 0..n {
+            for c in 0..n {
+                diagonals[r + n - 1 - c].push(cells[r * n + c]);
+                anti_diagonals[r + c].push(cells[r * n + c]);
+            }
+        }
+        for diagonal in diagonals.iter().chain(&anti_diagonals) {
+            if diagonal.len() > 1 {
+                problem.constrain(diagonal, OneHotProjector::at_most_one());
+            }
+        }
+
+        Self {
+            n,
+            problem: Arc::new(problem),
+        }
+    }
+
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    pub fn problem(&self) -> &ConstraintProblem {
+        &self.problem
+    }
+
+    // One value per cell, row-major.
+    pub fn initial_state(&self, values: &[f32]) -> Result<ProblemState> {
+        self.problem.initial_state(values)
+    }
+
+    // Only accepts a fixed point whose decoded placement has no attacks.
+    pub fn solver(&self, beta: f32, epsilon: f32, n_steps: usize) -> ProblemSolver<'_> {
+        let problem = Arc::clone(&self.problem);
+        let n = self.n;
+        self.problem
+            .solver(beta, epsilon, n_steps)
+            .with_feasibility(move |state: &ProblemState| match problem.decode(state) {
+                Ok(values) => attacks(&placement(n, &values)) as f32,
+                Err(_) => f32::INFINITY,
+            })
+    }
+
+    // The queen's column in each row.
+    pub fn decode(&self, state: &ProblemState) -> Result<Vec<usize>> {
+        Ok(placement(self.n, &self.problem.decode(state)?))
+    }
+}
+
+fn placement(n: usize, values: &[f32]) -> Vec<usize> {
+    values
+        .chunks(n)
+        .map(|row| {
+            row.iter()
+                .enumerate()
+                .max_by(|(_, a), (_, b)| a.total_cmp(b))
+                .map_or(0, |(c, _)| c)
+        })
+        .collect()
+}
+
+// Pairs of queens sharing a column or a diagonal, given one queen per row.
+pub fn attacks(columns: &[usize]) -> usize {
+    let mut count = 0;
+    for (r1, &c1) in columns.iter().enumerate() {
+        for (r2, &c2) in columns.iter().enumerate().skip(r1 + 1) {
+            if c1 == c2 || r2 - r1 == c1.abs_diff(c2) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{problems::rng::SplitMix64, Solver};
+
+    #[test]
+    fn test_attacks() {
+        assert_eq!(attacks(&[1, 3, 0, 2]), 0);
+        assert_eq!(attacks(&[0, 1, 2, 3]), 6);
+        assert_eq!(attacks(&[0, 2, 0]), 1);
+    }
+
+    #[test]
+    fn test_constraints() {
+        let queens = NQueens::new(4);
+        // One permutation constraint plus 2 * (2n - 3) diagonals of length >= 2.
+        assert_eq!(queens.problem().n_constraints(), 11);
+        assert_eq!(queens.problem().n_variables(), 16);
+    }
+
+    #[test]
+    fn test_solve() {
+        let n = 8;
+        let queens = NQueens::new(n);
+        let mut rng = SplitMix64::new(7);
+        let values: Vec<f32> = (0..n * n).map(|_| rng.uniform()).collect();
+
+        let state = queens.initial_state(&values).unwrap();
+        let (state, _, _) = queens.solver(1.0, 1e-3, 20000).run(state).unwrap();
+        let columns = queens.decode(&state).unwrap();
+        assert_eq!(columns.len(), n);
+        assert_eq!(attacks(&columns), 0);
+    }
+}
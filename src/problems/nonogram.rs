@@ -0,0 +1,246 @@
+use crate::{
+    errors::Error,
+    problem::{ConstraintProblem, ProblemSolver, ProblemState},
+    Projector, Result,
+};
+use std::sync::Arc;
+
+// Projects a line of cell values onto the 0/1 lines whose filled runs match
+// `clue`, by dynamic programming over (position, runs placed).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunLengthProjector {
+    clue: Vec<usize>,
+    len: usize,
+}
+
+impl RunLengthProjector {
+    pub fn new(clue: Vec<usize>, len: usize) -> Self {
+        if clue.contains(&0) {
+            panic!("invalid clue: expected positive run lengths, got {clue:?}");
+        }
+        let needed = clue.iter().sum::<usize>() + clue.len().saturating_sub(1);
+        if needed > len {
+            panic!("invalid clue: expected at most {len} cells, got {needed}");
+        }
+
+        Self { clue, len }
+    }
+
+    fn solve(&self, values: &[f32]) -> Vec<bool> {
+        let (n, m) = (self.len, self.clue.len());
+        let white: Vec<f32> = values.iter().map(|x| x * x).collect();
+        let mut black = vec![0f32; n + 1];
+        for (i, x) in values.iter().enumerate() {
+            black[i + 1] = black[i] + (1.0 - x).powi(2);
+        }
+
+        // cost[i][k]: cheapest way to fill cells i.. with runs k.. still to place.
+        let mut cost = vec![vec![f32::INFINITY; m + 1]; n + 2];
+        let mut place = vec![vec![false; m + 1]; n + 1];
+        cost[n][m] = 0.0;
+        cost[n + 1][m] = 0.0;
+        for i in (0..n).rev() {
+            for k in (0..=m).rev() {
+                let mut best = white[i] + cost[i + 1][k];
+                if k < m && i + self.clue[k] <= n {
+                    let end = i + self.clue[k];
+                    let run = black[end] - black[i];
+                    let candidate = if end == n {
+                        run + cost[n][k + 1]
+                    } else {
+                        run + white[end] + cost[end + 1][k + 1]
+                    };
+                    if candidate < best {
+                        best = candidate;
+                        place[i][k] = true;
+                    }
+                }
+                cost[i][k] = best;
+            }
+        }
+
+        let mut line = vec![false; n];
+        let (mut i, mut k) = (0, 0);
+        while i < n {
+            if place[i][k] {
+                let end = i + self.clue[k];
+                line[i..end].iter_mut().for_each(|x| *x = true);
+                i = end + 1;
+                k += 1;
+            } else {
+                i += 1;
+            }
+        }
+        line
+    }
+}
+
+impl<S> Projector<S> for RunLengthProjector
+where
+    S: AsMut<[f32]>,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        if values.len() != self.len {
+            return Err(Error::Dimension(self.len, values.len()));
+        }
+
+        let line = self.solve(values);
+        for (x, filled) in values.iter_mut().zip(line) {
+            *x = if filled { 1.0 } else { 0.0 };
+        }
+
+        Ok(state)
+    }
+}
+
+// The run lengths of filled cells in a line.
+pub fn clue(line: &[bool]) -> Vec<usize> {
+    line.split(|&filled| !filled)
+        .map(<[bool]>::len)
+        .filter(|&len| len > 0)
+        .collect()
+}
+
+// A nonogram with one clue per row and per column; cell (r, c) is a 0/1
+// variable at index r * width + c.
+#[derive(Debug)]
+pub struct Nonogram {
+    rows: Arc<[Vec<usize>]>,
+    columns: Arc<[Vec<usize>]>,
+    problem: Arc<ConstraintProblem>,
+}
+
+impl Nonogram {
+    pub fn new(rows: Vec<Vec<usize>>, columns: Vec<Vec<usize>>) -> Self {
+        let (height, width) = (rows.len(), columns.len());
+        let mut problem = ConstraintProblem::new();
+        let cells = problem.variables(height * width);
+
+        for (r, clue) in rows.iter().enumerate() {
+            let line: Vec<_> = (0..width).map(|c| cells[r * width + c]).collect();
+            problem.constrain(&line, RunLengthProjector::new(clue.clone(), width));
+        }
+        for (c, clue) in columns.iter().enumerate() {
+            let line: Vec<_> = (0..height).map(|r| cells[r * width + c]).collect();
+            problem.constrain(&line, RunLengthProjector::new(clue.clone(), height));
+        }
+
+        Self {
+            rows: rows.into(),
+            columns: columns.into(),
+            problem: Arc::new(problem),
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn problem(&self) -> &ConstraintProblem {
+        &self.problem
+    }
+
+    // One value per cell, row-major.
+    pub fn initial_state(&self, values: &[f32]) -> Result<ProblemState> {
+        self.problem.initial_state(values)
+    }
+
+    // Only accepts a fixed point whose decoded grid matches every clue.
+    pub fn solver(&self, beta: f32, epsilon: f32, n_steps: usize) -> ProblemSolver<'_> {
+        let problem = Arc::clone(&self.problem);
+        let (rows, columns) = (Arc::clone(&self.rows), Arc::clone(&self.columns));
+        self.problem
+            .solver(beta, epsilon, n_steps)
+            .with_feasibility(move |state: &ProblemState| match problem.decode(state) {
+                Ok(values) => violations(&rows, &columns, &grid(columns.len(), &values)) as f32,
+                Err(_) => f32::INFINITY,
+            })
+    }
+
+    // Filled cells by row.
+    pub fn decode(&self, state: &ProblemState) -> Result<Vec<Vec<bool>>> {
+        Ok(grid(self.width(), &self.problem.decode(state)?))
+    }
+
+    // Rows and columns whose runs differ from their clue.
+    pub fn violations(&self, grid: &[Vec<bool>]) -> usize {
+        violations(&self.rows, &self.columns, grid)
+    }
+}
+
+fn grid(width: usize, values: &[f32]) -> Vec<Vec<bool>> {
+    values
+        .chunks(width.max(1))
+        .map(|row| row.iter().map(|&x| x >= 0.5).collect())
+        .collect()
+}
+
+fn violations(rows: &[Vec<usize>], columns: &[Vec<usize>], grid: &[Vec<bool>]) -> usize {
+    let bad_rows = rows
+        .iter()
+        .zip(grid)
+        .filter(|(expected, row)| clue(row) != **expected)
+        .count();
+    let bad_columns = columns
+        .iter()
+        .enumerate()
+        .filter(|(c, expected)| {
+            let column: Vec<bool> = grid.iter().map(|row| row[*c]).collect();
+            clue(&column) != **expected
+        })
+        .count();
+    bad_rows + bad_columns
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solver;
+
+    #[test]
+    fn test_run_length_projector() {
+        let projector = RunLengthProjector::new(vec![2, 1], 5);
+        let output = projector.project(vec![0.9, 0.6, 0.4, 0.1, 0.8]).unwrap();
+        assert_eq!(output, vec![1.0, 1.0, 0.0, 0.0, 1.0]);
+
+        let output = projector.project(vec![0.0, 0.9, 0.9, 0.0, 0.4]).unwrap();
+        assert_eq!(output, vec![0.0, 1.0, 1.0, 0.0, 1.0]);
+
+        let empty = RunLengthProjector::new(vec![], 3);
+        assert_eq!(empty.project(vec![0.9; 3]).unwrap(), vec![0.0; 3]);
+
+        assert!(matches!(
+            projector.project(vec![0.0; 4]),
+            Err(Error::Dimension(5, 4))
+        ));
+    }
+
+    #[test]
+    fn test_clue() {
+        assert_eq!(clue(&[true, true, false, true, false]), vec![2, 1]);
+        assert!(clue(&[false, false]).is_empty());
+    }
+
+    #[test]
+    fn test_solve() {
+        // . # # # .
+        // # # . # #
+        // # # # # #
+        // . # # # .
+        // . . # . .
+        let rows = vec![vec![3], vec![2, 2], vec![5], vec![3], vec![1]];
+        let columns = vec![vec![2], vec![4], vec![1, 3], vec![4], vec![2]];
+        let nonogram = Nonogram::new(rows, columns);
+
+        let state = nonogram.initial_state(&[0.5; 25]).unwrap();
+        let (state, _, _) = nonogram.solver(1.0, 1e-3, 10000).run(state).unwrap();
+        let grid = nonogram.decode(&state).unwrap();
+        assert_eq!(nonogram.violations(&grid), 0);
+        assert_eq!(grid[1], vec![true, true, false, true, true]);
+    }
+}
@@ -0,0 +1,271 @@
+use crate::{
+    errors::Error,
+    problem::{ConstraintProblem, ProblemSolver, ProblemState, Variable},
+    projectors::{ball::L2BallProjector, box_constraints::BoxProjector},
+    Projector, Result,
+};
+use std::sync::Arc;
+
+// Overlaps and container violations smaller than this are ignored when checking
+// a packing.
+const TOLERANCE: f32 = 1e-4;
+
+// Pushes two centers, stored back to back, symmetrically apart along the line
+// joining them until they are at least `distance` apart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OverlapProjector {
+    dims: usize,
+    distance: f32,
+}
+
+impl OverlapProjector {
+    pub fn new(dims: usize, distance: f32) -> Self {
+        if dims == 0 {
+            panic!("invalid dimension: expected dims >= 1, got {dims}");
+        }
+        if distance < 0.0 {
+            panic!("invalid distance: expected non-negative value, got {distance}");
+        }
+
+        Self { dims, distance }
+    }
+}
+
+impl<S> Projector<S> for OverlapProjector
+where
+    S: AsMut<[f32]>,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        if values.len() != 2 * self.dims {
+            return Err(Error::Dimension(2 * self.dims, values.len()));
+        }
+
+        let (a, b) = values.split_at_mut(self.dims);
+        let gap = a
+            .iter()
+            .zip(b.iter())
+            .map(|(x, y)| (x - y).powi(2))
+            .sum::<f32>()
+            .sqrt();
+        if gap >= self.distance {
+            return Ok(state);
+        }
+
+        // Coincident centers have no preferred direction; separate them along
+        // the first axis.
+        let push = 0.5 * (self.distance - gap);
+        for (k, (x, y)) in a.iter_mut().zip(b.iter_mut()).enumerate() {
+            let direction = match gap > 0.0 {
+                true => (*x - *y) / gap,
+                false if k == 0 => 1.0,
+                false => 0.0,
+            };
+            *x += push * direction;
+            *y -= push * direction;
+        }
+
+        Ok(state)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Container {
+    // The cube [0, side]^dims.
+    Cube(f32),
+    // The ball of this radius about the origin.
+    Ball(f32),
+}
+
+// Pack n balls of equal radius into a container in `dims` dimensions. Each
+// center is `dims` consecutive variables; every pair of balls gets an overlap
+// constraint and every ball a container constraint, and concur averages the
+// copies each ball has across its pairs, following Gravel and Elser's
+// divide-and-concur packings.
+#[derive(Debug)]
+pub struct Packing {
+    n: usize,
+    dims: usize,
+    radius: f32,
+    container: Container,
+    problem: Arc<ConstraintProblem>,
+}
+
+impl Packing {
+    pub fn new(n: usize, dims: usize, radius: f32, container: Container) -> Self {
+        if radius <= 0.0 {
+            panic!("invalid radius: expected positive value, got {radius}");
+        }
+        let room = match container {
+            Container::Cube(side) => 0.5 * side,
+            Container::Ball(outer) => outer,
+        };
+        if radius > room {
+            panic!("invalid radius: expected at most {room} to fit the container, got {radius}");
+        }
+
+        let mut problem = ConstraintProblem::new();
+        let centers: Vec<Vec<Variable>> = (0..n).map(|_| problem.variables(dims)).collect();
+
+        for (i, a) in centers.iter().enumerate() {
+            for b in &centers[i + 1..] {
+                let pair: Vec<Variable> = a.iter().chain(b).copied().collect();
+                problem.constrain(&pair, OverlapProjector::new(dims, 2.0 * radius));
+            }
+        }
+        for center in &centers {
+            match container {
+                Container::Cube(side) => {
+                    problem.constrain(center, BoxProjector::new(radius, side - radius))
+                }
+                Container::Ball(outer) => {
+                    problem.constrain(center, L2BallProjector::new(outer - radius))
+                }
+            };
+        }
+
+        Self {
+            n,
+            dims,
+            radius,
+            container,
+            problem: Arc::new(problem),
+        }
+    }
+
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    pub fn dims(&self) -> usize {
+        self.dims
+    }
+
+    pub fn radius(&self) -> f32 {
+        self.radius
+    }
+
+    pub fn container(&self) -> Container {
+        self.container
+    }
+
+    pub fn problem(&self) -> &ConstraintProblem {
+        &self.problem
+    }
+
+    // Starting centers, flattened.
+    pub fn initial_state(&self, values: &[f32]) -> Result<ProblemState> {
+        self.problem.initial_state(values)
+    }
+
+    // Only accepts a fixed point whose averaged centers form a valid packing.
+    pub fn solver(&self, beta: f32, epsilon: f32, n_steps: usize) -> ProblemSolver<'_> {
+        let problem = Arc::clone(&self.problem);
+        let (dims, radius, container) = (self.dims, self.radius, self.container);
+        self.problem
+            .solver(beta, epsilon, n_steps)
+            .with_feasibility(move |state: &ProblemState| match problem.decode(state) {
+                Ok(values) => {
+                    let centers: Vec<Vec<f32>> = values.chunks(dims).map(<[f32]>::to_vec).collect();
+                    violations(&centers, radius, container) as f32
+                }
+                Err(_) => f32::INFINITY,
+            })
+    }
+
+    // One center per ball.
+    pub fn decode(&self, state: &ProblemState) -> Result<Vec<Vec<f32>>> {
+        let values = self.problem.decode(state)?;
+        Ok(values.chunks(self.dims).map(<[f32]>::to_vec).collect())
+    }
+
+    // Overlapping pairs plus balls sticking out of the container.
+    pub fn violations(&self, centers: &[Vec<f32>]) -> usize {
+        violations(centers, self.radius, self.container)
+    }
+}
+
+fn violations(centers: &[Vec<f32>], radius: f32, container: Container) -> usize {
+    let distance = |a: &[f32], b: &[f32]| {
+        a.iter()
+            .zip(b)
+            .map(|(x, y)| (x - y).powi(2))
+            .sum::<f32>()
+            .sqrt()
+    };
+
+    let mut count = 0;
+    for (i, a) in centers.iter().enumerate() {
+        count += centers[i + 1..]
+            .iter()
+            .filter(|b| distance(a, b) < 2.0 * radius - TOLERANCE)
+            .count();
+
+        let outside = match container {
+            Container::Cube(side) => a
+                .iter()
+                .any(|&x| x < radius - TOLERANCE || x > side - radius + TOLERANCE),
+            Container::Ball(outer) => {
+                a.iter().map(|x| x * x).sum::<f32>().sqrt() > outer - radius + TOLERANCE
+            }
+        };
+        count += outside as usize;
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{problems::rng::SplitMix64, Solver};
+
+    fn random(n: usize, seed: u64) -> Vec<f32> {
+        let mut rng = SplitMix64::new(seed);
+        (0..n).map(|_| rng.uniform()).collect()
+    }
+
+    #[test]
+    fn test_overlap_projector() {
+        let projector = OverlapProjector::new(2, 2.0);
+        let output = projector.project(vec![0.0, 0.0, 1.0, 0.0]).unwrap();
+        assert_eq!(output, vec![-0.5, 0.0, 1.5, 0.0]);
+
+        let apart = vec![0.0, 0.0, 3.0, 0.0];
+        assert_eq!(projector.project(apart.clone()).unwrap(), apart);
+
+        let output = projector.project(vec![1.0, 1.0, 1.0, 1.0]).unwrap();
+        assert_eq!(output, vec![2.0, 1.0, 0.0, 1.0]);
+
+        assert!(matches!(
+            projector.project(vec![0.0; 3]),
+            Err(Error::Dimension(4, 3))
+        ));
+    }
+
+    #[test]
+    fn test_violations() {
+        let packing = Packing::new(2, 2, 0.25, Container::Cube(1.0));
+        assert_eq!(packing.violations(&[vec![0.25, 0.25], vec![0.75, 0.75]]), 0);
+        // Overlapping, and the second ball pokes out of the square.
+        assert_eq!(packing.violations(&[vec![0.25, 0.25], vec![0.5, 0.1]]), 2);
+    }
+
+    #[test]
+    fn test_solve_square() {
+        let packing = Packing::new(5, 2, 0.18, Container::Cube(1.0));
+        let state = packing.initial_state(&random(10, 3)).unwrap();
+        let (state, _, _) = packing.solver(0.5, 1e-5, 20000).run(state).unwrap();
+        let centers = packing.decode(&state).unwrap();
+        assert_eq!(centers.len(), 5);
+        assert_eq!(packing.violations(&centers), 0);
+    }
+
+    #[test]
+    fn test_solve_ball() {
+        let packing = Packing::new(7, 3, 0.3, Container::Ball(1.0));
+        let values: Vec<f32> = random(21, 5).iter().map(|x| 2.0 * x - 1.0).collect();
+        let state = packing.initial_state(&values).unwrap();
+        let (state, _, _) = packing.solver(0.5, 1e-5, 20000).run(state).unwrap();
+        assert_eq!(packing.violations(&packing.decode(&state).unwrap()), 0);
+    }
+}
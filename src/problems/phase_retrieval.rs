@@ -0,0 +1,249 @@
+use crate::{
+    errors::Error,
+    problems::rng::SplitMix64,
+    projectors::{
+        fourier::{fourier_magnitudes, FourierMagnitudeProjector},
+        support::SupportProjector,
+    },
+    solvers::divide_and_concur::DivideAndConcurSolver,
+    states::complex::{norm, ComplexState},
+    Result,
+};
+use num_complex::Complex32;
+
+pub type PhaseRetrievalSolver = DivideAndConcurSolver<
+    ComplexState,
+    SupportProjector<Complex32>,
+    FourierMagnitudeProjector,
+    fn(&ComplexState, &ComplexState) -> f32,
+>;
+
+// Recover an object on a row-major nrows x ncols grid from the magnitudes of its
+// Fourier transform: divide confines the object to its support, concur imposes
+// the measured magnitudes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseRetrieval {
+    nrows: usize,
+    ncols: usize,
+    magnitudes: Vec<f32>,
+    support: Vec<bool>,
+    nonnegative: bool,
+}
+
+impl PhaseRetrieval {
+    pub fn new(magnitudes: Vec<f32>, support: Vec<bool>, nrows: usize, ncols: usize) -> Self {
+        if magnitudes.len() != nrows * ncols {
+            panic!(
+                "invalid magnitudes: expected {} values for a {nrows}x{ncols} grid, got {}",
+                nrows * ncols,
+                magnitudes.len()
+            );
+        }
+        if support.len() != nrows * ncols {
+            panic!(
+                "invalid support: expected {} values for a {nrows}x{ncols} grid, got {}",
+                nrows * ncols,
+                support.len()
+            );
+        }
+
+        Self {
+            nrows,
+            ncols,
+            magnitudes,
+            support,
+            nonnegative: false,
+        }
+    }
+
+    // Constrain the object to be real and nonnegative inside its support.
+    pub fn with_nonnegative(mut self) -> Self {
+        self.nonnegative = true;
+        self
+    }
+
+    // The noiseless diffraction pattern of a real object, with `support` as the
+    // a priori constraint.
+    pub fn simulate(object: &[f32], support: Vec<bool>, nrows: usize, ncols: usize) -> Self {
+        let values: Vec<Complex32> = object.iter().map(|&x| Complex32::new(x, 0.0)).collect();
+        Self::new(
+            fourier_magnitudes(&values, nrows, ncols),
+            support,
+            nrows,
+            ncols,
+        )
+    }
+
+    // A nonnegative object with uniform random pixels in the top-left
+    // object_rows x object_cols corner of an nrows x ncols grid, reproducible
+    // from `seed`. The grid should be at least twice the object in each
+    // direction for the phases to be recoverable. Returns the nonnegative
+    // problem together with the true object.
+    pub fn synthetic(
+        nrows: usize,
+        ncols: usize,
+        object_rows: usize,
+        object_cols: usize,
+        seed: u64,
+    ) -> (Self, Vec<f32>) {
+        if object_rows > nrows || object_cols > ncols {
+            panic!(
+                "invalid object: expected at most {nrows}x{ncols}, got {object_rows}x{object_cols}"
+            );
+        }
+
+        let mut rng = SplitMix64::new(seed);
+        let support: Vec<bool> = (0..nrows * ncols)
+            .map(|i| i / ncols < object_rows && i % ncols < object_cols)
+            .collect();
+        let object: Vec<f32> = support
+            .iter()
+            .map(|&inside| if inside { rng.uniform() } else { 0.0 })
+            .collect();
+
+        let problem = Self::simulate(&object, support, nrows, ncols).with_nonnegative();
+        (problem, object)
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.nrows, self.ncols)
+    }
+
+    pub fn magnitudes(&self) -> &[f32] {
+        &self.magnitudes
+    }
+
+    pub fn support(&self) -> &[bool] {
+        &self.support
+    }
+
+    // Real starting values, one per pixel.
+    pub fn initial_state(&self, values: &[f32]) -> Result<ComplexState> {
+        if values.len() != self.nrows * self.ncols {
+            return Err(Error::Dimension(self.nrows * self.ncols, values.len()));
+        }
+
+        Ok(ComplexState(
+            values.iter().map(|&x| Complex32::new(x, 0.0)).collect(),
+        ))
+    }
+
+    pub fn solver(&self, beta: f32, epsilon: f32, n_steps: usize) -> PhaseRetrievalSolver {
+        let support = SupportProjector::new(self.support.clone());
+        let support = match self.nonnegative {
+            true => support.with_nonnegative(),
+            false => support,
+        };
+
+        DivideAndConcurSolver::new(
+            support,
+            FourierMagnitudeProjector::new_2d(self.magnitudes.clone(), self.nrows, self.ncols),
+            norm as fn(&ComplexState, &ComplexState) -> f32,
+            beta,
+            epsilon,
+            n_steps,
+        )
+    }
+
+    // Real part of each pixel, row-major.
+    pub fn decode(&self, state: &ComplexState) -> Result<Vec<f32>> {
+        if state.len() != self.nrows * self.ncols {
+            return Err(Error::Dimension(self.nrows * self.ncols, state.len()));
+        }
+
+        Ok(state.iter().map(|x| x.re).collect())
+    }
+
+    // || |F x| - m || / ||m|| for a real estimate x.
+    pub fn fourier_error(&self, estimate: &[f32]) -> Result<f32> {
+        if estimate.len() != self.nrows * self.ncols {
+            return Err(Error::Dimension(self.nrows * self.ncols, estimate.len()));
+        }
+
+        let values: Vec<Complex32> = estimate.iter().map(|&x| Complex32::new(x, 0.0)).collect();
+        let error: f32 = fourier_magnitudes(&values, self.nrows, self.ncols)
+            .iter()
+            .zip(&self.magnitudes)
+            .map(|(a, m)| (a - m).powi(2))
+            .sum();
+        let norm: f32 = self.magnitudes.iter().map(|m| m * m).sum();
+        Ok((error / norm).sqrt())
+    }
+}
+
+// ||estimate - truth|| / ||truth||, minimised over the ambiguities the
+// magnitudes cannot resolve: cyclic translations and the twin image
+// x(-r, -c).
+pub fn reconstruction_error(
+    estimate: &[f32],
+    truth: &[f32],
+    nrows: usize,
+    ncols: usize,
+) -> Result<f32> {
+    if truth.len() != nrows * ncols {
+        return Err(Error::Dimension(nrows * ncols, truth.len()));
+    }
+    if estimate.len() != truth.len() {
+        return Err(Error::Dimension(truth.len(), estimate.len()));
+    }
+
+    let mut best = f32::INFINITY;
+    for twin in [false, true] {
+        for dr in 0..nrows {
+            for dc in 0..ncols {
+                let mut error = 0.0;
+                for r in 0..nrows {
+                    for c in 0..ncols {
+                        let (sr, sc) = match twin {
+                            false => ((r + dr) % nrows, (c + dc) % ncols),
+                            true => ((2 * nrows - r + dr) % nrows, (2 * ncols - c + dc) % ncols),
+                        };
+                        error += (estimate[sr * ncols + sc] - truth[r * ncols + c]).powi(2);
+                    }
+                }
+                best = best.min(error);
+            }
+        }
+    }
+
+    let norm: f32 = truth.iter().map(|t| t * t).sum();
+    Ok((best / norm).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{problems::rng::SplitMix64, Solver};
+
+    #[test]
+    fn test_reconstruction_error() {
+        let truth = vec![1.0, 2.0, 0.0, 3.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        // Shifted by one column, and the twin image.
+        let shifted = vec![0.0, 1.0, 2.0, 0.0, 3.0, 0.0, 0.0, 0.0, 0.0];
+        let twin = vec![1.0, 0.0, 2.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0];
+        assert!(reconstruction_error(&shifted, &truth, 3, 3).unwrap() < 1e-6);
+        assert!(reconstruction_error(&twin, &truth, 3, 3).unwrap() < 1e-6);
+        assert!(reconstruction_error(&[0.0; 9], &truth, 3, 3).unwrap() > 0.9);
+    }
+
+    #[test]
+    fn test_synthetic() {
+        let (problem, object) = PhaseRetrieval::synthetic(8, 8, 3, 3, 1);
+        assert_eq!(object.iter().filter(|&&x| x > 0.0).count(), 9);
+        assert_eq!(problem.support().iter().filter(|&&x| x).count(), 9);
+        assert!(problem.fourier_error(&object).unwrap() < 1e-6);
+    }
+
+    #[test]
+    fn test_solve() {
+        let (problem, object) = PhaseRetrieval::synthetic(16, 16, 6, 6, 3);
+        let mut rng = SplitMix64::new(5);
+        let values: Vec<f32> = (0..256).map(|_| rng.uniform()).collect();
+
+        let state = problem.initial_state(&values).unwrap();
+        let (state, _, _) = problem.solver(0.9, 1e-5, 20000).run(state).unwrap();
+        let estimate = problem.decode(&state).unwrap();
+        assert!(problem.fourier_error(&estimate).unwrap() < 1e-3);
+        assert!(reconstruction_error(&estimate, &object, 16, 16).unwrap() < 1e-2);
+    }
+}
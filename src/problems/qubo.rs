@@ -0,0 +1,325 @@
+use crate::{
+    errors::Error,
+    norms::euclidean_contiguous,
+    problems::rng::SplitMix64,
+    projectors::box_constraints::BoxProjector,
+    solvers::{
+        divide_and_concur::{step_with_solution, DivideAndConcurSolver},
+        workspace::StepWorkspace,
+    },
+    states::vector::VecState,
+    Projector, Result,
+};
+
+const STAGNATION: f32 = 1e-4;
+
+pub type IsingSolver =
+    DivideAndConcurSolver<VecState, EnergyProjector, BoxProjector, fn(&VecState, &VecState) -> f32>;
+
+// An Ising spin glass E(s) = s^T J s + h . s + offset over spins s in {-1, 1}^n.
+// The couplings are kept symmetric with a zero diagonal; the diagonal of the
+// input only shifts the offset, since s_i^2 = 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ising {
+    n: usize,
+    couplings: Vec<f32>,
+    fields: Vec<f32>,
+    offset: f32,
+}
+
+impl Ising {
+    // `couplings` is a row-major n x n matrix.
+    pub fn new(n: usize, couplings: &[f32]) -> Self {
+        if couplings.len() != n * n {
+            panic!(
+                "invalid couplings: expected {} values for an {n}x{n} matrix, got {}",
+                n * n,
+                couplings.len()
+            );
+        }
+
+        let mut symmetric = vec![0f32; n * n];
+        let mut offset = 0.0;
+        for i in 0..n {
+            offset += couplings[i * n + i];
+            for j in (0..n).filter(|&j| j != i) {
+                symmetric[i * n + j] = 0.5 * (couplings[i * n + j] + couplings[j * n + i]);
+            }
+        }
+
+        Self {
+            n,
+            couplings: symmetric,
+            fields: vec![0.0; n],
+            offset,
+        }
+    }
+
+    pub fn with_fields(mut self, fields: Vec<f32>) -> Self {
+        if fields.len() != self.n {
+            panic!(
+                "invalid fields: expected {} values, got {}",
+                self.n,
+                fields.len()
+            );
+        }
+
+        self.fields = fields;
+        self
+    }
+
+    // The spin glass with the same energies as x^T Q x over x in {0, 1}^n,
+    // under x = (1 + s) / 2.
+    pub fn from_qubo(n: usize, q: &[f32]) -> Self {
+        let scaled: Vec<f32> = q.iter().map(|x| 0.25 * x).collect();
+        let mut ising = Self::new(n, &scaled);
+
+        let fields = (0..n)
+            .map(|i| (0..n).map(|j| scaled[i * n + j] + scaled[j * n + i]).sum())
+            .collect();
+        ising.offset += scaled.iter().sum::<f32>();
+        ising.with_fields(fields)
+    }
+
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    // Entries are rounded to the nearest spin.
+    pub fn energy(&self, spins: &[f32]) -> f32 {
+        let s = signs(spins);
+        let mut energy = self.offset;
+        for i in 0..self.n {
+            energy += self.fields[i] * s[i] + s[i] * self.local_field(&s, i);
+        }
+        energy
+    }
+
+    // The change in energy from flipping spin i.
+    fn flip_cost(&self, s: &[f32], i: usize) -> f32 {
+        -2.0 * s[i] * (2.0 * self.local_field(s, i) + self.fields[i])
+    }
+
+    fn local_field(&self, s: &[f32], i: usize) -> f32 {
+        self.couplings[i * self.n..(i + 1) * self.n]
+            .iter()
+            .zip(s)
+            .map(|(j, s)| j * s)
+            .sum()
+    }
+
+    // Random starting values in [-1, 1].
+    pub fn initial_state(&self, values: &[f32]) -> Result<VecState> {
+        if values.len() != self.n {
+            return Err(Error::Dimension(self.n, values.len()));
+        }
+
+        Ok(VecState(values.to_vec()))
+    }
+
+    // Divide rounds to a spin configuration and descends in energy, concur is the
+    // hypercube [-1, 1]^n. Fixed points are local minima, so prefer `search`,
+    // which keeps the lowest energy seen along the way.
+    pub fn solver(&self, target: f32, beta: f32, epsilon: f32, n_steps: usize) -> IsingSolver {
+        DivideAndConcurSolver::new(
+            EnergyProjector::new(self.clone(), target),
+            BoxProjector::new(-1.0, 1.0),
+            euclidean_contiguous as fn(&VecState, &VecState) -> f32,
+            beta,
+            epsilon,
+            n_steps,
+        )
+    }
+
+    // Iterates for `n_steps`, or until a configuration at or below `target` is
+    // found, and returns the lowest-energy configuration seen. Whenever the
+    // iterate settles, it is perturbed and the search carries on.
+    pub fn search(
+        &self,
+        state: VecState,
+        target: f32,
+        beta: f32,
+        n_steps: usize,
+    ) -> Result<Ground> {
+        let divide = EnergyProjector::new(self.clone(), target);
+        let concur = BoxProjector::new(-1.0, 1.0);
+        let mut workspace = StepWorkspace::new();
+        let mut rng = SplitMix64::new(n_steps as u64);
+
+        let mut state = state;
+        let mut best = Ground {
+            spins: vec![],
+            energy: f32::INFINITY,
+            step: 0,
+        };
+        for t in 0..n_steps {
+            let (next, candidate) =
+                step_with_solution(state.clone(), &divide, &concur, beta, &mut workspace)?;
+            let energy = self.energy(&candidate);
+            if energy < best.energy {
+                best = Ground {
+                    spins: signs(&candidate),
+                    energy,
+                    step: t,
+                };
+            }
+            if best.energy <= target {
+                break;
+            }
+            workspace.recycle(candidate);
+
+            state = match euclidean_contiguous(&next, &state) < STAGNATION {
+                // Stuck on a local minimum above target: kick the state.
+                true => {
+                    #[cfg(feature = "metrics")]
+                    crate::metrics::restart("ising");
+                    VecState(next.iter().map(|x| x + 2.0 * rng.uniform() - 1.0).collect())
+                }
+                false => next,
+            };
+        }
+
+        Ok(best)
+    }
+}
+
+// The best configuration found by `Ising::search`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ground {
+    pub spins: Vec<f32>,
+    pub energy: f32,
+    pub step: usize,
+}
+
+impl Ground {
+    // The QUBO assignment x = (1 + s) / 2.
+    pub fn bits(&self) -> Vec<bool> {
+        self.spins.iter().map(|&s| s > 0.0).collect()
+    }
+}
+
+// Rounds to the nearest spin configuration, then greedily flips the spin with
+// the best energy decrease per unit of added distance until the energy reaches
+// `target` or no flip lowers it.
+#[derive(Debug, Clone)]
+pub struct EnergyProjector {
+    model: Ising,
+    target: f32,
+}
+
+impl EnergyProjector {
+    pub fn new(model: Ising, target: f32) -> Self {
+        Self { model, target }
+    }
+}
+
+impl<S> Projector<S> for EnergyProjector
+where
+    S: AsMut<[f32]>,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        if values.len() != self.model.n {
+            return Err(Error::Dimension(self.model.n, values.len()));
+        }
+
+        let mut s = signs(values);
+        let mut energy = self.model.energy(&s);
+        while energy > self.target {
+            let flip = (0..s.len())
+                .map(|i| (i, self.model.flip_cost(&s, i)))
+                .filter(|&(_, cost)| cost < 0.0)
+                .min_by(|(i, a), (j, b)| {
+                    // Flipping against x costs 4|x_i| in squared distance, and
+                    // flipping back toward it refunds the same.
+                    let da = 4.0 * values[*i] * s[*i] + 1e-6;
+                    let db = 4.0 * values[*j] * s[*j] + 1e-6;
+                    (da / -a).total_cmp(&(db / -b))
+                });
+            let Some((i, cost)) = flip else {
+                break;
+            };
+            s[i] = -s[i];
+            energy += cost;
+        }
+
+        values.copy_from_slice(&s);
+        Ok(state)
+    }
+}
+
+fn signs(values: &[f32]) -> Vec<f32> {
+    values
+        .iter()
+        .map(|&x| if x >= 0.0 { 1.0 } else { -1.0 })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute_force(ising: &Ising) -> f32 {
+        (0..1u32 << ising.n())
+            .map(|bits| {
+                let spins: Vec<f32> = (0..ising.n())
+                    .map(|i| if bits >> i & 1 == 1 { 1.0 } else { -1.0 })
+                    .collect();
+                ising.energy(&spins)
+            })
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    fn random(n: usize, seed: u64) -> Vec<f32> {
+        let mut rng = SplitMix64::new(seed);
+        (0..n).map(|_| 2.0 * rng.uniform() - 1.0).collect()
+    }
+
+    #[test]
+    fn test_energy() {
+        // Antiferromagnetic pair: aligned spins cost, opposite spins gain.
+        let ising = Ising::new(2, &[0.0, 1.0, 1.0, 0.0]);
+        assert_eq!(ising.energy(&[1.0, 1.0]), 2.0);
+        assert_eq!(ising.energy(&[1.0, -1.0]), -2.0);
+
+        let ising = ising.with_fields(vec![0.5, 0.0]);
+        assert_eq!(ising.energy(&[-1.0, 1.0]), -2.5);
+    }
+
+    #[test]
+    fn test_from_qubo() {
+        let q = [1.0, -2.0, 0.5, 0.0, -1.0, 3.0, 0.0, 0.0, 2.0];
+        let ising = Ising::from_qubo(3, &q);
+        for bits in 0..8u32 {
+            let x: Vec<f32> = (0..3).map(|i| (bits >> i & 1) as f32).collect();
+            let expected: f32 = (0..9).map(|k| q[k] * x[k / 3] * x[k % 3]).sum();
+            let spins: Vec<f32> = x.iter().map(|x| 2.0 * x - 1.0).collect();
+            assert!((ising.energy(&spins) - expected).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_energy_projector() {
+        let ising = Ising::new(2, &[0.0, 1.0, 1.0, 0.0]);
+        let projector = EnergyProjector::new(ising.clone(), f32::NEG_INFINITY);
+        // The weaker coordinate is the cheaper one to flip.
+        let output = projector.project(vec![0.9, 0.1]).unwrap();
+        assert_eq!(output, vec![1.0, -1.0]);
+
+        let projector = EnergyProjector::new(ising, f32::INFINITY);
+        assert_eq!(projector.project(vec![0.9, 0.1]).unwrap(), vec![1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_search() {
+        let n = 12;
+        let ising = Ising::new(n, &random(n * n, 11)).with_fields(random(n, 5));
+        let ground = brute_force(&ising);
+
+        let state = ising.initial_state(&random(n, 3)).unwrap();
+        let best = ising.search(state, ground + 1e-4, 0.5, 5000).unwrap();
+        assert!(best.energy <= ground + 1e-4);
+        assert!((ising.energy(&best.spins) - best.energy).abs() < 1e-4);
+        assert_eq!(best.bits().len(), n);
+    }
+}
@@ -0,0 +1,551 @@
+use crate::{
+    display::{Html, CELL_STYLE},
+    errors::Error,
+    problem::{ConstraintProblem, ProblemSolver, ProblemState, Variable},
+    projectors::binary::BinaryProjector,
+    solvers::{divide_and_concur::step_with_solution, workspace::StepWorkspace},
+    Projector, Result,
+};
+use std::{
+    fmt::{self, Write},
+    ops::Deref,
+    str::FromStr,
+    sync::Arc,
+};
+
+// Most variables `Cnf::parse` accepts: literals are `i32`, so no clause can
+// name a variable beyond this.
+pub const MAX_VARIABLES: usize = i32::MAX as usize;
+
+// A formula in conjunctive normal form, literals numbered from 1 as in DIMACS
+// (`-3` is the negation of variable 3).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cnf {
+    n_variables: usize,
+    clauses: Vec<Vec<i32>>,
+}
+
+impl Cnf {
+    pub fn new(n_variables: usize, clauses: Vec<Vec<i32>>) -> Self {
+        for clause in &clauses {
+            if clause.is_empty() {
+                panic!("invalid clause: expected at least one literal");
+            }
+            if let Some(&l) = clause
+                .iter()
+                .find(|&&l| l == 0 || l.unsigned_abs() as usize > n_variables)
+            {
+                panic!("invalid literal: expected 1..={n_variables} or its negation, got {l}");
+            }
+        }
+
+        Self {
+            n_variables,
+            clauses,
+        }
+    }
+
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut header: Option<(usize, usize)> = None;
+        let mut clauses = Vec::new();
+        let mut clause = Vec::new();
+        let mut line_number = 0;
+
+        for (i, line) in input.lines().enumerate() {
+            line_number = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('c') {
+                continue;
+            }
+            // SATLIB files end with a `%` line followed by junk.
+            if line.starts_with('%') {
+                break;
+            }
+
+            if line.starts_with('p') {
+                if header.is_some() {
+                    return Err(Error::Parse(line_number, "duplicate header".into()));
+                }
+                let (n_variables, n_clauses) = parse_header(line)
+                    .ok_or_else(|| Error::Parse(line_number, format!("invalid header `{line}`")))?;
+                // Each variable is a coordinate of every solve's state.
+                let fits = n_variables
+                    .checked_mul(std::mem::size_of::<f32>())
+                    .is_some_and(|bytes| bytes <= isize::MAX as usize);
+                if n_variables > MAX_VARIABLES || !fits {
+                    return Err(Error::Parse(
+                        line_number,
+                        format!("expected at most {MAX_VARIABLES} variables, got {n_variables}"),
+                    ));
+                }
+                header = Some((n_variables, n_clauses));
+                continue;
+            }
+
+            let Some((n_variables, _)) = header else {
+                return Err(Error::Parse(
+                    line_number,
+                    "expected `p cnf` header before clauses".into(),
+                ));
+            };
+
+            for token in line.split_whitespace() {
+                let literal: i32 = token
+                    .parse()
+                    .map_err(|_| Error::Parse(line_number, format!("invalid literal `{token}`")))?;
+
+                if literal == 0 {
+                    if clause.is_empty() {
+                        return Err(Error::Parse(line_number, "empty clause".into()));
+                    }
+                    clauses.push(std::mem::take(&mut clause));
+                } else if literal.unsigned_abs() as usize > n_variables {
+                    return Err(Error::Parse(
+                        line_number,
+                        format!("literal {literal} out of range for {n_variables} variables"),
+                    ));
+                } else {
+                    clause.push(literal);
+                }
+            }
+        }
+
+        // Tolerate a missing terminator on the last clause.
+        if !clause.is_empty() {
+            clauses.push(clause);
+        }
+
+        let Some((n_variables, n_clauses)) = header else {
+            return Err(Error::Parse(line_number, "missing `p cnf` header".into()));
+        };
+        if clauses.len() != n_clauses {
+            return Err(Error::Parse(
+                line_number,
+                format!("expected {n_clauses} clauses, got {}", clauses.len()),
+            ));
+        }
+
+        Ok(Self {
+            n_variables,
+            clauses,
+        })
+    }
+
+    pub fn n_variables(&self) -> usize {
+        self.n_variables
+    }
+
+    pub fn clauses(&self) -> &[Vec<i32>] {
+        &self.clauses
+    }
+
+    pub fn unsatisfied(&self, assignment: &[bool]) -> usize {
+        self.clauses
+            .iter()
+            .filter(|clause| !satisfies(assignment, clause))
+            .count()
+    }
+
+    pub fn is_satisfied(&self, assignment: &[bool]) -> bool {
+        self.unsatisfied(assignment) == 0
+    }
+}
+
+impl FromStr for Cnf {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+fn satisfies(assignment: &[bool], clause: &[i32]) -> bool {
+    clause
+        .iter()
+        .any(|&l| assignment[l.unsigned_abs() as usize - 1] == (l > 0))
+}
+
+fn parse_header(line: &str) -> Option<(usize, usize)> {
+    let mut tokens = line.split_whitespace();
+    if tokens.next()? != "p" || tokens.next()? != "cnf" {
+        return None;
+    }
+
+    let n_variables = tokens.next()?.parse().ok()?;
+    let n_clauses = tokens.next()?.parse().ok()?;
+    tokens.next().is_none().then_some((n_variables, n_clauses))
+}
+
+// Projects one clause's literal values (+1 true, -1 false) onto "at least one
+// literal true". Repeated variables are rounded together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClauseProjector {
+    groups: Vec<usize>,
+    negated: Vec<bool>,
+}
+
+impl ClauseProjector {
+    pub fn new(literals: &[i32]) -> Self {
+        let mut variables: Vec<u32> = Vec::new();
+        let groups = literals
+            .iter()
+            .map(|l| {
+                let v = l.unsigned_abs();
+                variables.iter().position(|&u| u == v).unwrap_or_else(|| {
+                    variables.push(v);
+                    variables.len() - 1
+                })
+            })
+            .collect();
+
+        Self {
+            groups,
+            negated: literals.iter().map(|&l| l < 0).collect(),
+        }
+    }
+}
+
+impl<S> Projector<S> for ClauseProjector
+where
+    S: AsMut<[f32]>,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        if values.len() != self.negated.len() {
+            return Err(Error::Dimension(self.negated.len(), values.len()));
+        }
+
+        let flip = |values: &mut [f32]| {
+            for (x, &neg) in values.iter_mut().zip(&self.negated) {
+                if neg {
+                    *x = -*x;
+                }
+            }
+        };
+
+        flip(values);
+        BinaryProjector::signs()
+            .with_reserve()
+            .round(values, &self.groups)?;
+        flip(values);
+
+        Ok(state)
+    }
+}
+
+// A CNF formula lifted into one copy per clause, with variables encoded as
+// +1 (true) and -1 (false). Clauses may carry weights, which concur uses to
+// average the clause copies, so heavier clauses pull the consensus harder.
+#[derive(Debug)]
+pub struct SatProblem {
+    cnf: Arc<Cnf>,
+    weights: Vec<f32>,
+    problem: Arc<ConstraintProblem>,
+}
+
+impl SatProblem {
+    pub fn new(cnf: Cnf) -> Self {
+        let weights = vec![1.0; cnf.clauses.len()];
+        Self::weighted(cnf, weights)
+    }
+
+    // Soft constraints for MaxSAT-style instances: one positive weight per
+    // clause, in order.
+    pub fn weighted(cnf: Cnf, weights: Vec<f32>) -> Self {
+        if weights.len() != cnf.clauses.len() {
+            panic!(
+                "invalid weights: expected {} weights, got {}",
+                cnf.clauses.len(),
+                weights.len()
+            );
+        }
+
+        let mut problem = ConstraintProblem::new();
+        let variables = problem.variables(cnf.n_variables);
+        for (clause, &weight) in cnf.clauses.iter().zip(&weights) {
+            let vars: Vec<Variable> = clause
+                .iter()
+                .map(|&l| variables[l.unsigned_abs() as usize - 1])
+                .collect();
+            problem.constrain_weighted(&vars, ClauseProjector::new(clause), weight);
+        }
+
+        Self {
+            cnf: Arc::new(cnf),
+            weights,
+            problem: Arc::new(problem),
+        }
+    }
+
+    pub fn from_dimacs(input: &str) -> Result<Self> {
+        Cnf::parse(input).map(Self::new)
+    }
+
+    pub fn cnf(&self) -> &Cnf {
+        &self.cnf
+    }
+
+    pub fn weights(&self) -> &[f32] {
+        &self.weights
+    }
+
+    pub fn problem(&self) -> &ConstraintProblem {
+        &self.problem
+    }
+
+    pub fn initial_state(&self, values: &[f32]) -> Result<ProblemState> {
+        self.problem.initial_state(values)
+    }
+
+    // Only accepts a fixed point whose decoded assignment satisfies every clause.
+    pub fn solver(&self, beta: f32, epsilon: f32, n_steps: usize) -> ProblemSolver<'_> {
+        let (cnf, problem) = (Arc::clone(&self.cnf), Arc::clone(&self.problem));
+        self.problem
+            .solver(beta, epsilon, n_steps)
+            .with_feasibility(move |state: &ProblemState| match problem.decode(state) {
+                Ok(values) => cnf.unsatisfied(&signs(&values)) as f32,
+                Err(_) => f32::INFINITY,
+            })
+    }
+
+    // Over-constrained instances have no fixed point to converge to, so instead
+    // iterate for `n_steps` and keep the assignment with the least total weight
+    // of unsatisfied clauses, stopping early if one satisfies every clause.
+    pub fn search(&self, state: ProblemState, beta: f32, n_steps: usize) -> Result<Incumbent> {
+        let (divide, concur) = (self.problem.divide(), self.problem.concur());
+        let mut workspace = StepWorkspace::new();
+
+        let mut state = state;
+        let mut best = Incumbent {
+            assignment: vec![],
+            violation: f32::INFINITY,
+            step: 0,
+        };
+        for t in 0..n_steps {
+            let (next, projected) =
+                step_with_solution(state, &divide, &concur, beta, &mut workspace)?;
+            let candidate = self.decode(&projected)?;
+            workspace.recycle(projected);
+            let violation = self.violation(&candidate);
+            if violation < best.violation {
+                best = Incumbent {
+                    assignment: candidate,
+                    violation,
+                    step: t,
+                };
+            }
+            if best.violation == 0.0 {
+                break;
+            }
+
+            state = next;
+        }
+
+        Ok(best)
+    }
+
+    pub fn decode(&self, state: &ProblemState) -> Result<Vec<bool>> {
+        Ok(signs(&self.problem.decode(state)?))
+    }
+
+    // Total weight of the clauses the assignment leaves unsatisfied.
+    pub fn violation(&self, assignment: &[bool]) -> f32 {
+        self.cnf
+            .clauses
+            .iter()
+            .zip(self.weights.iter())
+            .filter(|(clause, _)| !satisfies(assignment, clause))
+            .map(|(_, w)| w)
+            .sum()
+    }
+}
+
+// The best assignment found by `SatProblem::search`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Incumbent {
+    pub assignment: Vec<bool>,
+    pub violation: f32,
+    pub step: usize,
+}
+
+// A decoded assignment, indexed from variable 1 at position 0. Displays as
+// DIMACS solution lines: `v` and up to ten literals per line, then `0`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assignment(pub Vec<bool>);
+
+impl From<Vec<bool>> for Assignment {
+    fn from(values: Vec<bool>) -> Self {
+        Self(values)
+    }
+}
+
+impl Deref for Assignment {
+    type Target = [bool];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for Assignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, chunk) in self.0.chunks(10).enumerate() {
+            write!(f, "v")?;
+            for (j, &x) in chunk.iter().enumerate() {
+                let var = (10 * i + j + 1) as i64;
+                write!(f, " {}", if x { var } else { -var })?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f, "v 0")
+    }
+}
+
+// One column per variable, true in green and false in red.
+impl Html for Assignment {
+    fn to_html(&self) -> String {
+        let mut header = String::new();
+        let mut values = String::new();
+        for (i, &x) in self.0.iter().enumerate() {
+            let _ = write!(header, "<th style=\"{CELL_STYLE}\">x{}</th>", i + 1);
+            let (color, text) = if x {
+                ("#2e7d32", "T")
+            } else {
+                ("#c62828", "F")
+            };
+            let _ = write!(
+                values,
+                "<td style=\"{CELL_STYLE};color:{color}\">{text}</td>"
+            );
+        }
+        format!("<table><tr>{header}</tr><tr>{values}</tr></table>")
+    }
+}
+
+impl Assignment {
+    pub fn evcxr_display(&self) {
+        crate::display::evcxr_display(self);
+    }
+}
+
+fn signs(values: &[f32]) -> Vec<bool> {
+    values.iter().map(|&x| x >= 0.0).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{states::vector::VecState, Solver};
+
+    const INSTANCE: &str = "c example
+p cnf 2 3
+1 1 2 0
+-1 -2 -2 0
+-1 2
+2 0
+";
+
+    #[test]
+    fn test_parse() {
+        let cnf: Cnf = INSTANCE.parse().unwrap();
+        assert_eq!(cnf.n_variables(), 2);
+        assert_eq!(
+            cnf.clauses(),
+            &[vec![1, 1, 2], vec![-1, -2, -2], vec![-1, 2, 2]]
+        );
+        assert!(cnf.is_satisfied(&[false, true]));
+        assert_eq!(cnf.unsatisfied(&[true, true]), 1);
+
+        for (input, line) in [
+            ("1 2 0\n", 1),
+            ("p cnf 2 1\n1 3 0\n", 2),
+            ("p cnf 2 1\n1 x 0\n", 2),
+            ("p cnf 2 2\n1 2 0\n", 2),
+            ("p sat 2 1\n", 1),
+            ("p cnf 4294967296 1\n1 0\n", 1),
+            ("p cnf 18446744073709551615 0\n", 1),
+        ] {
+            match Cnf::parse(input) {
+                Err(Error::Parse(l, _)) => assert_eq!(l, line, "{input:?}"),
+                other => panic!("expected parse error, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_clause_projector() {
+        let clauses = [[1, 1, 2], [-1, -2, -2], [-1, 2, 2]];
+        for (vars, expected) in [
+            (
+                [-0.2, 0.7],
+                [[-1.0, -1.0, 1.0], [-1.0, 1.0, 1.0], [-1.0, 1.0, 1.0]],
+            ),
+            (
+                [-0.2, -0.7],
+                [[1.0, 1.0, -1.0], [-1.0, -1.0, -1.0], [-1.0, -1.0, -1.0]],
+            ),
+            (
+                [0.2, 0.7],
+                [[1.0, 1.0, 1.0], [-1.0, 1.0, 1.0], [1.0, 1.0, 1.0]],
+            ),
+            (
+                [0.2, -0.7],
+                [[1.0, 1.0, -1.0], [1.0, -1.0, -1.0], [-1.0, -1.0, -1.0]],
+            ),
+        ] {
+            for (clause, expected) in clauses.iter().zip(expected) {
+                let values: Vec<f32> = clause
+                    .iter()
+                    .map(|&l: &i32| vars[l.unsigned_abs() as usize - 1])
+                    .collect();
+                let output = ClauseProjector::new(clause)
+                    .project(VecState(values))
+                    .unwrap();
+                assert_eq!(output, VecState(expected.to_vec()), "{clause:?} {vars:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let problem = SatProblem::from_dimacs(INSTANCE).unwrap();
+        let state = problem.initial_state(&[0.3, -0.4]).unwrap();
+        let (state, _, _) = problem.solver(1.0, 0.1, 1000).run(state).unwrap();
+        assert_eq!(problem.decode(&state).unwrap(), vec![false, true]);
+    }
+
+    #[test]
+    fn test_search_weighted() {
+        // x1 and x3 are each pulled both ways; the heavier clause should win.
+        let cnf = Cnf::new(
+            3,
+            vec![vec![1], vec![-1], vec![2], vec![-2, 1], vec![3], vec![-3]],
+        );
+        let problem = SatProblem::weighted(cnf, vec![3.0, 1.0, 1.0, 2.0, 1.0, 5.0]);
+        assert_eq!(problem.violation(&[true, true, true]), 6.0);
+
+        let state = problem.initial_state(&[-0.5, 0.1, 0.4]).unwrap();
+        let best = problem.search(state, 0.5, 500).unwrap();
+        assert_eq!(best.assignment, vec![true, true, false]);
+        assert_eq!(best.violation, 2.0);
+    }
+
+    #[test]
+    fn test_assignment_display() {
+        let assignment = Assignment::from((0..12).map(|i| i % 3 != 1).collect::<Vec<_>>());
+        assert_eq!(
+            assignment.to_string(),
+            "v 1 -2 3 4 -5 6 7 -8 9 10\nv -11 12\nv 0\n"
+        );
+
+        let html = assignment.to_html();
+        assert_eq!(html.matches("<th").count(), 12);
+        assert_eq!(html.matches(">F</td>").count(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid weights: expected 3 weights, got 2")]
+    fn test_weights_length() {
+        SatProblem::weighted(INSTANCE.parse().unwrap(), vec![1.0, 2.0]);
+    }
+}
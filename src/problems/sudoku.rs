@@ -0,0 +1,805 @@
+use crate::{
+    display::{Html, CELL_STYLE},
+    errors::Error,
+    projectors::permutation::PermutationProjector,
+    solvers::divide_and_concur::DivideAndConcurSolver,
+    states::constraint_matrix::ConstraintMatrixState,
+    LinearCombine, Projector, Result, State,
+};
+use std::{
+    fmt::{self, Write},
+    ops::{Add, Mul},
+    str::FromStr,
+    sync::Arc,
+};
+
+// Weight of a given digit in the divide step, large enough that the assignment
+// never moves it.
+const GIVEN_WEIGHT: f32 = 1000.0;
+
+// Row, column and block weights used when averaging the constraint copies.
+pub const CONSTRAINT_WEIGHTS: [f32; 3] = [1.0, 1.0, 1.0];
+
+pub type SudokuSolver = DivideAndConcurSolver<
+    SudokuState,
+    fn(SudokuState) -> Result<SudokuState>,
+    fn(SudokuState) -> Result<SudokuState>,
+    fn(&SudokuState, &SudokuState) -> f32,
+>;
+
+// An N x N puzzle with N a perfect square, cells in row-major order and 0 for
+// an empty cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sudoku {
+    n: usize,
+    cells: Vec<usize>,
+}
+
+impl Sudoku {
+    pub fn new(n: usize, cells: Vec<usize>) -> Self {
+        iroot(n, 2);
+        if cells.len() != n * n {
+            panic!(
+                "invalid puzzle: expected {} cells, got {}",
+                n * n,
+                cells.len()
+            );
+        }
+        if let Some(&v) = cells.iter().find(|&&v| v > n) {
+            panic!("invalid cell: expected 0..={n}, got {v}");
+        }
+
+        Self { n, cells }
+    }
+
+    // Accepts either one character per cell (`1`-`9`, then `A`-`Z` for 10 and
+    // up, with `.`, `_` or `0` for blanks) or whitespace-separated numbers.
+    // Grid decorations (`|`, `-`, `+`) are ignored.
+    pub fn parse(input: &str) -> Result<Self> {
+        let tokens: Vec<&str> = input
+            .split(|c: char| c.is_whitespace() || matches!(c, '|' | '-' | '+'))
+            .filter(|t| !t.is_empty())
+            .collect();
+
+        // Numbers only if there is one token per cell and every token is a
+        // cell value; otherwise each token is a line of characters, as in a
+        // 16 x 16 grid written as 16 lines of 16.
+        let n = (tokens.len() as f32).sqrt().round() as usize;
+        let numeric = tokens.len() >= 16
+            && is_grid_size(tokens.len())
+            && tokens.iter().all(|t| parse_token(t).is_ok_and(|v| v <= n));
+
+        let cells = if numeric {
+            tokens
+                .iter()
+                .map(|t| parse_token(t))
+                .collect::<Result<Vec<_>>>()?
+        } else {
+            tokens
+                .iter()
+                .flat_map(|t| t.chars())
+                .map(parse_char)
+                .collect::<Result<Vec<_>>>()?
+        };
+
+        if !is_grid_size(cells.len()) {
+            return Err(Error::Parse(
+                1,
+                format!(
+                    "expected N^2 cells for a perfect-square N, got {}",
+                    cells.len()
+                ),
+            ));
+        }
+
+        let n = (cells.len() as f32).sqrt().round() as usize;
+        if let Some(&v) = cells.iter().find(|&&v| v > n) {
+            return Err(Error::Parse(1, format!("cell value {v} exceeds {n}")));
+        }
+
+        Ok(Self { n, cells })
+    }
+
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    pub fn cells(&self) -> &[usize] {
+        &self.cells
+    }
+
+    // One-hot encoding, `fill` supplying the starting value of every coordinate.
+    pub fn initial_state(&self, mut fill: impl FnMut() -> f32) -> SudokuState {
+        let n = self.n;
+        let mut given = vec![0f32; n.pow(3)];
+        for (i, &val) in self.cells.iter().enumerate() {
+            if val != 0 {
+                given[n * i + val - 1] = GIVEN_WEIGHT;
+            }
+        }
+
+        let layout = layout(n);
+        let states = layout
+            .iter()
+            .map(|layout| {
+                let values: Vec<f32> = (0..n.pow(3)).map(|_| fill()).collect();
+                gather(&values, layout, n * n)
+            })
+            .collect();
+
+        SudokuState {
+            given: given.into(),
+            layout,
+            states,
+        }
+    }
+
+    pub fn solver(beta: f32, epsilon: f32, n_steps: usize) -> SudokuSolver {
+        DivideAndConcurSolver::new(
+            divide_projector as fn(SudokuState) -> Result<SudokuState>,
+            concur_projector as fn(SudokuState) -> Result<SudokuState>,
+            norm as fn(&SudokuState, &SudokuState) -> f32,
+            beta,
+            epsilon,
+            n_steps,
+        )
+        .with_feasibility(feasibility)
+    }
+}
+
+impl FromStr for Sudoku {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+fn is_grid_size(cells: usize) -> bool {
+    let n = (cells as f32).sqrt().round() as usize;
+    let root = (n as f32).sqrt().round() as usize;
+    n > 0 && n * n == cells && root * root == n
+}
+
+fn parse_token(token: &str) -> Result<usize> {
+    match token {
+        "." | "_" => Ok(0),
+        _ => token
+            .parse()
+            .map_err(|_| Error::Parse(1, format!("invalid cell `{token}`"))),
+    }
+}
+
+fn parse_char(c: char) -> Result<usize> {
+    match c {
+        '.' | '_' | '0' => Ok(0),
+        '1'..='9' => Ok(c as usize - '0' as usize),
+        'A'..='Z' => Ok(c as usize - 'A' as usize + 10),
+        'a'..='z' => Ok(c as usize - 'a' as usize + 10),
+        _ => Err(Error::Parse(1, format!("invalid cell `{c}`"))),
+    }
+}
+
+// Three copies of the N^3 one-hot cell/value assignment, one each for the row,
+// column and block constraints. Each copy keeps every constraint's N^2
+// coordinates in one block, so the divide assigns them in place; `layout` maps
+// a copy's coordinates back to cell-major order. The givens, cell-major, ride
+// along untouched by arithmetic.
+#[derive(Debug, PartialEq)]
+pub struct SudokuState {
+    given: Arc<[f32]>,
+    layout: Arc<[Vec<usize>]>,
+    states: Vec<ConstraintMatrixState>,
+}
+
+// Spelled out so `clone_from` reuses the existing copies' allocations.
+impl Clone for SudokuState {
+    fn clone(&self) -> Self {
+        Self {
+            given: self.given.clone(),
+            layout: self.layout.clone(),
+            states: self.states.clone(),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.given.clone_from(&source.given);
+        self.layout.clone_from(&source.layout);
+        self.states.clone_from(&source.states);
+    }
+}
+
+impl SudokuState {
+    pub fn n(&self) -> usize {
+        iroot(self.given.len(), 3)
+    }
+
+    pub fn states(&self) -> &[ConstraintMatrixState] {
+        &self.states
+    }
+
+    // The `k`th copy in cell-major order, as `initial_state` encodes givens.
+    pub fn cell_values(&self, k: usize) -> Vec<f32> {
+        let mut values = vec![0f32; self.given.len()];
+        for (&x, &i) in self.states[k].as_ref().iter().zip(&self.layout[k]) {
+            values[i] = x;
+        }
+        values
+    }
+
+    // Picks the largest value of each cell, averaged across the copies.
+    pub fn solution(&self) -> SudokuSolution {
+        let n = self.n();
+        let mut scores = vec![0f32; self.given.len()];
+        for (s, layout) in self.states.iter().zip(self.layout.iter()) {
+            for (&x, &i) in s.as_ref().iter().zip(layout) {
+                scores[i] += x;
+            }
+        }
+
+        let cells = scores
+            .chunks(n)
+            .map(|score| {
+                (0..n).fold(0, |best, v| if score[v] > score[best] { v } else { best }) + 1
+            })
+            .collect();
+
+        SudokuSolution { n, cells }
+    }
+}
+
+impl Add for SudokuState {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let states = self
+            .states
+            .into_iter()
+            .zip(rhs.states)
+            .map(|(l, r)| l.axpy(1.0, r))
+            .collect();
+        Self {
+            given: self.given,
+            layout: self.layout,
+            states,
+        }
+    }
+}
+
+impl Mul<f32> for SudokuState {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        let states = self.states.into_iter().map(|l| l.scale(rhs)).collect();
+        Self {
+            given: self.given,
+            layout: self.layout,
+            states,
+        }
+    }
+}
+
+impl State for SudokuState {
+    fn axpy_ref(self, alpha: f32, y: &Self) -> Self {
+        let states = self
+            .states
+            .into_iter()
+            .zip(&y.states)
+            .map(|(l, r)| l.axpy_ref(alpha, r))
+            .collect();
+        Self {
+            given: self.given,
+            layout: self.layout,
+            states,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SudokuSolution {
+    n: usize,
+    cells: Vec<usize>,
+}
+
+impl SudokuSolution {
+    pub fn n(&self) -> usize {
+        self.n
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> usize {
+        self.cells[row * self.n + col]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[usize]> + '_ {
+        self.cells.chunks(self.n)
+    }
+
+    pub fn cells(&self) -> &[usize] {
+        &self.cells
+    }
+
+    // Repeated values across rows, columns and blocks, plus contradicted givens.
+    pub fn violations(&self, puzzle: &Sudoku) -> usize {
+        let n = self.n;
+        let mut violations = 0;
+
+        for indices in [
+            get_row_indices(n),
+            get_column_indices(n),
+            get_block_indices(n),
+        ] {
+            for constraint in indices {
+                let mut seen = vec![false; n];
+                for cell in constraint.iter().step_by(n).map(|&i| i / n) {
+                    let val = self.cells[cell] - 1;
+                    if seen[val] {
+                        violations += 1;
+                    }
+                    seen[val] = true;
+                }
+            }
+        }
+
+        violations
+            + self
+                .cells
+                .iter()
+                .zip(&puzzle.cells)
+                .filter(|&(&v, &g)| g != 0 && g != v)
+                .count()
+    }
+}
+
+// Heavier rules between blocks, as in the `Display` form.
+impl Html for SudokuSolution {
+    fn to_html(&self) -> String {
+        let k = iroot(self.n, 2);
+        let rule = "2px solid #333";
+        let mut html = String::from("<table style=\"border-collapse:collapse\">");
+        for (r, row) in self.rows().enumerate() {
+            html.push_str("<tr>");
+            for (c, val) in row.iter().enumerate() {
+                let _ = write!(html, "<td style=\"{CELL_STYLE}");
+                if r % k == 0 {
+                    let _ = write!(html, ";border-top:{rule}");
+                }
+                if c % k == 0 {
+                    let _ = write!(html, ";border-left:{rule}");
+                }
+                let _ = write!(html, "\">{val}</td>");
+            }
+            html.push_str("</tr>");
+        }
+        html.push_str("</table>");
+        html
+    }
+}
+
+impl SudokuSolution {
+    pub fn evcxr_display(&self) {
+        crate::display::evcxr_display(self);
+    }
+}
+
+impl fmt::Display for SudokuSolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n = self.n;
+        let k = iroot(n, 2);
+        let width = n.to_string().len();
+        let block = (width + 1) * k;
+        let rule = (0..k)
+            .map(|i| {
+                "-".repeat(if i == 0 || i + 1 == k {
+                    block
+                } else {
+                    block + 1
+                })
+            })
+            .collect::<Vec<_>>()
+            .join("+");
+
+        for (r, row) in self.rows().enumerate() {
+            if r > 0 && r % k == 0 {
+                writeln!(f, "{rule}")?;
+            }
+            for (c, val) in row.iter().enumerate() {
+                if c > 0 && c % k == 0 {
+                    write!(f, "| ")?;
+                }
+                write!(f, "{val:>width$}")?;
+                if c + 1 < n {
+                    write!(f, " ")?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn divide_projector(mut state: SudokuState) -> Result<SudokuState> {
+    let permutation = PermutationProjector::new(state.n());
+
+    for (s, layout) in state.states.iter_mut().zip(state.layout.iter()) {
+        for (x, &i) in s.as_mut().iter_mut().zip(layout) {
+            *x += state.given[i];
+        }
+    }
+
+    // Every block is its own assignment, so they project independently.
+    #[cfg(feature = "rayon")]
+    if 3 * state.n() >= crate::parallel::block_threshold() {
+        use rayon::prelude::*;
+        let width = state.n().pow(2);
+        crate::parallel::install(|| {
+            state
+                .states
+                .par_iter_mut()
+                .flat_map(|s| s.as_mut().par_chunks_exact_mut(width))
+                .try_for_each(|block| permutation.project(block).map(drop))
+        })?;
+        return Ok(state);
+    }
+
+    for block in state.states.iter_mut().flat_map(|s| s.blocks_mut()) {
+        permutation.project(block)?;
+    }
+
+    Ok(state)
+}
+
+pub fn concur_projector(state: SudokuState) -> Result<SudokuState> {
+    weighted_concur(state, &CONSTRAINT_WEIGHTS)
+}
+
+fn weighted_concur(mut state: SudokuState, weights: &[f32]) -> Result<SudokuState> {
+    let c = state.states.len();
+    if weights.len() != c {
+        return Err(Error::Dimension(c, weights.len()));
+    }
+    let d: f32 = weights.iter().sum();
+
+    let mut mean = vec![0f32; state.given.len()];
+    for ((s, layout), w) in state.states.iter().zip(state.layout.iter()).zip(weights) {
+        for (&x, &i) in s.as_ref().iter().zip(layout) {
+            mean[i] += x * w / d;
+        }
+    }
+    for (s, layout) in state.states.iter_mut().zip(state.layout.iter()) {
+        for (x, &i) in s.as_mut().iter_mut().zip(layout) {
+            *x = mean[i];
+        }
+    }
+
+    Ok(state)
+}
+
+pub fn norm(current: &SudokuState, previous: &SudokuState) -> f32 {
+    let d = current.states.len() as f32;
+    let mut delta = 0f32;
+
+    for (curr, prev) in current.states.iter().zip(previous.states.iter()) {
+        let mut diff = 0f32;
+        for (c, p) in curr.as_ref().iter().zip(prev.as_ref()) {
+            diff += (c - p).powi(2);
+        }
+        delta += diff.sqrt() / d;
+    }
+
+    delta
+}
+
+pub fn feasibility(state: &SudokuState) -> f32 {
+    let n = state.n();
+    let givens = state
+        .given
+        .chunks(n)
+        .map(|cell| cell.iter().position(|&g| g > 0.0).map_or(0, |v| v + 1))
+        .collect();
+
+    state.solution().violations(&Sudoku { n, cells: givens }) as f32
+}
+
+pub fn iroot(n: usize, p: usize) -> usize {
+    let x = n as f32;
+    let root = x.powf(1f32 / p as f32).round() as usize;
+
+    if root.pow(p as u32) != n {
+        panic!("invalid puzzle size: expected perfect power of {p}, got {n}")
+    }
+
+    root
+}
+
+// Each copy's coordinates as cell-major indices: the row, column and block
+// constraints' indices, one constraint after another.
+fn layout(n: usize) -> Arc<[Vec<usize>]> {
+    [
+        get_row_indices(n),
+        get_column_indices(n),
+        get_block_indices(n),
+    ]
+    .into_iter()
+    .map(|indices| indices.concat())
+    .collect()
+}
+
+fn gather(values: &[f32], layout: &[usize], width: usize) -> ConstraintMatrixState {
+    ConstraintMatrixState::new(layout.iter().map(|&i| values[i]).collect(), width)
+}
+
+fn get_row_indices(n: usize) -> Vec<Vec<usize>> {
+    (0..n)
+        .map(|r| {
+            let start = r * n.pow(2);
+            let end = start + n.pow(2);
+            (start..end).collect()
+        })
+        .collect()
+}
+
+fn get_column_indices(n: usize) -> Vec<Vec<usize>> {
+    let mut constraints = Vec::with_capacity(n);
+
+    for col in 0..n {
+        let mut constraint = Vec::with_capacity(n.pow(2));
+        for row in 0..n {
+            let start = row * n.pow(2) + col * n;
+            let end = start + n;
+            constraint.extend(start..end);
+        }
+        constraints.push(constraint);
+    }
+
+    constraints
+}
+
+fn get_block_indices(n: usize) -> Vec<Vec<usize>> {
+    let mut constraints = Vec::with_capacity(n);
+    let nsqrt = iroot(n, 2);
+
+    for block in 0..n {
+        let (row, col) = (block / nsqrt, block % nsqrt);
+        let (row, col) = (row * nsqrt, col * nsqrt);
+        let mut constraint = Vec::with_capacity(n.pow(2));
+
+        for r in row..row + nsqrt {
+            for c in col..col + nsqrt {
+                let start = r * n.pow(2) + c * n;
+                let end = start + n;
+                constraint.extend(start..end);
+            }
+        }
+
+        constraints.push(constraint);
+    }
+
+    constraints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{problems::rng::SplitMix64, Solver};
+
+    // 1 2 | 3 4
+    // 3 4 | 1 2
+    // ----+----
+    // 2 3 | 4 1
+    // 4 1 | 2 3
+    const SOLVED: &str = "12343412 23414123";
+
+    fn one_hot(puzzle: &Sudoku) -> Vec<f32> {
+        let n = puzzle.n();
+        let mut values = vec![0f32; n.pow(3)];
+        for (i, &v) in puzzle.cells().iter().enumerate() {
+            values[i * n + v - 1] = 1.0;
+        }
+        values
+    }
+
+    #[test]
+    fn test_isort_successful() {
+        for truth in 2usize..16 {
+            assert_eq!(truth, iroot(truth.pow(2), 2));
+            assert_eq!(truth, iroot(truth.pow(3), 3));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid puzzle size: expected perfect power of 2, got 10")]
+    fn test_isort_failure() {
+        iroot(10, 2);
+    }
+
+    #[test]
+    fn test_parse() {
+        let puzzle: Sudoku = "1 2 | 3 4\n3 4 | 1 2\n----+----\n2 3 | 4 1\n4 1 | 2 3"
+            .parse()
+            .unwrap();
+        assert_eq!(puzzle, SOLVED.parse().unwrap());
+        assert_eq!(puzzle.n(), 4);
+
+        let puzzle = Sudoku::parse("..34 ..12 23.. 41..").unwrap();
+        assert_eq!(&puzzle.cells()[..4], &[0, 0, 3, 4]);
+
+        let cells = vec!["16"; 256].join(" ");
+        assert_eq!(Sudoku::parse(&cells).unwrap().n(), 16);
+        assert_eq!(Sudoku::parse(&"G".repeat(256)).unwrap().cells()[0], 16);
+
+        // 16 lines of 16 characters, without decorations.
+        let grid: String = (0..16)
+            .map(|r| {
+                (0..16)
+                    .map(|c| "123456789ABCDEFG".as_bytes()[(r * 4 + r / 4 + c) % 16] as char)
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect();
+        let puzzle = Sudoku::parse(&grid).unwrap();
+        assert_eq!(puzzle.n(), 16);
+        assert_eq!(&puzzle.cells()[..3], &[1, 2, 3]);
+        assert_eq!(puzzle.cells()[16 + 15], 4);
+
+        assert!(matches!(Sudoku::parse("123"), Err(Error::Parse(..))));
+        assert!(matches!(
+            Sudoku::parse("5234341223414123"),
+            Err(Error::Parse(..))
+        ));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_get_column_indices() {
+        let n = 4;
+        let indices = get_column_indices(n);
+        let truth = vec![
+            vec![ 0,  1,  2,  3, 16, 17, 18, 19, 32, 33, 34, 35, 48, 49, 50, 51],
+            vec![ 4,  5,  6,  7, 20, 21, 22, 23, 36, 37, 38, 39, 52, 53, 54, 55],
+            vec![ 8,  9, 10, 11, 24, 25, 26, 27, 40, 41, 42, 43, 56, 57, 58, 59],
+            vec![12, 13, 14, 15, 28, 29, 30, 31, 44, 45, 46, 47, 60, 61, 62, 63],
+        ];
+        assert_eq!(indices, truth);
+        assert_eq!(get_row_indices(n)[1], (16..32).collect::<Vec<_>>());
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_get_block_indices() {
+        let n = 4;
+        let indices = get_block_indices(n);
+        let truth = vec![
+            vec![ 0,  1,  2,  3,  4,  5,  6,  7, 16, 17, 18, 19, 20, 21, 22, 23],
+            vec![ 8,  9, 10, 11, 12, 13, 14, 15, 24, 25, 26, 27, 28, 29, 30, 31],
+            vec![32, 33, 34, 35, 36, 37, 38, 39, 48, 49, 50, 51, 52, 53, 54, 55],
+            vec![40, 41, 42, 43, 44, 45, 46, 47, 56, 57, 58, 59, 60, 61, 62, 63],
+        ];
+        assert_eq!(indices, truth);
+    }
+
+    #[test]
+    fn test_divide_projector() {
+        let solved: Sudoku = SOLVED.parse().unwrap();
+        let state = solved.initial_state(|| 0.0);
+        let output = divide_projector(state).unwrap();
+        for k in 0..3 {
+            assert_eq!(output.cell_values(k), one_hot(&solved));
+        }
+
+        // Blank the top-left and bottom-right blocks and blur their copies.
+        let puzzle: Sudoku = "..34..1223..41..".parse().unwrap();
+        let mut state = puzzle.initial_state(|| 0.1);
+        let target = one_hot(&solved);
+        for (s, layout) in state.states.iter_mut().zip(state.layout.iter()) {
+            for (x, &i) in s.as_mut().iter_mut().zip(layout) {
+                *x += 0.4 * target[i];
+            }
+        }
+        let output = divide_projector(state).unwrap();
+        for k in 0..3 {
+            assert_eq!(output.cell_values(k), one_hot(&solved));
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_divide() {
+        // 25 x 25, so its 75 blocks take the parallel path at the default
+        // threshold.
+        let (n, k) = (25, 5);
+        assert!(3 * n >= crate::parallel::block_threshold());
+        let cells = (0..n * n)
+            .map(|i| (k * (i / n % k) + i / n / k + i % n) % n + 1)
+            .collect();
+        let solved = Sudoku::new(n, cells);
+        let output = divide_projector(solved.initial_state(|| 0.0)).unwrap();
+        for k in 0..3 {
+            assert_eq!(output.cell_values(k), one_hot(&solved));
+        }
+    }
+
+    #[test]
+    fn test_feasibility() {
+        let solved: Sudoku = SOLVED.parse().unwrap();
+        let mut state = solved.initial_state(|| 0.0);
+        let cells = |values: &[f32]| layout(4).iter().map(|l| gather(values, l, 16)).collect();
+        state.states = cells(&one_hot(&solved));
+        assert_eq!(feasibility(&state), 0f32);
+
+        // Every cell set to 1: each row, column, and block repeats three times,
+        // and 12 of the 16 givens are contradicted.
+        let ones: Vec<f32> = (0..64)
+            .map(|i| if i % 4 == 0 { 1.0 } else { 0.0 })
+            .collect();
+        state.states = cells(&ones);
+        assert_eq!(feasibility(&state), 48f32);
+    }
+
+    #[test]
+    fn test_weighted_concur() {
+        // The second copy lists its two coordinates in reverse.
+        let state = SudokuState {
+            given: vec![0f32; 2].into(),
+            layout: vec![vec![0, 1], vec![1, 0], vec![0, 1]].into(),
+            states: vec![
+                ConstraintMatrixState::new(vec![4.0, 0.0], 2),
+                ConstraintMatrixState::new(vec![4.0, 0.0], 2),
+                ConstraintMatrixState::new(vec![0.0, 0.0], 2),
+            ],
+        };
+        let output = weighted_concur(state.clone(), &[2.0, 1.0, 1.0]).unwrap();
+        for k in 0..3 {
+            assert_eq!(output.cell_values(k), vec![2.0, 1.0]);
+        }
+        assert_eq!(output.states[1].as_ref(), &[1.0, 2.0]);
+
+        let ones = SudokuState {
+            given: vec![1f32; 64].into(),
+            layout: layout(4),
+            states: vec![ConstraintMatrixState::new(vec![1f32; 64], 16); 3],
+        };
+        assert_eq!(concur_projector(ones.clone()).unwrap(), ones);
+
+        let err = weighted_concur(state, &[1.0]).unwrap_err();
+        assert!(matches!(err, Error::Dimension(3, 1)));
+    }
+
+    #[test]
+    fn test_norm() {
+        let puzzle: Sudoku = SOLVED.parse().unwrap();
+        let ones = puzzle.initial_state(|| 1.0);
+        assert_eq!(norm(&ones, &ones), 0f32);
+        assert_eq!(norm(&ones, &puzzle.initial_state(|| 0.0)), 8f32);
+    }
+
+    #[test]
+    fn test_solve() {
+        let puzzle: Sudoku = "..34..1223..41..".parse().unwrap();
+        let mut rng = SplitMix64::new(1);
+        let state = puzzle.initial_state(|| rng.uniform());
+        let (state, _, _) = Sudoku::solver(0.9, 1.0, 10000).run(state).unwrap();
+        let solution = state.solution();
+        assert_eq!(solution.cells(), SOLVED.parse::<Sudoku>().unwrap().cells());
+        assert_eq!(solution.violations(&puzzle), 0);
+        assert_eq!(
+            solution.to_string(),
+            "1 2 | 3 4\n3 4 | 1 2\n----+----\n2 3 | 4 1\n4 1 | 2 3\n"
+        );
+    }
+
+    #[test]
+    fn test_html() {
+        let solution = SudokuSolution {
+            n: 4,
+            cells: SOLVED.parse::<Sudoku>().unwrap().cells,
+        };
+        let html = solution.to_html();
+        assert_eq!(html.matches("<tr>").count(), 4);
+        assert_eq!(html.matches("<td").count(), 16);
+        // Two block rows and two block columns, each four cells long.
+        assert_eq!(html.matches("border-top").count(), 8);
+        assert_eq!(html.matches("border-left").count(), 8);
+        assert!(html.contains(">4</td></tr>"));
+    }
+}
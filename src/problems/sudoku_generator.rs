@@ -0,0 +1,177 @@
+use crate::{
+    problems::{
+        rng::SplitMix64,
+        sudoku::{iroot, Sudoku, SudokuSolution},
+    },
+    Result, Solver,
+};
+
+// Builds puzzles by solving an empty grid, then blanking cells for as long as
+// repeated solver runs from random starts keep landing on that same grid.
+// Agreement across runs is evidence of a unique solution, not a proof, so more
+// attempts make for more trustworthy puzzles.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SudokuGenerator {
+    n: usize,
+    seed: u64,
+    min_clues: usize,
+    attempts: usize,
+    symmetric: bool,
+    beta: f32,
+    n_steps: usize,
+}
+
+// A generated puzzle with the grid it was carved from, and the mean number of
+// solver steps the verification runs needed on it, a rough difficulty score.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneratedSudoku {
+    pub puzzle: Sudoku,
+    pub solution: SudokuSolution,
+    pub mean_steps: f32,
+}
+
+impl SudokuGenerator {
+    pub fn new(n: usize) -> Self {
+        iroot(n, 2);
+        Self {
+            n,
+            seed: 0,
+            min_clues: 0,
+            attempts: 3,
+            symmetric: true,
+            beta: 0.9,
+            n_steps: 20000,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    // Stop removing once this few clues remain; higher is easier.
+    pub fn with_min_clues(mut self, min_clues: usize) -> Self {
+        self.min_clues = min_clues;
+        self
+    }
+
+    // Solver runs that must all reproduce the grid before a removal is kept.
+    pub fn with_attempts(mut self, attempts: usize) -> Self {
+        if attempts == 0 {
+            panic!("invalid attempts: expected at least 1, got {attempts}");
+        }
+        self.attempts = attempts;
+        self
+    }
+
+    // Remove cells in pairs mirrored through the centre, as printed puzzles do.
+    pub fn with_symmetry(mut self, symmetric: bool) -> Self {
+        self.symmetric = symmetric;
+        self
+    }
+
+    // Budget for each verification run; a smaller budget only keeps puzzles the
+    // solver finds easy.
+    pub fn with_solver(mut self, beta: f32, n_steps: usize) -> Self {
+        self.beta = beta;
+        self.n_steps = n_steps;
+        self
+    }
+
+    pub fn generate(&self) -> Result<GeneratedSudoku> {
+        let mut rng = SplitMix64::new(self.seed);
+        let size = self.n * self.n;
+
+        let empty = Sudoku::new(self.n, vec![0; size]);
+        let (solution, _) = self.solve(&empty, &mut rng)?;
+
+        let mut order: Vec<usize> = (0..size).collect();
+        for i in (1..size).rev() {
+            order.swap(i, (rng.next() % (i as u64 + 1)) as usize);
+        }
+
+        let mut cells = solution.cells().to_vec();
+        let mut mean_steps = 0.0;
+        for cell in order {
+            let mut removal = vec![cell];
+            if self.symmetric && size - 1 - cell != cell {
+                removal.push(size - 1 - cell);
+            }
+            let clues = cells.iter().filter(|&&v| v != 0).count();
+            let blanks = removal.iter().filter(|&&i| cells[i] != 0).count();
+            if blanks == 0 || clues - blanks < self.min_clues {
+                continue;
+            }
+
+            let mut candidate = cells.clone();
+            removal.iter().for_each(|&i| candidate[i] = 0);
+            if let Some(steps) = self.verify(&candidate, &solution, &mut rng) {
+                cells = candidate;
+                mean_steps = steps;
+            }
+        }
+
+        Ok(GeneratedSudoku {
+            puzzle: Sudoku::new(self.n, cells),
+            solution,
+            mean_steps,
+        })
+    }
+
+    fn solve(&self, puzzle: &Sudoku, rng: &mut SplitMix64) -> Result<(SudokuSolution, usize)> {
+        // The default beta is tuned to Elser's form, under which the reflection
+        // step can stall on an empty grid.
+        let solver = Sudoku::solver(self.beta, 1.0, self.n_steps).with_difference_map(true);
+        let (state, steps, _) = solver.run(puzzle.initial_state(|| rng.uniform()))?;
+        Ok((state.solution(), steps))
+    }
+
+    // The mean steps to solve, if every attempt lands on `solution`.
+    fn verify(
+        &self,
+        cells: &[usize],
+        solution: &SudokuSolution,
+        rng: &mut SplitMix64,
+    ) -> Option<f32> {
+        let puzzle = Sudoku::new(self.n, cells.to_vec());
+        let mut total = 0;
+        for _ in 0..self.attempts {
+            let (found, steps) = self.solve(&puzzle, rng).ok()?;
+            if found != *solution {
+                return None;
+            }
+            total += steps;
+        }
+        Some(total as f32 / self.attempts as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let generated = SudokuGenerator::new(4).with_seed(7).generate().unwrap();
+        let (puzzle, solution) = (&generated.puzzle, &generated.solution);
+        assert_eq!(solution.violations(puzzle), 0);
+        assert!(solution.cells().iter().all(|&v| (1..=4).contains(&v)));
+
+        let clues: Vec<usize> = puzzle.cells().iter().map(|&v| (v != 0) as usize).collect();
+        assert!(clues.iter().sum::<usize>() < 16);
+        // Blanks come in mirrored pairs.
+        assert!((0..16).all(|i| clues[i] == clues[15 - i]));
+    }
+
+    #[test]
+    fn test_min_clues() {
+        let generated = SudokuGenerator::new(4)
+            .with_seed(3)
+            .with_min_clues(10)
+            .with_symmetry(false)
+            .generate()
+            .unwrap();
+        let clues = generated.puzzle.cells().iter().filter(|&&v| v != 0).count();
+        assert!(clues >= 10);
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{
+    errors::Error,
+    linalg::{DefaultBackend, LinalgBackend},
+    Projector, Result, Scalar,
+};
+#[cfg(feature = "nalgebra")]
+use nalgebra::{DMatrix, DVector};
+
+// Projects onto { x : A x = b }, for A with full row rank, by factoring A Aᵀ
+// once in the backend `B`. A is stored column-major, in f32 or f64.
+#[derive(Debug, Clone)]
+pub struct AffineProjector<B = DefaultBackend, T = f32>
+where
+    B: LinalgBackend<T>,
+{
+    nrows: usize,
+    ncols: usize,
+    a: Vec<T>,
+    b: Vec<T>,
+    gram: B::Gram,
+}
+
+impl<T> AffineProjector<DefaultBackend, T>
+where
+    T: Scalar,
+    DefaultBackend: LinalgBackend<T>,
+{
+    #[cfg(feature = "nalgebra")]
+    pub fn new(a: DMatrix<T>, b: DVector<T>) -> Result<Self> {
+        Self::new_in(a, b, DefaultBackend::default())
+    }
+
+    pub fn from_column_major(nrows: usize, ncols: usize, a: Vec<T>, b: Vec<T>) -> Result<Self> {
+        Self::from_column_major_in(nrows, ncols, a, b, DefaultBackend::default())
+    }
+}
+
+impl<B, T> AffineProjector<B, T>
+where
+    B: LinalgBackend<T>,
+    T: Scalar,
+{
+    #[cfg(feature = "nalgebra")]
+    pub fn new_in(a: DMatrix<T>, b: DVector<T>, backend: B) -> Result<Self> {
+        let (nrows, ncols) = a.shape();
+        Self::from_column_major_in(
+            nrows,
+            ncols,
+            a.as_slice().to_vec(),
+            b.as_slice().to_vec(),
+            backend,
+        )
+    }
+
+    pub fn from_column_major_in(
+        nrows: usize,
+        ncols: usize,
+        a: Vec<T>,
+        b: Vec<T>,
+        _backend: B,
+    ) -> Result<Self> {
+        if nrows == 0 {
+            return Err(Error::Dimension(1, 0));
+        }
+        if a.len() != nrows * ncols {
+            return Err(Error::Dimension(nrows * ncols, a.len()));
+        }
+        if nrows != b.len() {
+            return Err(Error::Dimension(nrows, b.len()));
+        }
+
+        let gram = B::factor_gram(nrows, ncols, &a)?;
+        Ok(Self {
+            nrows,
+            ncols,
+            a,
+            b,
+            gram,
+        })
+    }
+
+    // A x - b
+    pub fn residual(&self, x: &[T]) -> Result<Vec<T>> {
+        if x.len() != self.ncols {
+            return Err(Error::Dimension(self.ncols, x.len()));
+        }
+
+        let mut residual: Vec<T> = self.b.iter().map(|&b| -b).collect();
+        for (column, &xj) in self.a.chunks_exact(self.nrows).zip(x) {
+            for (r, &a) in residual.iter_mut().zip(column) {
+                *r += a * xj;
+            }
+        }
+        Ok(residual)
+    }
+}
+
+impl<S, B, T> Projector<S> for AffineProjector<B, T>
+where
+    S: AsMut<[T]>,
+    B: LinalgBackend<T>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        let mut y = self.residual(values)?;
+        B::solve_gram(&self.gram, &mut y);
+
+        // x -= Aᵀ (A Aᵀ)⁻¹ (A x - b)
+        for (x, column) in values.iter_mut().zip(self.a.chunks_exact(self.nrows)) {
+            *x -= crate::projectors::dot(column, &y);
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "faer")]
+    use crate::linalg::Faer;
+
+    fn assert_close(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-5, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_affine_projector() {
+        // x + y + z = 3
+        let a = DMatrix::from_row_slice(1, 3, &[1.0, 1.0, 1.0]);
+        let b = DVector::from_column_slice(&[3.0]);
+        let projector = AffineProjector::new(a, b).unwrap();
+
+        let output = projector.project(vec![0.0, 0.0, 0.0]).unwrap();
+        assert_close(&output, &[1.0, 1.0, 1.0]);
+
+        let output = projector.project(vec![2.0, 0.0, 1.0]).unwrap();
+        assert_close(&output, &[2.0, 0.0, 1.0]);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_affine_projector_multiple_rows() {
+        // x = 1, y - z = 0
+        let a = DMatrix::from_row_slice(2, 3, &[1.0, 0.0, 0.0, 0.0, 1.0, -1.0]);
+        let b = DVector::from_column_slice(&[1.0, 0.0]);
+        let projector = AffineProjector::new(a, b).unwrap();
+
+        let output = projector.project(vec![5.0, 2.0, 4.0]).unwrap();
+        assert_close(&output, &[1.0, 3.0, 3.0]);
+        assert!(projector
+            .residual(&output)
+            .unwrap()
+            .iter()
+            .all(|r| r.abs() < 1e-6));
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_affine_projector_rank_deficient() {
+        let a = DMatrix::from_row_slice(2, 2, &[1.0, 0.0, 0.0, 0.0]);
+        let b = DVector::from_column_slice(&[1.0, 0.0]);
+        assert!(matches!(
+            AffineProjector::new(a, b),
+            Err(Error::Factorization(_))
+        ));
+    }
+
+    #[test]
+    fn test_affine_projector_f64() {
+        // x + y + z = 1, met to f64 accuracy.
+        let projector =
+            AffineProjector::from_column_major(1, 3, vec![1.0f64; 3], vec![1.0]).unwrap();
+        let output = projector.project(vec![0.1, 0.2, 0.3]).unwrap();
+        let sum: f64 = output.iter().sum();
+        assert!((sum - 1.0).abs() < 1e-12, "{output:?}");
+    }
+
+    #[test]
+    fn test_affine_projector_no_rows() {
+        assert!(matches!(
+            AffineProjector::from_column_major(0, 3, Vec::<f32>::new(), vec![]),
+            Err(Error::Dimension(1, 0))
+        ));
+    }
+
+    #[cfg(feature = "faer")]
+    #[test]
+    fn test_affine_projector_faer() {
+        // x = 1, y - z = 0, column-major.
+        let a = vec![1.0, 0.0, 0.0, 1.0, 0.0, -1.0];
+        let projector =
+            AffineProjector::from_column_major_in(2, 3, a, vec![1.0, 0.0], Faer).unwrap();
+
+        let output = projector.project(vec![5.0, 2.0, 4.0]).unwrap();
+        assert_close(&output, &[1.0, 3.0, 3.0]);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{Projector, Result};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tracing::{event, Level};
+
+#[derive(Debug)]
+pub struct ApproximateProjector<F, N> {
+    routine: F,
+    distance: N,
+    tolerance: f32,
+    max_iterations: usize,
+    calls: AtomicUsize,
+    iterations: AtomicUsize,
+}
+
+impl<F, N> ApproximateProjector<F, N> {
+    pub fn new(routine: F, distance: N, tolerance: f32, max_iterations: usize) -> Self {
+        Self {
+            routine,
+            distance,
+            tolerance,
+            max_iterations,
+            calls: AtomicUsize::new(0),
+            iterations: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn calls(&self) -> usize {
+        self.calls.load(Ordering::Relaxed)
+    }
+
+    pub fn total_iterations(&self) -> usize {
+        self.iterations.load(Ordering::Relaxed)
+    }
+}
+
+impl<S, F, N> Projector<S> for ApproximateProjector<F, N>
+where
+    S: Clone,
+    F: Fn(&S, S) -> Result<S>,
+    N: Fn(&S, &S) -> f32,
+{
+    fn project(&self, state: S) -> Result<S> {
+        let mut current = state.clone();
+        let mut iterations = 0;
+        let mut delta = f32::NAN;
+        let mut converged = false;
+
+        while iterations < self.max_iterations {
+            let next = (self.routine)(&state, current.clone())?;
+            delta = (self.distance)(&next, &current);
+            current = next;
+            iterations += 1;
+
+            if delta <= self.tolerance {
+                converged = true;
+                break;
+            }
+        }
+
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.iterations.fetch_add(iterations, Ordering::Relaxed);
+        event!(
+            Level::DEBUG,
+            inner_iterations = iterations,
+            inner_delta = delta
+        );
+        if !converged {
+            event!(
+                Level::WARN,
+                inner_iterations = iterations,
+                inner_delta = delta,
+                "approximate projection hit the iteration cap"
+            );
+        }
+
+        Ok(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projectors::{ball::L2BallProjector, halfspace::HalfspaceProjector};
+
+    #[allow(clippy::ptr_arg)]
+    fn distance(a: &Vec<f32>, b: &Vec<f32>) -> f32 {
+        a.iter()
+            .zip(b)
+            .map(|(x, y)| (x - y).powi(2))
+            .sum::<f32>()
+            .sqrt()
+    }
+
+    #[test]
+    fn test_approximate_projector() {
+        // Alternating projections onto the unit ball and x + y >= 1.
+        let ball = L2BallProjector::new(1.0);
+        let halfspace = HalfspaceProjector::new(vec![-1.0, -1.0], -1.0);
+        let routine = |_: &Vec<f32>, x: Vec<f32>| ball.project(halfspace.project(x)?);
+
+        let projector = ApproximateProjector::new(routine, distance, 1e-6, 100);
+        let output = projector.project(vec![2.0, 2.0]).unwrap();
+        assert!((output[0] - 0.5f32.sqrt()).abs() < 1e-5);
+        assert!((output[1] - 0.5f32.sqrt()).abs() < 1e-5);
+        assert_eq!(projector.calls(), 1);
+        assert!(projector.total_iterations() >= 1);
+
+        let output = projector.project(vec![-2.0, 0.0]).unwrap();
+        assert!(output[0] + output[1] >= 1.0 - 1e-3);
+        assert!(output[0].hypot(output[1]) <= 1.0 + 1e-5);
+        assert_eq!(projector.calls(), 2);
+    }
+
+    #[test]
+    fn test_approximate_projector_cap() {
+        let routine = |_: &Vec<f32>, x: Vec<f32>| Ok(x.into_iter().map(|v| v + 1.0).collect());
+        let projector = ApproximateProjector::new(routine, distance, 1e-6, 5);
+        let output = projector.project(vec![0.0]).unwrap();
+        assert_eq!(output, vec![5.0]);
+        assert_eq!(projector.total_iterations(), 5);
+    }
+}
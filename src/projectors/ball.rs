@@ -0,0 +1,128 @@
+use crate::{errors::Error, projectors::dot, Projector, Result, Scalar};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct L2BallProjector<T = f32> {
+    center: Option<Vec<T>>,
+    radius: T,
+}
+
+impl<T: Scalar> L2BallProjector<T> {
+    pub fn new(radius: T) -> Self {
+        if radius < T::ZERO {
+            panic!("invalid radius: expected non-negative value, got {radius}");
+        }
+
+        Self {
+            center: None,
+            radius,
+        }
+    }
+
+    pub fn centered(center: Vec<T>, radius: T) -> Self {
+        Self {
+            center: Some(center),
+            ..Self::new(radius)
+        }
+    }
+}
+
+impl<S, T> Projector<S> for L2BallProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        if let Some(center) = &self.center {
+            if center.len() != values.len() {
+                return Err(Error::Dimension(center.len(), values.len()));
+            }
+        }
+
+        let offset = |i: usize| self.center.as_ref().map_or(T::ZERO, |c| c[i]);
+        let norm = values
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| (x - offset(i)) * (x - offset(i)))
+            .sum::<T>()
+            .sqrt();
+
+        if norm > self.radius {
+            let scale = self.radius / norm;
+            for (i, x) in values.iter_mut().enumerate() {
+                let c = offset(i);
+                *x = c + (*x - c) * scale;
+            }
+        }
+
+        Ok(state)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SphereProjector<T = f32> {
+    radius: T,
+}
+
+impl<T: Scalar> SphereProjector<T> {
+    pub fn new(radius: T) -> Self {
+        if radius < T::ZERO {
+            panic!("invalid radius: expected non-negative value, got {radius}");
+        }
+
+        Self { radius }
+    }
+}
+
+impl<S, T> Projector<S> for SphereProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        let norm = dot(values, values).sqrt();
+
+        // Every point on the sphere is nearest to the origin, so pick the first axis.
+        if norm == T::ZERO {
+            if let Some(x) = values.first_mut() {
+                *x = self.radius;
+            }
+            return Ok(state);
+        }
+
+        let scale = self.radius / norm;
+        values.iter_mut().for_each(|x| *x *= scale);
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_l2_ball_projector() {
+        let projector = L2BallProjector::new(5.0);
+        let output = projector.project(vec![6.0, 8.0]).unwrap();
+        assert_eq!(output, vec![3.0, 4.0]);
+
+        let output = projector.project(vec![1.0, 2.0]).unwrap();
+        assert_eq!(output, vec![1.0, 2.0]);
+
+        let projector = L2BallProjector::centered(vec![1.0, 1.0], 5.0);
+        let output = projector.project(vec![7.0, 9.0]).unwrap();
+        assert_eq!(output, vec![4.0, 5.0]);
+    }
+
+    #[test]
+    fn test_sphere_projector() {
+        let projector = SphereProjector::new(10.0);
+        let output = projector.project(vec![3.0, 4.0]).unwrap();
+        assert_eq!(output, vec![6.0, 8.0]);
+
+        let output = projector.project(vec![0.0, 0.0]).unwrap();
+        assert_eq!(output, vec![10.0, 0.0]);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{errors::Error, Projector, Result, Scalar};
+use core::marker::PhantomData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Signs,
+    Bits,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinaryProjector<T = f32> {
+    encoding: Encoding,
+    reserve: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Scalar> BinaryProjector<T> {
+    pub fn signs() -> Self {
+        Self {
+            encoding: Encoding::Signs,
+            reserve: false,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn bits() -> Self {
+        Self {
+            encoding: Encoding::Bits,
+            reserve: false,
+            _marker: PhantomData,
+        }
+    }
+
+    // When every coordinate rounds to false, set the group with the largest summed
+    // value to true instead, i.e. project onto "at least one true" like a SAT clause.
+    pub fn with_reserve(mut self) -> Self {
+        self.reserve = true;
+        self
+    }
+
+    pub fn round(&self, values: &mut [T], groups: &[usize]) -> Result<()> {
+        if groups.len() != values.len() {
+            return Err(Error::Dimension(values.len(), groups.len()));
+        }
+
+        let (threshold, lo, hi) = match self.encoding {
+            Encoding::Signs => (T::ZERO, -T::ONE, T::ONE),
+            Encoding::Bits => (T::from_f32(0.5), T::ZERO, T::ONE),
+        };
+
+        let costs = self.reserve.then(|| {
+            let mut costs = vec![None; groups.iter().max().map_or(0, |&g| g + 1)];
+            for (&g, &x) in groups.iter().zip(values.iter()) {
+                *costs[g].get_or_insert(T::ZERO) += x - threshold;
+            }
+            costs
+        });
+
+        for x in values.iter_mut() {
+            *x = if *x < threshold { lo } else { hi };
+        }
+
+        if let Some(costs) = costs {
+            if values.iter().all(|&x| x == lo) {
+                let best = argmax(&costs);
+                for (x, &g) in values.iter_mut().zip(groups) {
+                    if Some(g) == best {
+                        *x = hi;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<S, T> Projector<S> for BinaryProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        let groups: Vec<usize> = (0..values.len()).collect();
+        self.round(values, &groups)?;
+        Ok(state)
+    }
+}
+
+fn argmax<T: Scalar>(costs: &[Option<T>]) -> Option<usize> {
+    let mut best: Option<(usize, T)> = None;
+    for (i, cost) in costs.iter().enumerate() {
+        if let Some(c) = *cost {
+            match best {
+                Some((_, v)) if v > c => {}
+                _ => best = Some((i, c)),
+            }
+        }
+    }
+    best.map(|(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binary_projector() {
+        let projector = BinaryProjector::signs();
+        let output = projector.project(vec![-0.3, 0.0, 2.0]).unwrap();
+        assert_eq!(output, vec![-1.0, 1.0, 1.0]);
+
+        let projector = BinaryProjector::bits();
+        let output = projector.project(vec![0.2, 0.5, 2.0]).unwrap();
+        assert_eq!(output, vec![0.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_binary_projector_reserve() {
+        let projector = BinaryProjector::signs().with_reserve();
+        let output = projector.project(vec![-0.3, -0.1, -2.0]).unwrap();
+        assert_eq!(output, vec![-1.0, 1.0, -1.0]);
+
+        let output = projector.project(vec![-0.3, 0.1, -2.0]).unwrap();
+        assert_eq!(output, vec![-1.0, 1.0, -1.0]);
+
+        let projector = BinaryProjector::bits().with_reserve();
+        let output = projector.project(vec![0.1, 0.4, 0.2]).unwrap();
+        assert_eq!(output, vec![0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_binary_projector_groups() {
+        // Group 0 sums to -0.4, group 1 to -0.35.
+        let projector = BinaryProjector::signs().with_reserve();
+        let mut values = vec![-0.2, -0.2, -0.35];
+        projector.round(&mut values, &[0, 0, 1]).unwrap();
+        assert_eq!(values, vec![-1.0, -1.0, 1.0]);
+
+        let mut values = vec![-0.2, -0.2, -0.5];
+        projector.round(&mut values, &[0, 0, 1]).unwrap();
+        assert_eq!(values, vec![1.0, 1.0, -1.0]);
+    }
+}
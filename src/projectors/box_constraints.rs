@@ -0,0 +1,125 @@
+use crate::{errors::Error, Projector, Result, Scalar};
+use core::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Bounds<T> {
+    Scalar(T, T),
+    PerCoordinate(Vec<T>, Vec<T>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxProjector<T = f32> {
+    bounds: Bounds<T>,
+}
+
+impl<T: Scalar> BoxProjector<T> {
+    pub fn new(lo: T, hi: T) -> Self {
+        if !ordered(lo, hi) {
+            panic!("invalid bounds: expected lo <= hi, got lo={lo}, hi={hi}");
+        }
+
+        Self {
+            bounds: Bounds::Scalar(lo, hi),
+        }
+    }
+
+    pub fn per_coordinate(lo: Vec<T>, hi: Vec<T>) -> Self {
+        if lo.len() != hi.len() {
+            panic!(
+                "invalid bounds: expected equal lengths, got {} and {}",
+                lo.len(),
+                hi.len()
+            );
+        }
+        if let Some(i) = lo.iter().zip(&hi).position(|(&l, &h)| !ordered(l, h)) {
+            panic!(
+                "invalid bounds: expected lo <= hi, got lo={}, hi={} at coordinate {i}",
+                lo[i], hi[i]
+            );
+        }
+
+        Self {
+            bounds: Bounds::PerCoordinate(lo, hi),
+        }
+    }
+}
+
+// False for NaN bounds as well as inverted ones.
+fn ordered<T: Scalar>(lo: T, hi: T) -> bool {
+    lo.partial_cmp(&hi).is_some_and(Ordering::is_le)
+}
+
+fn clamp<T: Scalar>(x: T, lo: T, hi: T) -> T {
+    if x < lo {
+        lo
+    } else if x > hi {
+        hi
+    } else {
+        x
+    }
+}
+
+impl<S, T> Projector<S> for BoxProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+
+        match &self.bounds {
+            Bounds::Scalar(lo, hi) => {
+                for x in values.iter_mut() {
+                    *x = clamp(*x, *lo, *hi);
+                }
+            }
+            Bounds::PerCoordinate(lo, hi) => {
+                if values.len() != lo.len() {
+                    return Err(Error::Dimension(lo.len(), values.len()));
+                }
+                for ((x, &l), &h) in values.iter_mut().zip(lo).zip(hi) {
+                    *x = clamp(*x, l, h);
+                }
+            }
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_box_projector_scalar() {
+        let projector = BoxProjector::new(-1.0, 1.0);
+        let output = projector.project(vec![-2.0, -0.5, 0.0, 0.5, 2.0]).unwrap();
+        assert_eq!(output, vec![-1.0, -0.5, 0.0, 0.5, 1.0]);
+
+        let output = projector.project([3.0f32, -3.0]).unwrap();
+        assert_eq!(output, [1.0, -1.0]);
+    }
+
+    #[test]
+    fn test_box_projector_per_coordinate() {
+        let projector = BoxProjector::per_coordinate(vec![0.0, -1.0, 2.0], vec![1.0, 1.0, 3.0]);
+        let output = projector.project(vec![-1.0, 0.5, 0.0]).unwrap();
+        assert_eq!(output, vec![0.0, 0.5, 2.0]);
+
+        let err = projector.project(vec![0.0, 0.0]).unwrap_err();
+        assert!(matches!(err, Error::Dimension(3, 2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid bounds: expected lo <= hi, got lo=1, hi=0")]
+    fn test_box_projector_invalid_bounds() {
+        BoxProjector::new(1.0, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid bounds: expected lo <= hi, got lo=NaN, hi=1")]
+    fn test_box_projector_nan_bounds() {
+        BoxProjector::new(f32::NAN, 1.0);
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{errors::Error, Projector, Result, Scalar, State};
+use core::marker::PhantomData;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReflectorProjector<P> {
+    projector: P,
+    gamma: f32,
+}
+
+impl<P> ReflectorProjector<P> {
+    pub fn new(projector: P) -> Self {
+        Self::relaxed(projector, 1.0)
+    }
+
+    // (1 + gamma) P - gamma I, the plain reflector being gamma = 1.
+    pub fn relaxed(projector: P, gamma: f32) -> Self {
+        Self { projector, gamma }
+    }
+
+    pub fn into_inner(self) -> P {
+        self.projector
+    }
+}
+
+impl<S, P> Projector<S> for ReflectorProjector<P>
+where
+    S: State,
+    P: Projector<S>,
+{
+    fn project(&self, state: S) -> Result<S> {
+        let projected = self.projector.project(state.clone())?;
+        Ok(projected.scale(1.0 + self.gamma).axpy(-self.gamma, state))
+    }
+}
+
+pub fn reflect<P>(projector: P) -> ReflectorProjector<P> {
+    ReflectorProjector::new(projector)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Composition<P1, P2> {
+    outer: P1,
+    inner: P2,
+}
+
+impl<S, P1, P2> Projector<S> for Composition<P1, P2>
+where
+    P1: Projector<S>,
+    P2: Projector<S>,
+{
+    fn project(&self, state: S) -> Result<S> {
+        self.outer.project(self.inner.project(state)?)
+    }
+}
+
+// Follows function composition, so `compose(p1, p2)` applies `p2` first.
+pub fn compose<P1, P2>(p1: P1, p2: P2) -> Composition<P1, P2> {
+    Composition {
+        outer: p1,
+        inner: p2,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Average<P> {
+    projectors: Vec<P>,
+    weights: Vec<f32>,
+}
+
+impl<S, P> Projector<S> for Average<P>
+where
+    S: State,
+    P: Projector<S>,
+{
+    fn project(&self, state: S) -> Result<S> {
+        let mut output: Option<S> = None;
+        for (projector, &w) in self.projectors.iter().zip(&self.weights) {
+            let projected = projector.project(state.clone())?;
+            output = Some(match output {
+                Some(acc) => acc.axpy(w, projected),
+                None => projected.scale(w),
+            });
+        }
+
+        Ok(output.unwrap_or(state))
+    }
+}
+
+pub fn average<P>(projectors: Vec<P>, weights: Vec<f32>) -> Average<P> {
+    if projectors.len() != weights.len() {
+        panic!(
+            "invalid weights: expected {} weights, got {}",
+            projectors.len(),
+            weights.len()
+        );
+    }
+
+    let total: f32 = weights.iter().sum();
+    if total <= 0.0 || weights.iter().any(|&w| w < 0.0) {
+        panic!("invalid weights: expected non-negative weights with a positive sum");
+    }
+
+    Average {
+        projectors,
+        weights: weights.into_iter().map(|w| w / total).collect(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Masked<P, T = f32> {
+    projector: P,
+    mask: Vec<bool>,
+    _marker: PhantomData<T>,
+}
+
+impl<S, P, T> Projector<S> for Masked<P, T>
+where
+    S: Clone + AsRef<[T]> + AsMut<[T]>,
+    P: Projector<S>,
+    T: Scalar,
+{
+    fn project(&self, state: S) -> Result<S> {
+        if state.as_ref().len() != self.mask.len() {
+            return Err(Error::Dimension(self.mask.len(), state.as_ref().len()));
+        }
+
+        let mut projected = self.projector.project(state.clone())?;
+        let values = projected.as_mut();
+        if values.len() != self.mask.len() {
+            return Err(Error::Dimension(self.mask.len(), values.len()));
+        }
+
+        for ((x, &original), &inside) in values.iter_mut().zip(state.as_ref()).zip(&self.mask) {
+            if !inside {
+                *x = original;
+            }
+        }
+
+        Ok(projected)
+    }
+}
+
+pub fn masked<P, T: Scalar>(projector: P, mask: Vec<bool>) -> Masked<P, T> {
+    Masked {
+        projector,
+        mask,
+        _marker: PhantomData,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        projectors::{
+            box_constraints::BoxProjector, hyperplane::HyperplaneProjector,
+            nonnegative::NonNegativeProjector,
+        },
+        states::vector::VecState,
+    };
+
+    #[test]
+    fn test_reflect() {
+        let reflector = reflect(BoxProjector::new(-1.0, 1.0));
+        let output = reflector.project(VecState(vec![3.0, 0.5, -2.0])).unwrap();
+        assert_eq!(output, VecState(vec![-1.0, 0.5, 0.0]));
+
+        let relaxed = ReflectorProjector::relaxed(BoxProjector::new(-1.0, 1.0), 0.5);
+        let output = relaxed.project(VecState(vec![3.0, 0.5, -2.0])).unwrap();
+        assert_eq!(output, VecState(vec![0.0, 0.5, -0.5]));
+    }
+
+    #[test]
+    fn test_compose() {
+        let projector = compose(BoxProjector::new(-1.0, 1.0), NonNegativeProjector::new());
+        let output = projector.project(vec![-2.0, 0.5, 2.0]).unwrap();
+        assert_eq!(output, vec![0.0, 0.5, 1.0]);
+
+        // The hyperplane runs first and its output is then clamped.
+        let projector = compose(
+            BoxProjector::new(0.0, 1.0),
+            HyperplaneProjector::new(vec![1.0, 1.0], 4.0),
+        );
+        let output = projector.project(vec![0.0, 0.0]).unwrap();
+        assert_eq!(output, vec![1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_average() {
+        let projector = average(
+            vec![
+                Box::new(BoxProjector::new(0f32, 1.0)) as Box<dyn Projector<VecState>>,
+                Box::new(BoxProjector::new(2.0, 3.0)),
+            ],
+            vec![3.0, 1.0],
+        );
+        let output = projector.project(VecState(vec![-1.0, 5.0])).unwrap();
+        assert_eq!(output, VecState(vec![0.5, 1.5]));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid weights: expected 2 weights, got 1")]
+    fn test_average_invalid_weights() {
+        average(vec![BoxProjector::new(0.0, 1.0); 2], vec![1.0]);
+    }
+
+    #[test]
+    fn test_masked() {
+        let projector = masked(BoxProjector::new(-1.0, 1.0), vec![true, false, true]);
+        let output = projector.project(vec![-2.0, 5.0, 2.0]).unwrap();
+        assert_eq!(output, vec![-1.0, 5.0, 1.0]);
+    }
+}
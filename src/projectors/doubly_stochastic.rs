@@ -0,0 +1,123 @@
+use crate::{errors::Error, Projector, Result, Scalar};
+
+// Sinkhorn scaling is not the Euclidean projection onto the Birkhoff polytope, but it
+// is the usual soft relaxation of the permutation projector.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DoublyStochasticProjector<T = f32> {
+    n: usize,
+    tolerance: T,
+    max_iterations: usize,
+}
+
+impl<T: Scalar> DoublyStochasticProjector<T> {
+    pub fn new(n: usize) -> Self {
+        if n == 0 {
+            panic!("invalid size: expected at least 1, got 0");
+        }
+
+        Self {
+            n,
+            tolerance: T::from_f32(1e-6),
+            max_iterations: 1000,
+        }
+    }
+
+    pub fn with_tolerance(mut self, tolerance: T) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    fn normalize_rows(&self, values: &mut [T]) {
+        let n = self.n;
+        for row in values.chunks_mut(n) {
+            let sum: T = row.iter().copied().sum();
+            if sum > T::ZERO {
+                row.iter_mut().for_each(|x| *x /= sum);
+            } else {
+                row.iter_mut().for_each(|x| *x = T::ONE / T::from_usize(n));
+            }
+        }
+    }
+
+    fn normalize_columns(&self, values: &mut [T]) -> T {
+        let n = self.n;
+        let mut error = T::ZERO;
+        for c in 0..n {
+            let sum: T = (0..n).map(|r| values[r * n + c]).sum();
+            error = error.max((sum - T::ONE).abs());
+            for r in 0..n {
+                let x = &mut values[r * n + c];
+                *x = if sum > T::ZERO {
+                    *x / sum
+                } else {
+                    T::ONE / T::from_usize(n)
+                };
+            }
+        }
+        error
+    }
+}
+
+impl<S, T> Projector<S> for DoublyStochasticProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        if values.len() != self.n * self.n {
+            return Err(Error::Dimension(self.n * self.n, values.len()));
+        }
+
+        values.iter_mut().for_each(|x| *x = x.max(T::ZERO));
+        for _ in 0..self.max_iterations {
+            self.normalize_rows(values);
+            if self.normalize_columns(values) <= self.tolerance {
+                break;
+            }
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_doubly_stochastic_projector() {
+        let projector = DoublyStochasticProjector::new(3);
+        let output = projector
+            .project(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, -9.0])
+            .unwrap();
+
+        for r in 0..3 {
+            let sum: f32 = output[r * 3..(r + 1) * 3].iter().sum();
+            assert!((sum - 1.0).abs() < 1e-4, "row {r} sums to {sum}");
+        }
+        for c in 0..3 {
+            let sum: f32 = (0..3).map(|r| output[r * 3 + c]).sum();
+            assert!((sum - 1.0).abs() < 1e-4, "column {c} sums to {sum}");
+        }
+        assert!(output.iter().all(|&x| x >= 0.0));
+    }
+
+    #[test]
+    fn test_doubly_stochastic_projector_zeros() {
+        let projector = DoublyStochasticProjector::new(2);
+        let output = projector.project(vec![0.0; 4]).unwrap();
+        assert_eq!(output, vec![0.5; 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid size: expected at least 1, got 0")]
+    fn test_doubly_stochastic_projector_empty() {
+        DoublyStochasticProjector::<f32>::new(0);
+    }
+}
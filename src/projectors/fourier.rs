@@ -0,0 +1,160 @@
+use crate::{errors::Error, Projector, Result};
+use rustfft::{num_complex::Complex32, Fft, FftPlanner};
+use std::{fmt, sync::Arc};
+
+#[derive(Clone)]
+pub struct FourierMagnitudeProjector {
+    magnitudes: Vec<f32>,
+    nrows: usize,
+    ncols: usize,
+    row_forward: Arc<dyn Fft<f32>>,
+    row_inverse: Arc<dyn Fft<f32>>,
+    col_forward: Arc<dyn Fft<f32>>,
+    col_inverse: Arc<dyn Fft<f32>>,
+}
+
+impl FourierMagnitudeProjector {
+    pub fn new(magnitudes: Vec<f32>) -> Self {
+        let n = magnitudes.len();
+        Self::new_2d(magnitudes, 1, n)
+    }
+
+    pub fn new_2d(magnitudes: Vec<f32>, nrows: usize, ncols: usize) -> Self {
+        if magnitudes.len() != nrows * ncols {
+            panic!(
+                "invalid magnitudes: expected {} values for a {nrows}x{ncols} grid, got {}",
+                nrows * ncols,
+                magnitudes.len()
+            );
+        }
+
+        let mut planner = FftPlanner::new();
+        Self {
+            magnitudes,
+            nrows,
+            ncols,
+            row_forward: planner.plan_fft_forward(ncols),
+            row_inverse: planner.plan_fft_inverse(ncols),
+            col_forward: planner.plan_fft_forward(nrows),
+            col_inverse: planner.plan_fft_inverse(nrows),
+        }
+    }
+
+    fn transform(&self, values: &mut [Complex32], rows: &dyn Fft<f32>, cols: &dyn Fft<f32>) {
+        rows.process(values);
+
+        if self.nrows > 1 {
+            let mut column = vec![Complex32::default(); self.nrows];
+            for c in 0..self.ncols {
+                for (r, x) in column.iter_mut().enumerate() {
+                    *x = values[r * self.ncols + c];
+                }
+                cols.process(&mut column);
+                for (r, x) in column.iter().enumerate() {
+                    values[r * self.ncols + c] = *x;
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Debug for FourierMagnitudeProjector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FourierMagnitudeProjector")
+            .field("magnitudes", &self.magnitudes)
+            .field("nrows", &self.nrows)
+            .field("ncols", &self.ncols)
+            .finish()
+    }
+}
+
+impl<S> Projector<S> for FourierMagnitudeProjector
+where
+    S: AsMut<[Complex32]>,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        if values.len() != self.magnitudes.len() {
+            return Err(Error::Dimension(self.magnitudes.len(), values.len()));
+        }
+
+        self.transform(values, &*self.row_forward, &*self.col_forward);
+
+        for (x, &m) in values.iter_mut().zip(&self.magnitudes) {
+            let norm = x.norm();
+            *x = if norm > 0.0 {
+                *x * (m / norm)
+            } else {
+                Complex32::new(m, 0.0)
+            };
+        }
+
+        self.transform(values, &*self.row_inverse, &*self.col_inverse);
+
+        let scale = 1.0 / values.len() as f32;
+        values.iter_mut().for_each(|x| *x *= scale);
+
+        Ok(state)
+    }
+}
+
+// The magnitudes of the unnormalised 2D DFT of a row-major nrows x ncols grid,
+// i.e. what a FourierMagnitudeProjector expects as its measurements.
+pub fn fourier_magnitudes(values: &[Complex32], nrows: usize, ncols: usize) -> Vec<f32> {
+    if values.len() != nrows * ncols {
+        panic!(
+            "invalid values: expected {} values for a {nrows}x{ncols} grid, got {}",
+            nrows * ncols,
+            values.len()
+        );
+    }
+
+    let projector = FourierMagnitudeProjector::new_2d(vec![0.0; nrows * ncols], nrows, ncols);
+    let mut values = values.to_vec();
+    projector.transform(
+        &mut values,
+        &*projector.row_forward,
+        &*projector.col_forward,
+    );
+    values.iter().map(|x| x.norm()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fourier_magnitude_projector() {
+        let signal: Vec<Complex32> = [1.0, 2.0, 0.0, -1.0]
+            .iter()
+            .map(|&x| Complex32::new(x, 0.0))
+            .collect();
+        let magnitudes = fourier_magnitudes(&signal, 1, 4);
+
+        // A signal that already has the measured magnitudes is a fixed point.
+        let projector = FourierMagnitudeProjector::new(magnitudes.clone());
+        let output = projector.project(signal.clone()).unwrap();
+        for (o, s) in output.iter().zip(&signal) {
+            assert!((o - s).norm() < 1e-5);
+        }
+
+        let guess = vec![Complex32::new(0.3, 0.1); 4];
+        let output = projector.project(guess).unwrap();
+        for (a, b) in fourier_magnitudes(&output, 1, 4).iter().zip(&magnitudes) {
+            assert!((a - b).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_fourier_magnitude_projector_2d() {
+        let signal: Vec<Complex32> = (0..6).map(|i| Complex32::new(i as f32, 0.0)).collect();
+        let magnitudes = fourier_magnitudes(&signal, 2, 3);
+        let projector = FourierMagnitudeProjector::new_2d(magnitudes.clone(), 2, 3);
+
+        let guess: Vec<Complex32> = (0..6).map(|i| Complex32::new(1.0, i as f32)).collect();
+        let output = projector.project(guess).unwrap();
+        for (a, b) in fourier_magnitudes(&output, 2, 3).iter().zip(&magnitudes) {
+            assert!((a - b).abs() < 1e-4);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::{errors::Error, projectors::dot, Projector, Result, Scalar};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HalfspaceProjector<T = f32> {
+    a: Vec<T>,
+    b: T,
+    norm_squared: T,
+}
+
+impl<T: Scalar> HalfspaceProjector<T> {
+    pub fn new(a: Vec<T>, b: T) -> Self {
+        let norm_squared = dot(&a, &a);
+        if norm_squared == T::ZERO {
+            panic!("invalid halfspace: expected non-zero normal vector");
+        }
+
+        Self { a, b, norm_squared }
+    }
+}
+
+impl<S, T> Projector<S> for HalfspaceProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        if values.len() != self.a.len() {
+            return Err(Error::Dimension(self.a.len(), values.len()));
+        }
+
+        let excess = dot(&self.a, values) - self.b;
+        if excess > T::ZERO {
+            let scale = excess / self.norm_squared;
+            for (x, &a) in values.iter_mut().zip(&self.a) {
+                *x -= scale * a;
+            }
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_halfspace_projector() {
+        // x + y <= 2
+        let projector = HalfspaceProjector::new(vec![1.0, 1.0], 2.0);
+        let output = projector.project(vec![3.0, 3.0]).unwrap();
+        assert_eq!(output, vec![1.0, 1.0]);
+
+        let output = projector.project(vec![-3.0, 1.0]).unwrap();
+        assert_eq!(output, vec![-3.0, 1.0]);
+
+        let err = projector.project(vec![0.0]).unwrap_err();
+        assert!(matches!(err, Error::Dimension(2, 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid halfspace: expected non-zero normal vector")]
+    fn test_halfspace_projector_zero_normal() {
+        HalfspaceProjector::new(vec![0.0, 0.0], 1.0);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{errors::Error, projectors::dot, Projector, Result, Scalar};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HyperplaneProjector<T = f32> {
+    a: Vec<T>,
+    b: T,
+    norm_squared: T,
+}
+
+impl<T: Scalar> HyperplaneProjector<T> {
+    pub fn new(a: Vec<T>, b: T) -> Self {
+        let norm_squared = dot(&a, &a);
+        if norm_squared == T::ZERO {
+            panic!("invalid hyperplane: expected non-zero normal vector");
+        }
+
+        Self { a, b, norm_squared }
+    }
+
+    pub fn dim(&self) -> usize {
+        self.a.len()
+    }
+
+    fn project_slice(&self, values: &mut [T]) -> Result<()> {
+        if values.len() != self.a.len() {
+            return Err(Error::Dimension(self.a.len(), values.len()));
+        }
+
+        let scale = (dot(&self.a, values) - self.b) / self.norm_squared;
+        for (x, &a) in values.iter_mut().zip(&self.a) {
+            *x -= scale * a;
+        }
+
+        Ok(())
+    }
+}
+
+impl<S, T> Projector<S> for HyperplaneProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        self.project_slice(state.as_mut())?;
+        Ok(state)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchedHyperplaneProjector<T = f32> {
+    hyperplanes: Vec<HyperplaneProjector<T>>,
+    dim: usize,
+}
+
+impl<T: Scalar> BatchedHyperplaneProjector<T> {
+    pub fn new(hyperplanes: Vec<HyperplaneProjector<T>>) -> Self {
+        let dim = hyperplanes.iter().map(HyperplaneProjector::dim).sum();
+        Self { hyperplanes, dim }
+    }
+}
+
+impl<S, T> Projector<S> for BatchedHyperplaneProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let mut values = state.as_mut();
+        if values.len() != self.dim {
+            return Err(Error::Dimension(self.dim, values.len()));
+        }
+
+        for hyperplane in &self.hyperplanes {
+            let (block, rest) = values.split_at_mut(hyperplane.dim());
+            hyperplane.project_slice(block)?;
+            values = rest;
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hyperplane_projector() {
+        // x + y = 2
+        let projector = HyperplaneProjector::new(vec![1.0, 1.0], 2.0);
+        let output = projector.project(vec![3.0, 3.0]).unwrap();
+        assert_eq!(output, vec![1.0, 1.0]);
+
+        let output = projector.project(vec![-1.0, 1.0]).unwrap();
+        assert_eq!(output, vec![0.0, 2.0]);
+    }
+
+    #[test]
+    fn test_batched_hyperplane_projector() {
+        let projector = BatchedHyperplaneProjector::new(vec![
+            HyperplaneProjector::new(vec![1.0, 1.0], 2.0),
+            HyperplaneProjector::new(vec![1.0, 0.0, 0.0], -1.0),
+        ]);
+        let output = projector.project(vec![3.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        assert_eq!(output, vec![1.0, 1.0, -1.0, 5.0, 6.0]);
+
+        let err = projector.project(vec![0.0; 4]).unwrap_err();
+        assert!(matches!(err, Error::Dimension(5, 4)));
+    }
+}
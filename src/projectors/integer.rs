@@ -0,0 +1,64 @@
+use crate::{Projector, Result, Scalar};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct IntegerProjector<T = f32> {
+    bounds: Option<(T, T)>,
+}
+
+impl<T: Scalar> IntegerProjector<T> {
+    pub fn new() -> Self {
+        Self { bounds: None }
+    }
+
+    pub fn bounded(lo: T, hi: T) -> Self {
+        let (lo, hi) = (lo.ceil(), hi.floor());
+        if lo > hi {
+            panic!("invalid bounds: expected at least one integer in range, got lo={lo}, hi={hi}");
+        }
+
+        Self {
+            bounds: Some((lo, hi)),
+        }
+    }
+}
+
+impl<S, T> Projector<S> for IntegerProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+
+        match self.bounds {
+            Some((lo, hi)) => values
+                .iter_mut()
+                .for_each(|x| *x = x.round().max(lo).min(hi)),
+            None => values.iter_mut().for_each(|x| *x = x.round()),
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer_projector() {
+        let projector = IntegerProjector::new();
+        let output = projector.project(vec![-1.6, 0.4, 2.5, 7.0]).unwrap();
+        assert_eq!(output, vec![-2.0, 0.0, 3.0, 7.0]);
+
+        let projector = IntegerProjector::bounded(0.5, 9.0);
+        let output = projector.project(vec![-3.2, 4.4, 12.0]).unwrap();
+        assert_eq!(output, vec![1.0, 4.0, 9.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid bounds: expected at least one integer in range")]
+    fn test_integer_projector_invalid_bounds() {
+        IntegerProjector::bounded(0.2, 0.8);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{errors::Error, states::product_space::ProductSpace, Projector, Result, State};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductDivide<P> {
+    projectors: Vec<P>,
+}
+
+impl<S, P> Projector<ProductSpace<S>> for ProductDivide<P>
+where
+    S: State,
+    P: Projector<S>,
+{
+    fn project(&self, state: ProductSpace<S>) -> Result<ProductSpace<S>> {
+        if state.len() != self.projectors.len() {
+            return Err(Error::Dimension(self.projectors.len(), state.len()));
+        }
+
+        let copies = state
+            .copies
+            .into_iter()
+            .zip(&self.projectors)
+            .map(|(copy, projector)| projector.project(copy))
+            .collect::<Result<_>>()?;
+
+        Ok(ProductSpace { copies })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProductConcur {
+    weights: Option<Vec<f32>>,
+}
+
+impl ProductConcur {
+    pub fn new() -> Self {
+        Self { weights: None }
+    }
+
+    pub fn weighted(weights: Vec<f32>) -> Self {
+        if weights.iter().any(|&w| w < 0.0) || weights.iter().sum::<f32>() <= 0.0 {
+            panic!("invalid weights: expected non-negative weights with a positive sum");
+        }
+
+        Self {
+            weights: Some(weights),
+        }
+    }
+}
+
+impl<S> Projector<ProductSpace<S>> for ProductConcur
+where
+    S: State,
+{
+    fn project(&self, state: ProductSpace<S>) -> Result<ProductSpace<S>> {
+        let n = state.len();
+        let mean = match &self.weights {
+            Some(weights) if weights.len() != n => {
+                return Err(Error::Dimension(weights.len(), n));
+            }
+            Some(weights) => state.weighted_mean(weights),
+            None => state.mean(),
+        };
+
+        match mean {
+            Some(mean) => Ok(ProductSpace::replicate(mean, n)),
+            None => Ok(state),
+        }
+    }
+}
+
+pub fn lift<P>(projectors: Vec<P>) -> (ProductDivide<P>, ProductConcur) {
+    (ProductDivide { projectors }, ProductConcur::new())
+}
+
+pub fn lift_weighted<P>(
+    projectors: Vec<P>,
+    weights: Vec<f32>,
+) -> (ProductDivide<P>, ProductConcur) {
+    if projectors.len() != weights.len() {
+        panic!(
+            "invalid weights: expected {} weights, got {}",
+            projectors.len(),
+            weights.len()
+        );
+    }
+
+    (
+        ProductDivide { projectors },
+        ProductConcur::weighted(weights),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        projectors::{box_constraints::BoxProjector, hyperplane::HyperplaneProjector},
+        solvers::divide_and_concur::DivideAndConcurSolver,
+        states::vector::VecState,
+        Solver,
+    };
+
+    fn norm(current: &ProductSpace<VecState>, previous: &ProductSpace<VecState>) -> f32 {
+        current
+            .copies
+            .iter()
+            .zip(&previous.copies)
+            .flat_map(|(c, p)| c.0.iter().zip(&p.0).map(|(x, y)| (x - y).powi(2)))
+            .sum::<f32>()
+            .sqrt()
+    }
+
+    #[test]
+    fn test_lift() {
+        let (divide, concur) = lift(vec![
+            Box::new(BoxProjector::new(0f32, 1.0)) as Box<dyn Projector<VecState>>,
+            Box::new(HyperplaneProjector::new(vec![1.0, 1.0], 1.5)),
+        ]);
+
+        let state = ProductSpace::replicate(VecState(vec![2.0, -1.0]), 2);
+        let divided = divide.project(state).unwrap();
+        assert_eq!(divided.copies[0], VecState(vec![1.0, 0.0]));
+        assert_eq!(divided.copies[1], VecState(vec![2.25, -0.75]));
+
+        let concurred = concur.project(divided).unwrap();
+        assert_eq!(concurred.copies, vec![VecState(vec![1.625, -0.375]); 2]);
+
+        let solver = DivideAndConcurSolver::new(divide, concur, norm, 0.8, 1e-5, 1000);
+        let (solution, _, _) = solver
+            .run(ProductSpace::replicate(VecState(vec![2.0, -1.0]), 2))
+            .unwrap();
+        let x = solution.mean().unwrap();
+        assert!((x.0[0] + x.0[1] - 1.5).abs() < 1e-3);
+        assert!(x.0.iter().all(|&v| (-1e-3..=1.0 + 1e-3).contains(&v)));
+    }
+
+    #[test]
+    fn test_lift_weighted() {
+        let (divide, concur) = lift_weighted(
+            vec![BoxProjector::new(0.0, 1.0), BoxProjector::new(2.0, 3.0)],
+            vec![3.0, 1.0],
+        );
+
+        let state = ProductSpace::replicate(VecState(vec![-1.0, 5.0]), 2);
+        let divided = divide.project(state).unwrap();
+        let concurred = concur.project(divided).unwrap();
+        assert_eq!(concurred.copies, vec![VecState(vec![0.5, 1.5]); 2]);
+
+        let err = concur
+            .project(ProductSpace::replicate(VecState(vec![0.0]), 3))
+            .unwrap_err();
+        assert!(matches!(err, Error::Dimension(2, 3)));
+    }
+}
@@ -0,0 +1,31 @@
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+pub mod affine;
+pub mod approximate;
+pub mod ball;
+pub mod binary;
+pub mod box_constraints;
+pub mod combinators;
+pub mod doubly_stochastic;
+#[cfg(feature = "fft")]
+pub mod fourier;
+pub mod halfspace;
+pub mod hyperplane;
+pub mod integer;
+pub mod lift;
+pub mod nonnegative;
+pub mod one_hot;
+pub mod permutation;
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+pub mod psd;
+#[cfg(any(feature = "nalgebra", feature = "faer"))]
+pub mod rank;
+pub mod simplex;
+pub mod soc;
+#[cfg(feature = "nalgebra-sparse")]
+pub mod sparse_affine;
+pub mod sparsity;
+pub mod support;
+
+pub(crate) fn dot<T: crate::Scalar>(a: &[T], b: &[T]) -> T {
+    a.iter().zip(b).map(|(&x, &y)| x * y).sum()
+}
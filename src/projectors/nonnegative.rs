@@ -0,0 +1,67 @@
+use crate::{errors::Error, Projector, Result, Scalar};
+use core::marker::PhantomData;
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NonNegativeProjector<T = f32> {
+    mask: Option<Vec<bool>>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Scalar> NonNegativeProjector<T> {
+    pub fn new() -> Self {
+        Self {
+            mask: None,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn masked(mask: Vec<bool>) -> Self {
+        Self {
+            mask: Some(mask),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, T> Projector<S> for NonNegativeProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+
+        match &self.mask {
+            Some(mask) => {
+                if mask.len() != values.len() {
+                    return Err(Error::Dimension(mask.len(), values.len()));
+                }
+                for (x, _) in values.iter_mut().zip(mask).filter(|(_, &m)| m) {
+                    *x = x.max(T::ZERO);
+                }
+            }
+            None => values.iter_mut().for_each(|x| *x = x.max(T::ZERO)),
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nonnegative_projector() {
+        let projector = NonNegativeProjector::new();
+        let output = projector.project(vec![-1.0, 0.0, 2.0]).unwrap();
+        assert_eq!(output, vec![0.0, 0.0, 2.0]);
+
+        let projector = NonNegativeProjector::masked(vec![true, false, true]);
+        let output = projector.project(vec![-1.0, -2.0, -3.0]).unwrap();
+        assert_eq!(output, vec![0.0, -2.0, 0.0]);
+
+        let err = projector.project(vec![0.0]).unwrap_err();
+        assert!(matches!(err, Error::Dimension(3, 1)));
+    }
+}
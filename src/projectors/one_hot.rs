@@ -0,0 +1,68 @@
+use crate::{Projector, Result, Scalar};
+use core::marker::PhantomData;
+
+// Projects onto the standard basis vectors, and with `at_most_one` also onto
+// the zero vector, which wins whenever no coordinate exceeds 1/2.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OneHotProjector<T = f32> {
+    optional: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Scalar> OneHotProjector<T> {
+    pub fn new() -> Self {
+        Self {
+            optional: false,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn at_most_one() -> Self {
+        Self {
+            optional: true,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, T> Projector<S> for OneHotProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        let best = values
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(i, &x)| (i, x));
+
+        values.iter_mut().for_each(|x| *x = T::ZERO);
+        if let Some((i, x)) = best {
+            if !self.optional || x > T::from_f32(0.5) {
+                values[i] = T::ONE;
+            }
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_one_hot_projector() {
+        let projector = OneHotProjector::new();
+        let output = projector.project(vec![0.1, 0.3, -2.0]).unwrap();
+        assert_eq!(output, vec![0.0, 1.0, 0.0]);
+
+        let projector = OneHotProjector::at_most_one();
+        let output = projector.project(vec![0.1, 0.3, -2.0]).unwrap();
+        assert_eq!(output, vec![0.0, 0.0, 0.0]);
+        let output = projector.project(vec![0.1, 0.7, 0.6]).unwrap();
+        assert_eq!(output, vec![0.0, 1.0, 0.0]);
+    }
+}
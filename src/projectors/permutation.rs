@@ -0,0 +1,96 @@
+use crate::{errors::Error, Projector, Result, Scalar};
+use pathfinding::prelude::{kuhn_munkres, Matrix};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PermutationProjector<T = f32> {
+    n: usize,
+    scale: T,
+}
+
+impl<T: Scalar> PermutationProjector<T> {
+    pub fn new(n: usize) -> Self {
+        Self {
+            n,
+            scale: T::from_f32(1000.0),
+        }
+    }
+
+    pub fn with_scale(mut self, scale: T) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn assign(&self, weights: &[T]) -> Result<Vec<usize>> {
+        if weights.len() != self.n * self.n {
+            return Err(Error::Dimension(self.n * self.n, weights.len()));
+        }
+        if self.n == 0 {
+            return Ok(Vec::new());
+        }
+
+        let rounded = weights
+            .iter()
+            .map(|&w| (w * self.scale).round().to_f64() as isize)
+            .collect();
+        let matrix =
+            Matrix::square_from_vec(rounded).map_err(|err| Error::Projection(Box::new(err)))?;
+        let (_, assignments) = kuhn_munkres(&matrix);
+
+        Ok(assignments)
+    }
+}
+
+impl<S, T> Projector<S> for PermutationProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        let assignments = self.assign(values)?;
+
+        values.iter_mut().for_each(|x| *x = T::ZERO);
+        for (r, c) in assignments.into_iter().enumerate() {
+            values[r * self.n + c] = T::ONE;
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_permutation_projector() {
+        let projector = PermutationProjector::new(3);
+        let output = projector
+            .project(vec![
+                0.1, 0.9, 0.2,
+                0.8, 0.7, 0.1,
+                0.3, 0.2, 0.4,
+            ])
+            .unwrap();
+        assert_eq!(output, vec![
+            0.0, 1.0, 0.0,
+            1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0,
+        ]);
+
+        let err = projector.project(vec![0.0; 8]).unwrap_err();
+        assert!(matches!(err, Error::Dimension(9, 8)));
+    }
+
+    #[test]
+    fn test_permutation_projector_scale() {
+        // Differences below the rounding resolution are ties.
+        let weights = [0.0, 0.0004, 0.0, 0.0];
+        let projector = PermutationProjector::new(2);
+        assert_eq!(projector.assign(&weights).unwrap(), vec![0, 1]);
+
+        let projector = projector.with_scale(1e4);
+        assert_eq!(projector.assign(&weights).unwrap(), vec![1, 0]);
+    }
+}
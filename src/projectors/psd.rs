@@ -0,0 +1,89 @@
+use crate::{
+    errors::Error,
+    linalg::{DefaultBackend, LinalgBackend},
+    Projector, Result, Scalar,
+};
+use core::marker::PhantomData;
+
+// Projects a column-major n × n matrix onto the positive semidefinite cone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PsdProjector<B = DefaultBackend, T = f32>
+where
+    B: LinalgBackend<T>,
+{
+    n: usize,
+    backend: B,
+    _marker: PhantomData<T>,
+}
+
+impl<T> PsdProjector<DefaultBackend, T>
+where
+    T: Scalar,
+    DefaultBackend: LinalgBackend<T>,
+{
+    pub fn new(n: usize) -> Self {
+        Self::new_in(n, DefaultBackend::default())
+    }
+}
+
+impl<B, T> PsdProjector<B, T>
+where
+    B: LinalgBackend<T>,
+    T: Scalar,
+{
+    pub fn new_in(n: usize, backend: B) -> Self {
+        Self {
+            n,
+            backend,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, B, T> Projector<S> for PsdProjector<B, T>
+where
+    S: AsMut<[T]>,
+    B: LinalgBackend<T>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        if values.len() != self.n * self.n {
+            return Err(Error::Dimension(self.n * self.n, values.len()));
+        }
+
+        B::project_psd(self.n, values)?;
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-5, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_psd_projector() {
+        let projector = PsdProjector::new(2);
+
+        let output = projector.project(vec![2.0, 1.0, 1.0, 2.0]).unwrap();
+        assert_close(&output, &[2.0, 1.0, 1.0, 2.0]);
+
+        let output = projector.project(vec![1.0, 0.0, 0.0, -1.0]).unwrap();
+        assert_close(&output, &[1.0, 0.0, 0.0, 0.0]);
+
+        // Eigenvalues 3 and -1 with eigenvectors (1, 1) and (1, -1).
+        let output = projector.project(vec![1.0, 2.0, 2.0, 1.0]).unwrap();
+        assert_close(&output, &[1.5, 1.5, 1.5, 1.5]);
+
+        // Asymmetric input is symmetrized before projecting.
+        let output = projector.project(vec![1.0, 0.0, 2.0, 1.0]).unwrap();
+        assert_close(&output, &[1.0, 1.0, 1.0, 1.0]);
+    }
+}
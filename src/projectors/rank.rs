@@ -0,0 +1,103 @@
+use crate::{
+    errors::Error,
+    linalg::{DefaultBackend, LinalgBackend},
+    Projector, Result, Scalar,
+};
+use core::marker::PhantomData;
+
+// Projects a column-major matrix onto those of rank at most `k`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RankProjector<B = DefaultBackend, T = f32>
+where
+    B: LinalgBackend<T>,
+{
+    k: usize,
+    nrows: usize,
+    ncols: usize,
+    backend: B,
+    _marker: PhantomData<T>,
+}
+
+impl<T> RankProjector<DefaultBackend, T>
+where
+    T: Scalar,
+    DefaultBackend: LinalgBackend<T>,
+{
+    pub fn new(k: usize, nrows: usize, ncols: usize) -> Self {
+        Self::new_in(k, nrows, ncols, DefaultBackend::default())
+    }
+}
+
+impl<B, T> RankProjector<B, T>
+where
+    B: LinalgBackend<T>,
+    T: Scalar,
+{
+    pub fn new_in(k: usize, nrows: usize, ncols: usize, backend: B) -> Self {
+        Self {
+            k,
+            nrows,
+            ncols,
+            backend,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, B, T> Projector<S> for RankProjector<B, T>
+where
+    S: AsMut<[T]>,
+    B: LinalgBackend<T>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        if values.len() != self.nrows * self.ncols {
+            return Err(Error::Dimension(self.nrows * self.ncols, values.len()));
+        }
+
+        if self.k < self.nrows.min(self.ncols) {
+            B::truncate_rank(self.k, self.nrows, self.ncols, values)?;
+        }
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-5, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_rank_projector() {
+        let projector = RankProjector::new(1, 2, 2);
+
+        let output = projector.project(vec![3.0, 0.0, 0.0, 1.0]).unwrap();
+        assert_close(&output, &[3.0, 0.0, 0.0, 0.0]);
+
+        let output = projector.project(vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+        assert_close(&output, &[1.0, 2.0, 2.0, 4.0]);
+    }
+
+    #[test]
+    fn test_rank_projector_rectangular() {
+        // [[1, 0, 0], [0, 2, 0]] in column-major order.
+        let projector = RankProjector::new(1, 2, 3);
+        let output = projector
+            .project(vec![1.0, 0.0, 0.0, 2.0, 0.0, 0.0])
+            .unwrap();
+        assert_close(&output, &[0.0, 0.0, 0.0, 2.0, 0.0, 0.0]);
+
+        let projector = RankProjector::new(2, 2, 3);
+        let output = projector
+            .project(vec![1.0, 0.0, 0.0, 2.0, 0.0, 0.0])
+            .unwrap();
+        assert_close(&output, &[1.0, 0.0, 0.0, 2.0, 0.0, 0.0]);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{Projector, Result, Scalar};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimplexProjector<T = f32> {
+    radius: T,
+    k: Option<usize>,
+}
+
+impl<T: Scalar> SimplexProjector<T> {
+    pub fn new(radius: T) -> Self {
+        if radius <= T::ZERO {
+            panic!("invalid radius: expected a positive value, got {radius}");
+        }
+
+        Self { radius, k: None }
+    }
+
+    // Keeps the k largest coordinates and projects them onto the simplex, which
+    // is the exact projection onto k-sparse points of the simplex.
+    pub fn sparse(radius: T, k: usize) -> Self {
+        if k == 0 {
+            panic!("invalid sparsity: expected k >= 1, got {k}");
+        }
+
+        Self {
+            k: Some(k),
+            ..Self::new(radius)
+        }
+    }
+}
+
+fn project_simplex<T: Scalar>(values: &mut [T], radius: T) {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable_by(|a, b| b.total_cmp(a));
+
+    let mut cumulative = T::ZERO;
+    let mut theta = T::ZERO;
+    for (j, &u) in sorted.iter().enumerate() {
+        cumulative += u;
+        let candidate = (cumulative - radius) / T::from_usize(j + 1);
+        if u - candidate > T::ZERO {
+            theta = candidate;
+        }
+    }
+
+    values
+        .iter_mut()
+        .for_each(|x| *x = (*x - theta).max(T::ZERO));
+}
+
+impl<S, T> Projector<S> for SimplexProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+
+        match self.k {
+            Some(k) if k < values.len() => {
+                let mut order: Vec<usize> = (0..values.len()).collect();
+                order.select_nth_unstable_by(k - 1, |&i, &j| values[j].total_cmp(&values[i]));
+
+                let mut support: Vec<T> = order[..k].iter().map(|&i| values[i]).collect();
+                project_simplex(&mut support, self.radius);
+
+                values.iter_mut().for_each(|x| *x = T::ZERO);
+                for (&i, &x) in order[..k].iter().zip(&support) {
+                    values[i] = x;
+                }
+            }
+            _ => project_simplex(values, self.radius),
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: &[f32], b: &[f32]) {
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() < 1e-6, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_simplex_projector() {
+        let projector = SimplexProjector::new(1.0);
+        let output = projector.project(vec![0.5, 0.2, 0.3]).unwrap();
+        assert_close(&output, &[0.5, 0.2, 0.3]);
+
+        let output = projector.project(vec![2.0, 1.0, -1.0]).unwrap();
+        assert_close(&output, &[1.0, 0.0, 0.0]);
+
+        let output = projector.project(vec![0.0, 0.0, 0.0, 0.0]).unwrap();
+        assert_close(&output, &[0.25, 0.25, 0.25, 0.25]);
+
+        let projector = SimplexProjector::new(1f64);
+        let output = projector.project(vec![0.1, 0.2, 0.3]).unwrap();
+        // Every coordinate shifts up by (1 - 0.6) / 3.
+        let expected = [0.1, 0.2, 0.3].map(|x: f64| x + 0.4 / 3.0);
+        for (x, e) in output.iter().zip(&expected) {
+            assert!((x - e).abs() < 1e-12, "{output:?}");
+        }
+    }
+
+    #[test]
+    fn test_sparse_simplex_projector() {
+        let projector = SimplexProjector::sparse(1.0, 2);
+        let output = projector.project(vec![0.4, 0.1, 0.3, 0.2]).unwrap();
+        assert_close(&output, &[0.55, 0.0, 0.45, 0.0]);
+
+        let projector = SimplexProjector::sparse(2.0, 1);
+        let output = projector.project(vec![-1.0, 0.5, 0.2]).unwrap();
+        assert_close(&output, &[0.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid sparsity: expected k >= 1, got 0")]
+    fn test_sparse_simplex_projector_invalid_k() {
+        SimplexProjector::sparse(1.0, 0);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{errors::Error, projectors::dot, Projector, Result, Scalar};
+use core::marker::PhantomData;
+
+// Second-order cone {(x, t) : ||x|| <= t}, with t stored as the last coordinate.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SocProjector<T = f32> {
+    _marker: PhantomData<T>,
+}
+
+impl<T: Scalar> SocProjector<T> {
+    pub fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, T> Projector<S> for SocProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        let Some((t, x)) = values.split_last_mut() else {
+            return Err(Error::Dimension(1, 0));
+        };
+
+        let norm = dot(x, x).sqrt();
+        if norm <= *t {
+            return Ok(state);
+        }
+
+        if norm <= -*t {
+            x.iter_mut().for_each(|v| *v = T::ZERO);
+            *t = T::ZERO;
+        } else {
+            let scale = (norm + *t) / T::from_f32(2.0);
+            x.iter_mut().for_each(|v| *v *= scale / norm);
+            *t = scale;
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_soc_projector() {
+        let projector = SocProjector::new();
+
+        let output = projector.project(vec![3.0, 4.0, 6.0]).unwrap();
+        assert_eq!(output, vec![3.0, 4.0, 6.0]);
+
+        let output = projector.project(vec![3.0, 4.0, -6.0]).unwrap();
+        assert_eq!(output, vec![0.0, 0.0, 0.0]);
+
+        let output = projector.project(vec![3.0, 4.0, 1.0]).unwrap();
+        for (o, e) in output.iter().zip([1.8, 2.4, 3.0]) {
+            assert!((o - e).abs() < 1e-6);
+        }
+
+        let err = projector.project(Vec::<f32>::new()).unwrap_err();
+        assert!(matches!(err, Error::Dimension(1, 0)));
+    }
+}
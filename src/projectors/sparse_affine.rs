@@ -0,0 +1,205 @@
+use crate::{errors::Error, linalg::not_full_rank, projectors::dot, Projector, Result, Scalar};
+use nalgebra_sparse::CsrMatrix;
+use tracing::{event, Level};
+
+// Projects onto { x : A x = b } for a sparse A with full row rank. Factoring
+// A Aᵀ would fill in, so this solves A Aᵀ y = A x - b by conjugate gradients
+// instead, at two passes over the non-zeros of A per iteration.
+#[derive(Debug, Clone)]
+pub struct SparseAffineProjector<T = f32> {
+    a: CsrMatrix<T>,
+    b: Vec<T>,
+    tolerance: T,
+    max_iterations: usize,
+}
+
+impl<T: Scalar> SparseAffineProjector<T> {
+    pub fn new(a: CsrMatrix<T>, b: Vec<T>) -> Result<Self> {
+        if a.nrows() != b.len() {
+            return Err(Error::Dimension(a.nrows(), b.len()));
+        }
+        if a.row_iter()
+            .any(|row| row.values().iter().all(|&c| c == T::ZERO))
+        {
+            return Err(not_full_rank());
+        }
+
+        Ok(Self {
+            a,
+            b,
+            tolerance: T::from_f32(1e-6),
+            max_iterations: 1000,
+        })
+    }
+
+    // Stops conjugate gradients once the residual of A Aᵀ y = r is within
+    // `tolerance` of |r|.
+    pub fn with_tolerance(mut self, tolerance: T) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    // Past this, the projection is only approximate and a warning is logged.
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    // A x - b
+    pub fn residual(&self, x: &[T]) -> Result<Vec<T>> {
+        if x.len() != self.a.ncols() {
+            return Err(Error::Dimension(self.a.ncols(), x.len()));
+        }
+
+        Ok(self
+            .a
+            .row_iter()
+            .zip(&self.b)
+            .map(|(row, &b)| sparse_dot(row.col_indices(), row.values(), x) - b)
+            .collect())
+    }
+
+    // out = Aᵀ y
+    fn transpose_mul(&self, y: &[T], out: &mut [T]) {
+        out.fill(T::ZERO);
+        for (row, &yi) in self.a.row_iter().zip(y) {
+            for (&j, &c) in row.col_indices().iter().zip(row.values()) {
+                out[j] += c * yi;
+            }
+        }
+    }
+
+    // Solves A Aᵀ y = rhs, leaving Aᵀ y in `correction`.
+    fn solve_gram(&self, rhs: Vec<T>, correction: &mut [T]) -> Result<()> {
+        let mut y = vec![T::ZERO; rhs.len()];
+        let mut r = rhs;
+        let mut p = r.clone();
+        let mut q = vec![T::ZERO; r.len()];
+        let mut rr = dot(&r, &r);
+        let threshold = self.tolerance * self.tolerance * rr;
+
+        let mut iterations = 0;
+        while rr > threshold && iterations < self.max_iterations {
+            // q = A Aᵀ p
+            self.transpose_mul(&p, correction);
+            for (qi, row) in q.iter_mut().zip(self.a.row_iter()) {
+                *qi = sparse_dot(row.col_indices(), row.values(), correction);
+            }
+
+            let pq = dot(&p, &q);
+            if pq <= T::ZERO {
+                return Err(not_full_rank());
+            }
+            let alpha = rr / pq;
+            for ((yi, ri), (&pi, &qi)) in y.iter_mut().zip(&mut r).zip(p.iter().zip(&q)) {
+                *yi += alpha * pi;
+                *ri -= alpha * qi;
+            }
+
+            let next = dot(&r, &r);
+            for (pi, &ri) in p.iter_mut().zip(&r) {
+                *pi = ri + next / rr * *pi;
+            }
+            rr = next;
+            iterations += 1;
+        }
+        if rr > threshold {
+            event!(
+                Level::WARN,
+                iterations,
+                residual = rr.sqrt().to_f64(),
+                "conjugate gradients stopped before reaching the tolerance"
+            );
+        }
+
+        self.transpose_mul(&y, correction);
+        Ok(())
+    }
+}
+
+impl<S, T> Projector<S> for SparseAffineProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        let residual = self.residual(values)?;
+
+        let mut correction = vec![T::ZERO; values.len()];
+        self.solve_gram(residual, &mut correction)?;
+        for (x, &c) in values.iter_mut().zip(&correction) {
+            *x -= c;
+        }
+
+        Ok(state)
+    }
+}
+
+fn sparse_dot<T: Scalar>(indices: &[usize], values: &[T], x: &[T]) -> T {
+    indices.iter().zip(values).map(|(&j, &c)| c * x[j]).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projectors::affine::AffineProjector;
+    use nalgebra::{DMatrix, DVector};
+    use nalgebra_sparse::CooMatrix;
+
+    fn assert_close(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-4, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_sparse_affine_projector() {
+        // x0 + x1 = 1, x1 - x2 = 0, x2 + 2 x3 = 3
+        let dense = [
+            1.0, 1.0, 0.0, 0.0, //
+            0.0, 1.0, -1.0, 0.0, //
+            0.0, 0.0, 1.0, 2.0,
+        ];
+        let b = vec![1.0, 0.0, 3.0];
+        let a = DMatrix::from_row_slice(3, 4, &dense);
+        let sparse = SparseAffineProjector::new(CsrMatrix::from(&a), b.clone()).unwrap();
+        let expected = AffineProjector::new(a, DVector::from_vec(b)).unwrap();
+
+        let x = vec![2.0, -1.0, 0.5, 4.0];
+        let output = sparse.project(x.clone()).unwrap();
+        assert_close(&output, &expected.project(x).unwrap());
+        assert!(sparse
+            .residual(&output)
+            .unwrap()
+            .iter()
+            .all(|r| r.abs() < 1e-4));
+
+        let a = DMatrix::from_row_slice(3, 4, &dense).map(f64::from);
+        let sparse = SparseAffineProjector::new(CsrMatrix::from(&a), vec![1.0, 0.0, 3.0])
+            .unwrap()
+            .with_tolerance(1e-14);
+        let output = sparse.project(vec![2.0, -1.0, 0.5, 4.0]).unwrap();
+        assert!(sparse
+            .residual(&output)
+            .unwrap()
+            .iter()
+            .all(|r| r.abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_sparse_affine_projector_errors() {
+        let mut a = CooMatrix::new(2, 2);
+        a.push(0, 0, 1.0);
+        let a = CsrMatrix::from(&a);
+        assert!(matches!(
+            SparseAffineProjector::new(a.clone(), vec![1.0]),
+            Err(Error::Dimension(2, 1))
+        ));
+        assert!(matches!(
+            SparseAffineProjector::new(a, vec![1.0, 0.0]),
+            Err(Error::Factorization(_))
+        ));
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{Projector, Result, Scalar};
+use core::marker::PhantomData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SparsityProjector<T = f32> {
+    k: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Scalar> SparsityProjector<T> {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S, T> Projector<S> for SparsityProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        if self.k >= values.len() {
+            return Ok(state);
+        }
+
+        let mut order: Vec<usize> = (0..values.len()).collect();
+        if self.k > 0 {
+            order.select_nth_unstable_by(self.k - 1, |&i, &j| {
+                values[j].abs().total_cmp(&values[i].abs())
+            });
+        }
+        for &i in &order[self.k..] {
+            values[i] = T::ZERO;
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparsity_projector() {
+        let projector = SparsityProjector::new(2);
+        let output = projector.project(vec![0.5, -3.0, 1.0, 2.0]).unwrap();
+        assert_eq!(output, vec![0.0, -3.0, 0.0, 2.0]);
+
+        let projector = SparsityProjector::new(0);
+        let output = projector.project(vec![1.0, 2.0]).unwrap();
+        assert_eq!(output, vec![0.0, 0.0]);
+
+        let projector = SparsityProjector::new(5);
+        let output = projector.project(vec![1.0, 2.0]).unwrap();
+        assert_eq!(output, vec![1.0, 2.0]);
+    }
+}
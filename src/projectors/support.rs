@@ -0,0 +1,149 @@
+use crate::{errors::Error, Projector, Result};
+use std::marker::PhantomData;
+
+pub trait Coordinate: Copy {
+    fn zero() -> Self;
+
+    fn real(self) -> Self;
+
+    fn nonnegative(self) -> Self;
+}
+
+impl Coordinate for f32 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn real(self) -> Self {
+        self
+    }
+
+    fn nonnegative(self) -> Self {
+        self.max(0.0)
+    }
+}
+
+#[cfg(feature = "complex")]
+impl Coordinate for num_complex::Complex32 {
+    fn zero() -> Self {
+        Self::new(0.0, 0.0)
+    }
+
+    fn real(self) -> Self {
+        Self::new(self.re, 0.0)
+    }
+
+    // Ordering only exists on the real line, so nonnegativity implies realness.
+    fn nonnegative(self) -> Self {
+        Self::new(self.re.max(0.0), 0.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SupportProjector<T = f32> {
+    mask: Vec<bool>,
+    real: bool,
+    nonnegative: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<T> SupportProjector<T>
+where
+    T: Coordinate,
+{
+    pub fn new(mask: Vec<bool>) -> Self {
+        Self {
+            mask,
+            real: false,
+            nonnegative: false,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn with_real(mut self) -> Self {
+        self.real = true;
+        self
+    }
+
+    pub fn with_nonnegative(mut self) -> Self {
+        self.nonnegative = true;
+        self
+    }
+}
+
+impl<S, T> Projector<S> for SupportProjector<T>
+where
+    S: AsMut<[T]>,
+    T: Coordinate,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let values = state.as_mut();
+        if values.len() != self.mask.len() {
+            return Err(Error::Dimension(self.mask.len(), values.len()));
+        }
+
+        for (x, &inside) in values.iter_mut().zip(&self.mask) {
+            *x = match inside {
+                false => T::zero(),
+                true if self.nonnegative => x.nonnegative(),
+                true if self.real => x.real(),
+                true => *x,
+            };
+        }
+
+        Ok(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_support_projector() {
+        let projector = SupportProjector::new(vec![true, false, true]);
+        let output = projector.project(vec![-1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(output, vec![-1.0, 0.0, 3.0]);
+
+        let projector = projector.with_nonnegative();
+        let output = projector.project(vec![-1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(output, vec![0.0, 0.0, 3.0]);
+
+        let err = projector.project(vec![0.0]).unwrap_err();
+        assert!(matches!(err, Error::Dimension(3, 1)));
+    }
+
+    #[cfg(feature = "complex")]
+    #[test]
+    fn test_support_projector_complex() {
+        use num_complex::Complex32;
+
+        let state = vec![
+            Complex32::new(1.0, 1.0),
+            Complex32::new(2.0, 2.0),
+            Complex32::new(-3.0, 3.0),
+        ];
+
+        let projector = SupportProjector::new(vec![true, false, true]).with_real();
+        let output = projector.project(state.clone()).unwrap();
+        assert_eq!(
+            output,
+            vec![
+                Complex32::new(1.0, 0.0),
+                Complex32::new(0.0, 0.0),
+                Complex32::new(-3.0, 0.0)
+            ]
+        );
+
+        let projector = projector.with_nonnegative();
+        let output = projector.project(state).unwrap();
+        assert_eq!(
+            output,
+            vec![
+                Complex32::new(1.0, 0.0),
+                Complex32::new(0.0, 0.0),
+                Complex32::new(0.0, 0.0)
+            ]
+        );
+    }
+}
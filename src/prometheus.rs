@@ -0,0 +1,285 @@
+use crate::{errors::Error, Result};
+use ::prometheus::{GaugeVec, Opts, Registry, TextEncoder};
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    ops::ControlFlow,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+// How long a scrape may take to send its request, and how much of it is read;
+// a client that stalls or streams headers only holds up its own connection.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_REQUEST: u64 = 8 << 10;
+
+// Live gauges for the solves currently running in a process, one series per
+// solve ID: `drs_solve_step`, `drs_solve_delta`, `drs_solve_restarts` and
+// `drs_solve_eta_seconds`. A solve's series disappear once its last
+// `LiveSolve` handle is dropped, so the endpoint only ever lists active runs.
+#[derive(Clone)]
+pub struct LiveSolves {
+    registry: Registry,
+    gauges: Gauges,
+}
+
+#[derive(Clone)]
+struct Gauges {
+    step: GaugeVec,
+    delta: GaugeVec,
+    restarts: GaugeVec,
+    eta: GaugeVec,
+}
+
+impl LiveSolves {
+    pub fn new() -> Result<Self> {
+        Self::with_registry(Registry::new())
+    }
+
+    // Registers the gauges in an existing registry, alongside the
+    // application's own metrics.
+    pub fn with_registry(registry: Registry) -> Result<Self> {
+        let gauge = |name: &str, help: &str| -> Result<GaugeVec> {
+            let gauge = GaugeVec::new(Opts::new(name, help), &["solve_id"]).map_err(unknown)?;
+            registry
+                .register(Box::new(gauge.clone()))
+                .map_err(unknown)?;
+            Ok(gauge)
+        };
+        let gauges = Gauges {
+            step: gauge("drs_solve_step", "Last step finished by the solve.")?,
+            delta: gauge("drs_solve_delta", "Delta of the last step.")?,
+            restarts: gauge("drs_solve_restarts", "Restarts of the solve so far.")?,
+            eta: gauge(
+                "drs_solve_eta_seconds",
+                "Estimated seconds until delta reaches epsilon.",
+            )?,
+        };
+
+        Ok(Self { registry, gauges })
+    }
+
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+
+    // Starts tracking a solve that stops once delta falls below `epsilon`, or
+    // after `n_steps`; both bound the ETA.
+    pub fn track(&self, id: impl Into<String>, epsilon: f32, n_steps: usize) -> LiveSolve {
+        let id = id.into();
+        let restarts = self.gauges.restarts.with_label_values(&[id.as_str()]);
+        restarts.set(0.0);
+
+        LiveSolve {
+            inner: Arc::new(Tracked {
+                id,
+                gauges: self.gauges.clone(),
+                epsilon,
+                n_steps,
+                started: Instant::now(),
+                first_delta: Mutex::new(None),
+            }),
+        }
+    }
+
+    // The text exposition of every registered metric.
+    pub fn render(&self) -> Result<String> {
+        TextEncoder::new()
+            .encode_to_string(&self.registry.gather())
+            .map_err(unknown)
+    }
+
+    // Answers every request on `addr` with `render`, each connection on its own
+    // background thread, and returns the bound address (useful with port 0).
+    pub fn serve(&self, addr: impl ToSocketAddrs) -> Result<SocketAddr> {
+        let listener = TcpListener::bind(addr).map_err(unknown)?;
+        let addr = listener.local_addr().map_err(unknown)?;
+
+        let solves = self.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let solves = solves.clone();
+                std::thread::spawn(move || {
+                    if let Err(err) = solves.respond(stream) {
+                        tracing::warn!("failed to serve metrics: {err}");
+                    }
+                });
+            }
+        });
+
+        Ok(addr)
+    }
+
+    fn respond(&self, mut stream: TcpStream) -> Result<()> {
+        // Skip the request: every path gets the metrics.
+        stream
+            .set_read_timeout(Some(REQUEST_TIMEOUT))
+            .map_err(unknown)?;
+        let mut reader = BufReader::new((&stream).take(MAX_REQUEST));
+        let mut line = String::new();
+        while reader.read_line(&mut line).map_err(unknown)? > 2 {
+            line.clear();
+        }
+
+        let body = self.render()?;
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            ::prometheus::TEXT_FORMAT,
+            body.len(),
+        )
+        .map_err(unknown)
+    }
+}
+
+// A handle on one tracked solve. Clones share its series.
+#[derive(Clone)]
+pub struct LiveSolve {
+    inner: Arc<Tracked>,
+}
+
+struct Tracked {
+    id: String,
+    gauges: Gauges,
+    epsilon: f32,
+    n_steps: usize,
+    started: Instant,
+    first_delta: Mutex<Option<(usize, f32)>>,
+}
+
+impl LiveSolve {
+    pub fn id(&self) -> &str {
+        &self.inner.id
+    }
+
+    // Records step `t` with its delta, in the shape of
+    // `DivideAndConcurSolver::with_monitor`.
+    pub fn observe(&self, t: usize, delta: f32) {
+        let (id, gauges) = ([self.inner.id.as_str()], &self.inner.gauges);
+        gauges.step.with_label_values(&id).set(t as f64);
+        gauges.delta.with_label_values(&id).set(delta as f64);
+        gauges
+            .eta
+            .with_label_values(&id)
+            .set(self.inner.eta(t, delta));
+    }
+
+    // For callers that perturb a stalled iterate and carry on.
+    pub fn restart(&self) {
+        let id = [self.inner.id.as_str()];
+        self.inner.gauges.restarts.with_label_values(&id).inc();
+        // Convergence starts over from the perturbed state.
+        *self.inner.first_delta.lock().unwrap() = None;
+    }
+
+    // A monitor for `DivideAndConcurSolver::with_monitor` that never stops the
+    // run; the series stay up until the solver, and every other clone, is gone.
+    pub fn monitor(&self) -> impl Fn(usize, f32) -> ControlFlow<()> + Send + Sync + 'static {
+        let live = self.clone();
+        move |t, delta| {
+            live.observe(t, delta);
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+impl Tracked {
+    // Extrapolates the average rate at which log(delta) has fallen so far, capped
+    // by the steps left; with no progress yet, only the cap applies.
+    fn eta(&self, t: usize, delta: f32) -> f64 {
+        if delta <= self.epsilon {
+            return 0.0;
+        }
+
+        let mut first_delta = self.first_delta.lock().unwrap();
+        let (t0, delta0) = *first_delta.get_or_insert((t, delta));
+        let left = self.n_steps.saturating_sub(t + 1) as f64;
+        let rate = (delta0.ln() - delta.ln()) as f64 / (t - t0).max(1) as f64;
+        let steps = match rate > 0.0 {
+            true => ((delta.ln() - self.epsilon.ln()) as f64 / rate).min(left),
+            false => left,
+        };
+
+        steps * self.started.elapsed().as_secs_f64() / (t + 1) as f64
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        let id = [self.id.as_str()];
+        for gauge in [
+            &self.gauges.step,
+            &self.gauges.delta,
+            &self.gauges.restarts,
+            &self.gauges.eta,
+        ] {
+            // Absent when the solve never reported a step.
+            let _ = gauge.remove_label_values(&id);
+        }
+    }
+}
+
+fn unknown<E>(err: E) -> Error
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    Error::Unknown(Box::new(err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        norms::euclidean_contiguous,
+        projectors::{box_constraints::BoxProjector, hyperplane::HyperplaneProjector},
+        solvers::divide_and_concur::DivideAndConcurSolver,
+        states::vector::VecState,
+        Solver,
+    };
+
+    #[test]
+    fn test_track() {
+        let solves = LiveSolves::new().unwrap();
+        let live = solves.track("a", 1e-6, 1000);
+        live.observe(0, 1.0);
+        live.observe(9, 1e-3);
+        live.restart();
+
+        let text = solves.render().unwrap();
+        assert!(text.contains("drs_solve_step{solve_id=\"a\"} 9"));
+        assert!(text.contains("drs_solve_delta{solve_id=\"a\"} 0.001"));
+        assert!(text.contains("drs_solve_restarts{solve_id=\"a\"} 1"));
+        assert!(text.contains("drs_solve_eta_seconds{solve_id=\"a\"}"));
+
+        drop(live);
+        assert!(!solves.render().unwrap().contains("solve_id=\"a\""));
+    }
+
+    #[test]
+    fn test_serve() {
+        let solves = LiveSolves::new().unwrap();
+        let live = solves.track("box", 1e-6, 1000);
+        let solver = DivideAndConcurSolver::new(
+            BoxProjector::new(0.0, 1.0),
+            HyperplaneProjector::new(vec![1.0, 1.0], 1.5),
+            euclidean_contiguous,
+            0.8,
+            1e-6,
+            1000,
+        )
+        .with_monitor(live.monitor());
+        let (_, steps, _) = solver.run(VecState(vec![2.0, -1.0])).unwrap();
+
+        let addr = solves.serve("127.0.0.1:0").unwrap();
+        // A client that never finishes its request doesn't hold up the next.
+        let _stalled = TcpStream::connect(addr).unwrap();
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(b"GET /metrics HTTP/1.1\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains(&format!("drs_solve_step{{solve_id=\"box\"}} {steps}")));
+        assert!(response.contains("drs_solve_eta_seconds{solve_id=\"box\"} 0"));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{Projector, Result, Scalar};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct L1Prox<T = f32> {
+    lambda: T,
+}
+
+impl<T: Scalar> L1Prox<T> {
+    pub fn new(lambda: T) -> Self {
+        Self { lambda }
+    }
+}
+
+impl<S, T> Projector<S> for L1Prox<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        state
+            .as_mut()
+            .iter_mut()
+            .for_each(|x| *x = soft_threshold(*x, self.lambda));
+        Ok(state)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SquaredL2Prox<T = f32> {
+    lambda: T,
+}
+
+impl<T: Scalar> SquaredL2Prox<T> {
+    pub fn new(lambda: T) -> Self {
+        Self { lambda }
+    }
+}
+
+impl<S, T> Projector<S> for SquaredL2Prox<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let scale = T::ONE / (T::ONE + self.lambda);
+        state.as_mut().iter_mut().for_each(|x| *x *= scale);
+        Ok(state)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ElasticNetProx<T = f32> {
+    l1: T,
+    l2: T,
+}
+
+impl<T: Scalar> ElasticNetProx<T> {
+    pub fn new(l1: T, l2: T) -> Self {
+        Self { l1, l2 }
+    }
+}
+
+impl<S, T> Projector<S> for ElasticNetProx<T>
+where
+    S: AsMut<[T]>,
+    T: Scalar,
+{
+    fn project(&self, mut state: S) -> Result<S> {
+        let scale = T::ONE / (T::ONE + self.l2);
+        state
+            .as_mut()
+            .iter_mut()
+            .for_each(|x| *x = soft_threshold(*x, self.l1) * scale);
+        Ok(state)
+    }
+}
+
+// The prox of a set's indicator function is the projection onto that set.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Indicator<P>(pub P);
+
+impl<S, P> Projector<S> for Indicator<P>
+where
+    P: Projector<S>,
+{
+    fn project(&self, state: S) -> Result<S> {
+        self.0.project(state)
+    }
+}
+
+pub fn soft_threshold<T: Scalar>(x: T, lambda: T) -> T {
+    x.signum() * (x.abs() - lambda).max(T::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projectors::box_constraints::BoxProjector;
+
+    #[test]
+    fn test_l1_prox() {
+        let prox = L1Prox::new(1.0);
+        let output = prox.project(vec![-3.0, -0.5, 0.0, 0.5, 3.0]).unwrap();
+        assert_eq!(output, vec![-2.0, 0.0, 0.0, 0.0, 2.0]);
+
+        let prox = L1Prox::new(0.25f64);
+        let output = prox.project(vec![-1.0, 0.1, 3.0]).unwrap();
+        assert_eq!(output, vec![-0.75, 0.0, 2.75]);
+    }
+
+    #[test]
+    fn test_squared_l2_prox() {
+        let prox = SquaredL2Prox::new(1.0);
+        let output = prox.project(vec![-2.0, 4.0]).unwrap();
+        assert_eq!(output, vec![-1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_elastic_net_prox() {
+        let prox = ElasticNetProx::new(1.0, 1.0);
+        let output = prox.project(vec![-3.0, 0.5, 5.0]).unwrap();
+        assert_eq!(output, vec![-1.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn test_indicator() {
+        let prox = Indicator(BoxProjector::new(0.0, 1.0));
+        let output = prox.project(vec![-1.0, 0.5, 2.0]).unwrap();
+        assert_eq!(output, vec![0.0, 0.5, 1.0]);
+    }
+}
@@ -0,0 +1,213 @@
+use crate::{
+    errors::Error,
+    norms::euclidean,
+    problem::{ConstraintProblem, Variable},
+    problems::{rng::SplitMix64, sat::SatProblem, sudoku::Sudoku},
+    schema::{ProblemSpec, ProjectorSpec},
+    solvers::divide_and_concur::DivideAndConcurSolver,
+    states::vector::VecState,
+    Projector, Result, Solver,
+};
+use extendr_api::prelude::*;
+
+// R entry points. Building them needs an R installation, as `extendr-api`
+// links against libR; an R package's `src/rust` crate depends on `drs` with
+// this feature and calls `R_init_drs_extendr` from its C entry point. States
+// cross as numeric vectors, and variables are numbered from 1 as in R.
+
+// An R function as a projector: it gets the block as a numeric vector and
+// must return one of the same length. R is single-threaded, so these only run
+// on the thread R called into; don't combine them with the `rayon` feature,
+// whose parallel divide would call them from worker threads.
+struct RClosure(Function);
+
+// SAFETY: the solvers here run on R's thread and never move projectors to
+// another one; see above.
+unsafe impl Send for RClosure {}
+unsafe impl Sync for RClosure {}
+
+impl Projector<VecState> for RClosure {
+    fn project(&self, state: VecState) -> Result<VecState> {
+        let values: Vec<f64> = state.iter().map(|&x| x as f64).collect();
+        let projected = self
+            .0
+            .call(pairlist!(values))
+            .map_err(|err| Error::Projection(err.to_string().into()))?;
+
+        match projected.as_real_slice() {
+            Some(values) => Ok(VecState(values.iter().map(|&x| x as f32).collect())),
+            None => Err(Error::Projection(
+                format!(
+                    "expected a numeric vector from the R projector, got {:?}",
+                    projected.rtype()
+                )
+                .into(),
+            )),
+        }
+    }
+}
+
+#[extendr]
+#[derive(Debug, Default)]
+pub struct RProblem {
+    problem: ConstraintProblem,
+}
+
+#[extendr]
+impl RProblem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Starts from the constraints of a JSON problem description; its solver
+    // settings and initial values are not kept.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let problem = ProblemSpec::from_json(json)?.build()?;
+        Ok(Self { problem })
+    }
+
+    // Adds `n` variables and returns their indices.
+    pub fn variables(&mut self, n: usize) -> Vec<i32> {
+        self.problem
+            .variables(n)
+            .iter()
+            .map(|v| v.index() as i32 + 1)
+            .collect()
+    }
+
+    pub fn n_variables(&self) -> usize {
+        self.problem.n_variables()
+    }
+
+    pub fn n_constraints(&self) -> usize {
+        self.problem.n_constraints()
+    }
+
+    // Constrains `variables` with an R function.
+    pub fn constrain(&mut self, variables: Vec<i32>, projector: Function, weight: f64) {
+        self.problem.constrain_weighted(
+            &to_variables(&variables),
+            RClosure(projector),
+            weight as f32,
+        );
+    }
+
+    // Constrains `variables` with a built-in projector, given as the JSON of
+    // one `ProjectorSpec`, such as `{"type": "box", "lower": 0, "upper": 1}`.
+    pub fn constrain_spec(&mut self, variables: Vec<i32>, spec: &str, weight: f64) -> Result<()> {
+        let spec: ProjectorSpec =
+            serde_json::from_str(spec).map_err(|err| Error::Parse(err.line(), err.to_string()))?;
+        let projector = spec.projector(variables.len())?;
+        self.problem
+            .constrain_weighted(&to_variables(&variables), projector, weight as f32);
+        Ok(())
+    }
+
+    // Solves from `initial` and returns the consensus values with the run's
+    // steps and final delta.
+    pub fn solve(
+        &self,
+        initial: Vec<f64>,
+        beta: f64,
+        epsilon: f64,
+        n_steps: usize,
+    ) -> Result<List> {
+        let initial: Vec<f32> = initial.iter().map(|&x| x as f32).collect();
+        let state = self.problem.initial_state(&initial)?;
+        let solver = self.problem.solver(beta as f32, epsilon as f32, n_steps);
+        let (state, steps, delta) = solver.run(state)?;
+
+        Ok(list!(
+            values = self.problem.decode(&state)?,
+            steps = steps,
+            delta = delta
+        ))
+    }
+}
+
+// Divide and concur on one numeric vector, with both projectors in R.
+#[extendr]
+fn divide_and_concur(
+    initial: Vec<f64>,
+    divide: Function,
+    concur: Function,
+    beta: f64,
+    epsilon: f64,
+    n_steps: usize,
+) -> Result<List> {
+    let solver = DivideAndConcurSolver::new(
+        RClosure(divide),
+        RClosure(concur),
+        euclidean::<VecState>,
+        beta as f32,
+        epsilon as f32,
+        n_steps,
+    );
+    let (state, steps, delta) =
+        solver.run(VecState(initial.iter().map(|&x| x as f32).collect()))?;
+
+    Ok(list!(values = state.0, steps = steps, delta = delta))
+}
+
+// Solves a DIMACS CNF formula from a seeded start in [-1, 1].
+#[extendr]
+fn solve_sat(dimacs: &str, beta: f64, epsilon: f64, n_steps: usize, seed: u64) -> Result<List> {
+    let problem = SatProblem::from_dimacs(dimacs)?;
+    let mut rng = SplitMix64::new(seed);
+    let values: Vec<f32> = (0..problem.cnf().n_variables())
+        .map(|_| 2.0 * rng.uniform() - 1.0)
+        .collect();
+    let state = problem.initial_state(&values)?;
+    let solver = problem.solver(beta as f32, epsilon as f32, n_steps);
+    let (state, steps, delta) = solver.run(state)?;
+
+    let assignment = problem.decode(&state)?;
+    Ok(list!(
+        unsatisfied = problem.cnf().unsatisfied(&assignment),
+        assignment = assignment,
+        steps = steps,
+        delta = delta
+    ))
+}
+
+// Solves a sudoku in `Sudoku::parse`'s format; the grid comes back row-major.
+#[extendr]
+fn solve_sudoku(puzzle: &str, beta: f64, epsilon: f64, n_steps: usize, seed: u64) -> Result<List> {
+    let puzzle = Sudoku::parse(puzzle)?;
+    let mut rng = SplitMix64::new(seed);
+    let state = puzzle.initial_state(|| rng.uniform());
+    let solver = Sudoku::solver(beta as f32, epsilon as f32, n_steps);
+    let (state, steps, delta) = solver.run(state)?;
+
+    let solution = state.solution();
+    Ok(list!(
+        grid = solution
+            .cells()
+            .iter()
+            .map(|&v| v as i32)
+            .collect::<Vec<_>>(),
+        violations = solution.violations(&puzzle),
+        steps = steps,
+        delta = delta
+    ))
+}
+
+fn to_variables(indices: &[i32]) -> Vec<Variable> {
+    indices
+        .iter()
+        .map(|&i| {
+            if i < 1 {
+                panic!("invalid variable: expected an index from 1, got {i}");
+            }
+            Variable((i - 1) as usize)
+        })
+        .collect()
+}
+
+extendr_module! {
+    mod drs;
+    impl RProblem;
+    fn divide_and_concur;
+    fn solve_sat;
+    fn solve_sudoku;
+}
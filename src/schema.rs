@@ -0,0 +1,417 @@
+use crate::{
+    errors::Error,
+    problem::{ConstraintProblem, ProblemState},
+    projectors::{
+        ball::{L2BallProjector, SphereProjector},
+        binary::BinaryProjector,
+        box_constraints::BoxProjector,
+        doubly_stochastic::DoublyStochasticProjector,
+        halfspace::HalfspaceProjector,
+        hyperplane::HyperplaneProjector,
+        integer::IntegerProjector,
+        nonnegative::NonNegativeProjector,
+        one_hot::OneHotProjector,
+        permutation::PermutationProjector,
+        simplex::SimplexProjector,
+        soc::SocProjector,
+        sparsity::SparsityProjector,
+        support::SupportProjector,
+    },
+    states::vector::VecState,
+    Projector, Result,
+};
+use serde::{Deserialize, Serialize};
+
+// A product-space feasibility problem as JSON, for pipelines outside Rust:
+//
+//     {
+//       "variables": 3,
+//       "constraints": [
+//         {"variables": [0, 1], "projector": {"type": "hyperplane", "a": [1, 1], "b": 1}},
+//         {"variables": [0, 1, 2], "projector": {"type": "box", "lower": 0, "upper": 1}, "weight": 2}
+//       ],
+//       "solver": {"beta": 1.0, "epsilon": 1e-6, "n_steps": 1000},
+//       "initial": [0.9, 0.9, 0.9]
+//     }
+//
+// `solver`, `initial` and each `weight` are optional.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProblemSpec {
+    pub variables: usize,
+    pub constraints: Vec<ConstraintSpec>,
+    #[serde(default)]
+    pub solver: SolverSpec,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial: Option<Vec<f32>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConstraintSpec {
+    pub variables: Vec<usize>,
+    pub projector: ProjectorSpec,
+    #[serde(default = "unit_weight")]
+    pub weight: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SolverSpec {
+    pub beta: f32,
+    pub epsilon: f32,
+    pub n_steps: usize,
+}
+
+impl Default for SolverSpec {
+    fn default() -> Self {
+        Self {
+            beta: 1.0,
+            epsilon: 1e-6,
+            n_steps: 1000,
+        }
+    }
+}
+
+// The built-in projectors, tagged by `type`. Each acts on the constraint's
+// variables, in the order listed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ProjectorSpec {
+    Ball {
+        radius: f32,
+    },
+    // Signs in {-1, 1}, or bits in {0, 1} with `bits`.
+    Binary {
+        #[serde(default)]
+        bits: bool,
+    },
+    Box {
+        lower: f32,
+        upper: f32,
+    },
+    // Over an n x n matrix in row-major order.
+    DoublyStochastic {
+        n: usize,
+    },
+    Halfspace {
+        a: Vec<f32>,
+        b: f32,
+    },
+    Hyperplane {
+        a: Vec<f32>,
+        b: f32,
+    },
+    Integer {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        lower: Option<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        upper: Option<f32>,
+    },
+    Nonnegative,
+    OneHot,
+    // Over an n x n matrix in row-major order.
+    Permutation {
+        n: usize,
+    },
+    #[cfg(any(feature = "nalgebra", feature = "faer"))]
+    Psd {
+        n: usize,
+    },
+    #[cfg(any(feature = "nalgebra", feature = "faer"))]
+    Rank {
+        k: usize,
+        nrows: usize,
+        ncols: usize,
+    },
+    SecondOrderCone,
+    Simplex {
+        radius: f32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        k: Option<usize>,
+    },
+    Sparsity {
+        k: usize,
+    },
+    Sphere {
+        radius: f32,
+    },
+    Support {
+        mask: Vec<bool>,
+    },
+}
+
+impl ProjectorSpec {
+    // The projector for a constraint over `n` variables.
+    pub fn projector(&self, n: usize) -> Result<Box<dyn Projector<VecState> + Send + Sync>> {
+        let invalid = |message: String| Err(Error::Schema(message));
+        let expect_len = |len: usize| match len == n {
+            true => Ok(()),
+            false => Err(Error::Dimension(n, len)),
+        };
+        let area = |nrows: usize, ncols: usize| {
+            nrows.checked_mul(ncols).ok_or_else(|| {
+                Error::Schema(format!(
+                    "expected a matrix that fits, got {nrows} x {ncols}"
+                ))
+            })
+        };
+        // JSON has no infinities, but numbers past f32::MAX parse to them.
+        if let Some(x) = self.parameters().find(|x| !x.is_finite()) {
+            return invalid(format!("expected finite parameters, got {x}"));
+        }
+
+        Ok(match self {
+            ProjectorSpec::Ball { radius } | ProjectorSpec::Sphere { radius } if *radius < 0.0 => {
+                return invalid(format!("expected non-negative radius, got {radius}"));
+            }
+            ProjectorSpec::Ball { radius } => Box::new(L2BallProjector::new(*radius)),
+            ProjectorSpec::Binary { bits: true } => Box::new(BinaryProjector::bits()),
+            ProjectorSpec::Binary { bits: false } => Box::new(BinaryProjector::signs()),
+            ProjectorSpec::Box { lower, upper } => {
+                if lower > upper {
+                    return invalid(format!("expected lower <= upper, got {lower} and {upper}"));
+                }
+                Box::new(BoxProjector::new(*lower, *upper))
+            }
+            ProjectorSpec::DoublyStochastic { n: size } => {
+                expect_len(area(*size, *size)?)?;
+                Box::new(DoublyStochasticProjector::new(*size))
+            }
+            ProjectorSpec::Halfspace { a, .. } | ProjectorSpec::Hyperplane { a, .. }
+                if a.iter().all(|&x| x == 0.0) =>
+            {
+                return invalid("expected a non-zero normal vector".into());
+            }
+            ProjectorSpec::Halfspace { a, b } => {
+                expect_len(a.len())?;
+                Box::new(HalfspaceProjector::new(a.clone(), *b))
+            }
+            ProjectorSpec::Hyperplane { a, b } => {
+                expect_len(a.len())?;
+                Box::new(HyperplaneProjector::new(a.clone(), *b))
+            }
+            ProjectorSpec::Integer {
+                lower: None,
+                upper: None,
+            } => Box::new(IntegerProjector::new()),
+            ProjectorSpec::Integer { lower, upper } => {
+                let lower = lower.unwrap_or(f32::NEG_INFINITY);
+                let upper = upper.unwrap_or(f32::INFINITY);
+                if lower.ceil() > upper.floor() {
+                    return invalid(format!("expected an integer between {lower} and {upper}"));
+                }
+                Box::new(IntegerProjector::bounded(lower, upper))
+            }
+            ProjectorSpec::Nonnegative => Box::new(NonNegativeProjector::new()),
+            ProjectorSpec::OneHot => Box::new(OneHotProjector::new()),
+            ProjectorSpec::Permutation { n: size } => {
+                expect_len(area(*size, *size)?)?;
+                Box::new(PermutationProjector::new(*size))
+            }
+            #[cfg(any(feature = "nalgebra", feature = "faer"))]
+            ProjectorSpec::Psd { n: size } => {
+                expect_len(area(*size, *size)?)?;
+                Box::new(crate::projectors::psd::PsdProjector::new(*size))
+            }
+            #[cfg(any(feature = "nalgebra", feature = "faer"))]
+            ProjectorSpec::Rank { k, nrows, ncols } => {
+                expect_len(area(*nrows, *ncols)?)?;
+                Box::new(crate::projectors::rank::RankProjector::new(
+                    *k, *nrows, *ncols,
+                ))
+            }
+            ProjectorSpec::SecondOrderCone => Box::new(SocProjector::new()),
+            ProjectorSpec::Simplex { radius, k } => {
+                if *radius <= 0.0 || *k == Some(0) {
+                    return invalid(format!(
+                        "expected a positive radius and k, got {radius} and {k:?}"
+                    ));
+                }
+                match k {
+                    Some(k) => Box::new(SimplexProjector::sparse(*radius, *k)),
+                    None => Box::new(SimplexProjector::new(*radius)),
+                }
+            }
+            ProjectorSpec::Sparsity { k } => Box::new(SparsityProjector::new(*k)),
+            ProjectorSpec::Sphere { radius } => Box::new(SphereProjector::new(*radius)),
+            ProjectorSpec::Support { mask } => {
+                expect_len(mask.len())?;
+                Box::new(SupportProjector::<f32>::new(mask.clone()))
+            }
+        })
+    }
+
+    // Every number the projector is built from.
+    fn parameters(&self) -> impl Iterator<Item = f32> + '_ {
+        let (vector, scalars): (&[f32], [Option<f32>; 2]) = match self {
+            ProjectorSpec::Ball { radius }
+            | ProjectorSpec::Sphere { radius }
+            | ProjectorSpec::Simplex { radius, .. } => (&[], [Some(*radius), None]),
+            ProjectorSpec::Box { lower, upper } => (&[], [Some(*lower), Some(*upper)]),
+            ProjectorSpec::Halfspace { a, b } | ProjectorSpec::Hyperplane { a, b } => {
+                (a, [Some(*b), None])
+            }
+            ProjectorSpec::Integer { lower, upper } => (&[], [*lower, *upper]),
+            _ => (&[], [None, None]),
+        };
+        vector.iter().copied().chain(scalars.into_iter().flatten())
+    }
+}
+
+fn unit_weight() -> f32 {
+    1.0
+}
+
+impl ProblemSpec {
+    pub fn from_json(input: &str) -> Result<Self> {
+        serde_json::from_str(input).map_err(|err| Error::Parse(err.line(), err.to_string()))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("serializable problem")
+    }
+
+    // Checks every constraint against the problem and its projector's
+    // parameters, so a bad description is an error here rather than a panic.
+    pub fn build(&self) -> Result<ConstraintProblem> {
+        let mut problem = ConstraintProblem::new();
+        let variables = problem.variables(self.variables);
+
+        for (i, constraint) in self.constraints.iter().enumerate() {
+            let invalid = |message: String| Error::Schema(format!("constraints[{i}]: {message}"));
+            if constraint.variables.is_empty() {
+                return Err(invalid("expected at least one variable".into()));
+            }
+            if let Some(&j) = constraint.variables.iter().find(|&&j| j >= self.variables) {
+                return Err(invalid(format!(
+                    "expected variable index below {}, got {j}",
+                    self.variables
+                )));
+            }
+            if !constraint.weight.is_finite() || constraint.weight <= 0.0 {
+                return Err(invalid(format!(
+                    "expected a positive, finite weight, got {}",
+                    constraint.weight
+                )));
+            }
+
+            let projector = match constraint.projector.projector(constraint.variables.len()) {
+                Err(Error::Schema(message)) => return Err(invalid(message)),
+                other => other?,
+            };
+            let scope: Vec<_> = constraint.variables.iter().map(|&j| variables[j]).collect();
+            problem.constrain_weighted(&scope, projector, constraint.weight);
+        }
+
+        Ok(problem)
+    }
+
+    // From `initial`, or zeros when it is absent.
+    pub fn initial_state(&self, problem: &ConstraintProblem) -> Result<ProblemState> {
+        match &self.initial {
+            Some(values) => problem.initial_state(values),
+            None => problem.initial_state(&vec![0.0; self.variables]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Solver;
+
+    // The system of `problem::tests::test_solve`.
+    const PROBLEM: &str = r#"{
+        "variables": 3,
+        "constraints": [
+            {"variables": [0, 1], "projector": {"type": "hyperplane", "a": [1, 1], "b": 1}},
+            {"variables": [1, 2], "projector": {"type": "hyperplane", "a": [1, -1], "b": 0.5}},
+            {"variables": [0, 1, 2], "projector": {"type": "box", "lower": 0, "upper": 1}}
+        ],
+        "solver": {"epsilon": 1e-6},
+        "initial": [0.9, 0.9, 0.9]
+    }"#;
+
+    #[test]
+    fn test_load() {
+        let spec = ProblemSpec::from_json(PROBLEM).unwrap();
+        assert_eq!(spec.solver.n_steps, 1000);
+        assert_eq!(spec.constraints[2].weight, 1.0);
+        assert_eq!(ProblemSpec::from_json(&spec.to_json()).unwrap(), spec);
+
+        let problem = spec.build().unwrap();
+        assert_eq!(problem.n_constraints(), 3);
+        let SolverSpec {
+            beta,
+            epsilon,
+            n_steps,
+        } = spec.solver;
+        let state = spec.initial_state(&problem).unwrap();
+        let (state, _, _) = problem.solver(beta, epsilon, n_steps).run(state).unwrap();
+
+        let x = problem.decode(&state).unwrap();
+        assert!((x[0] + x[1] - 1.0).abs() < 1e-3);
+        assert!((x[1] - x[2] - 0.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_errors() {
+        let line = "{\n  \"variables\": 2,\n  \"constraints\": [{\"type\": 1}]\n}";
+        assert!(matches!(
+            ProblemSpec::from_json(line),
+            Err(Error::Parse(3, _))
+        ));
+
+        let spec = |projector: &str, variables: &str| {
+            ProblemSpec::from_json(&format!(
+                r#"{{"variables": 2, "constraints": [
+                    {{"variables": {variables}, "projector": {projector}}}
+                ]}}"#
+            ))
+            .unwrap()
+            .build()
+        };
+        assert!(matches!(
+            spec(r#"{"type": "box", "lower": 1, "upper": 0}"#, "[0]"),
+            Err(Error::Schema(message)) if message.starts_with("constraints[0]")
+        ));
+        assert!(matches!(
+            spec(r#"{"type": "nonnegative"}"#, "[0, 2]"),
+            Err(Error::Schema(_))
+        ));
+        assert!(matches!(
+            spec(r#"{"type": "hyperplane", "a": [1], "b": 0}"#, "[0, 1]"),
+            Err(Error::Dimension(2, 1))
+        ));
+
+        // 1e39 is past f32::MAX and parses to infinity.
+        for projector in [
+            r#"{"type": "box", "lower": 0, "upper": 1e39}"#,
+            r#"{"type": "hyperplane", "a": [1e39], "b": 0}"#,
+            r#"{"type": "ball", "radius": 1e39}"#,
+            r#"{"type": "integer", "lower": -1e39}"#,
+        ] {
+            assert!(
+                matches!(spec(projector, "[0]"), Err(Error::Schema(_))),
+                "{projector}"
+            );
+        }
+        for projector in [
+            r#"{"type": "permutation", "n": 4294967296}"#,
+            r#"{"type": "doubly_stochastic", "n": 18446744073709551615}"#,
+        ] {
+            assert!(
+                matches!(spec(projector, "[0]"), Err(Error::Schema(_))),
+                "{projector}"
+            );
+        }
+        let weighted = ProblemSpec::from_json(
+            r#"{"variables": 1, "constraints": [
+                {"variables": [0], "projector": {"type": "nonnegative"}, "weight": 1e39}
+            ]}"#,
+        )
+        .unwrap();
+        assert!(matches!(weighted.build(), Err(Error::Schema(_))));
+    }
+}
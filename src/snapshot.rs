@@ -0,0 +1,300 @@
+use crate::{
+    errors::Error,
+    states::{
+        array::ArrayState,
+        double::{ArrayState64, VecState64},
+        product_space::ProductSpace,
+        product_state::ProductState,
+        vector::VecState,
+    },
+    Result,
+};
+use alloc::{format, vec::Vec};
+
+// Byte-level save/restore of a state, shared by checkpointing and history
+// recording. Built-in states use a raw little-endian layout; user states can
+// delegate to a wrapped state or, with the `serde` feature, go through JSON
+// (see `state_snapshot!`).
+pub trait StateSnapshot: Sized {
+    fn snapshot(&self) -> Vec<u8>;
+
+    fn restore(bytes: &[u8]) -> Result<Self>;
+}
+
+// Implements `StateSnapshot` for a single-field tuple struct by delegating to the
+// wrapped state, or through `serde_json` with the `serde` feature:
+//
+//     state_snapshot!(MyState);
+//     state_snapshot!(serde MyOtherState);
+#[macro_export]
+macro_rules! state_snapshot {
+    (serde $ty:ty) => {
+        impl $crate::snapshot::StateSnapshot for $ty {
+            fn snapshot(&self) -> Vec<u8> {
+                $crate::snapshot::__private::serde_json::to_vec(self).expect("serializable state")
+            }
+
+            fn restore(bytes: &[u8]) -> $crate::Result<Self> {
+                $crate::snapshot::__private::serde_json::from_slice(bytes)
+                    .map_err(|err| $crate::errors::Error::Snapshot(err.to_string()))
+            }
+        }
+    };
+    ($ty:ty) => {
+        impl $crate::snapshot::StateSnapshot for $ty {
+            fn snapshot(&self) -> Vec<u8> {
+                $crate::snapshot::StateSnapshot::snapshot(&self.0)
+            }
+
+            fn restore(bytes: &[u8]) -> $crate::Result<Self> {
+                $crate::snapshot::StateSnapshot::restore(bytes).map(Self)
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "serde")]
+    pub use serde_json;
+}
+
+pub(crate) fn write_len(bytes: &mut Vec<u8>, len: usize) {
+    bytes.extend_from_slice(&(len as u64).to_le_bytes());
+}
+
+pub(crate) fn read_len(bytes: &[u8]) -> Result<(usize, &[u8])> {
+    if bytes.len() < 8 {
+        return Err(Error::Snapshot(format!(
+            "expected a length prefix, got {} bytes",
+            bytes.len()
+        )));
+    }
+
+    let (head, tail) = bytes.split_at(8);
+    let len = u64::from_le_bytes(head.try_into().unwrap()) as usize;
+    Ok((len, tail))
+}
+
+fn encode<const W: usize, T>(values: &[T], to_bytes: impl Fn(&T) -> [u8; W]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(8 + W * values.len());
+    write_len(&mut bytes, values.len());
+    for value in values {
+        bytes.extend_from_slice(&to_bytes(value));
+    }
+    bytes
+}
+
+fn decode<const W: usize, T>(bytes: &[u8], from_bytes: impl Fn([u8; W]) -> T) -> Result<Vec<T>> {
+    let (len, body) = read_len(bytes)?;
+    let size = len
+        .checked_mul(W)
+        .ok_or_else(|| Error::Snapshot(format!("expected a length that fits, got {len}")))?;
+    if body.len() != size {
+        return Err(Error::Snapshot(format!(
+            "expected {size} bytes of values, got {}",
+            body.len()
+        )));
+    }
+
+    Ok(body
+        .chunks_exact(W)
+        .map(|chunk| from_bytes(chunk.try_into().unwrap()))
+        .collect())
+}
+
+fn decode_array<const W: usize, const N: usize, T>(
+    bytes: &[u8],
+    from_bytes: impl Fn([u8; W]) -> T,
+) -> Result<[T; N]> {
+    decode(bytes, from_bytes)?
+        .try_into()
+        .map_err(|values: Vec<T>| Error::Dimension(N, values.len()))
+}
+
+impl StateSnapshot for VecState {
+    fn snapshot(&self) -> Vec<u8> {
+        encode(&self.0, |x| x.to_le_bytes())
+    }
+
+    fn restore(bytes: &[u8]) -> Result<Self> {
+        decode(bytes, f32::from_le_bytes).map(Self)
+    }
+}
+
+impl StateSnapshot for VecState64 {
+    fn snapshot(&self) -> Vec<u8> {
+        encode(&self.0, |x| x.to_le_bytes())
+    }
+
+    fn restore(bytes: &[u8]) -> Result<Self> {
+        decode(bytes, f64::from_le_bytes).map(Self)
+    }
+}
+
+impl<const N: usize> StateSnapshot for ArrayState<N> {
+    fn snapshot(&self) -> Vec<u8> {
+        encode(&self.0, |x| x.to_le_bytes())
+    }
+
+    fn restore(bytes: &[u8]) -> Result<Self> {
+        decode_array(bytes, f32::from_le_bytes).map(Self)
+    }
+}
+
+impl<const N: usize> StateSnapshot for ArrayState64<N> {
+    fn snapshot(&self) -> Vec<u8> {
+        encode(&self.0, |x| x.to_le_bytes())
+    }
+
+    fn restore(bytes: &[u8]) -> Result<Self> {
+        decode_array(bytes, f64::from_le_bytes).map(Self)
+    }
+}
+
+// Each part is stored with its byte length so variable-size states can nest.
+pub(crate) fn write_part(bytes: &mut Vec<u8>, part: &[u8]) {
+    write_len(bytes, part.len());
+    bytes.extend_from_slice(part);
+}
+
+pub(crate) fn read_part(bytes: &[u8]) -> Result<(&[u8], &[u8])> {
+    let (len, rest) = read_len(bytes)?;
+    if rest.len() < len {
+        return Err(Error::Snapshot(format!(
+            "expected {} bytes, got {}",
+            len,
+            rest.len()
+        )));
+    }
+
+    Ok(rest.split_at(len))
+}
+
+pub(crate) fn read_end(bytes: &[u8]) -> Result<()> {
+    if !bytes.is_empty() {
+        return Err(Error::Snapshot(format!(
+            "expected no trailing bytes, got {}",
+            bytes.len()
+        )));
+    }
+    Ok(())
+}
+
+impl<S> StateSnapshot for ProductSpace<S>
+where
+    S: StateSnapshot,
+{
+    fn snapshot(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        write_len(&mut bytes, self.copies.len());
+        for copy in &self.copies {
+            write_part(&mut bytes, &copy.snapshot());
+        }
+        bytes
+    }
+
+    fn restore(bytes: &[u8]) -> Result<Self> {
+        // `n` comes from the bytes, so only reserve what they could hold: every
+        // copy takes at least its 8-byte length.
+        let (n, mut rest) = read_len(bytes)?;
+        let mut copies = Vec::with_capacity(n.min(rest.len() / 8));
+        for _ in 0..n {
+            let (part, tail) = read_part(rest)?;
+            copies.push(S::restore(part)?);
+            rest = tail;
+        }
+        read_end(rest)?;
+
+        Ok(Self { copies })
+    }
+}
+
+impl<A, B> StateSnapshot for ProductState<A, B>
+where
+    A: StateSnapshot,
+    B: StateSnapshot,
+{
+    fn snapshot(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        write_part(&mut bytes, &self.0.snapshot());
+        write_part(&mut bytes, &self.1.snapshot());
+        bytes
+    }
+
+    fn restore(bytes: &[u8]) -> Result<Self> {
+        let (a, rest) = read_part(bytes)?;
+        let (b, rest) = read_part(rest)?;
+        read_end(rest)?;
+        Ok(Self(A::restore(a)?, B::restore(b)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Wrapper(VecState);
+
+    state_snapshot!(Wrapper);
+
+    #[test]
+    fn test_round_trip() {
+        let state = ProductSpace::new(vec![VecState(vec![1.0, -2.5]), VecState(vec![])]);
+        assert_eq!(ProductSpace::restore(&state.snapshot()).unwrap(), state);
+
+        let state = ProductState(ArrayState([0.5; 3]), VecState64(vec![1e-12]));
+        assert_eq!(ProductState::restore(&state.snapshot()).unwrap(), state);
+
+        let state = Wrapper(VecState(vec![3.0]));
+        assert_eq!(Wrapper::restore(&state.snapshot()).unwrap(), state);
+    }
+
+    #[test]
+    fn test_restore_errors() {
+        let bytes = VecState(vec![1.0, 2.0]).snapshot();
+        assert!(matches!(
+            VecState::restore(&bytes[..9]),
+            Err(Error::Snapshot(_))
+        ));
+        assert!(matches!(
+            ArrayState::<3>::restore(&bytes),
+            Err(Error::Dimension(3, 2))
+        ));
+
+        // A length whose byte count overflows, with no body.
+        let huge = (u64::MAX / 2).to_le_bytes();
+        assert!(matches!(VecState::restore(&huge), Err(Error::Snapshot(_))));
+        assert!(matches!(
+            ProductSpace::<VecState>::restore(&huge),
+            Err(Error::Snapshot(_))
+        ));
+
+        let mut bytes = ProductState(VecState(vec![1.0]), VecState(vec![])).snapshot();
+        bytes.push(0);
+        assert!(matches!(
+            ProductState::<VecState, VecState>::restore(&bytes),
+            Err(Error::Snapshot(message)) if message == "expected no trailing bytes, got 1"
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        struct Grid {
+            cells: ProductSpace<VecState>,
+            width: usize,
+        }
+
+        state_snapshot!(serde Grid);
+
+        let grid = Grid {
+            cells: ProductSpace::replicate(VecState(vec![0.25, 0.75]), 2),
+            width: 2,
+        };
+        assert_eq!(Grid::restore(&grid.snapshot()).unwrap(), grid);
+        assert!(Grid::restore(b"{}").is_err());
+    }
+}
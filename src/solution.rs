@@ -0,0 +1,86 @@
+use crate::SolverSolution;
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunStats {
+    pub steps: usize,
+    pub delta: f32,
+}
+
+// Named form of the `(state, steps, delta)` tuple `Solver::run` returns.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Solution<S> {
+    pub state: S,
+    pub stats: RunStats,
+}
+
+impl<S> From<SolverSolution<S>> for Solution<S> {
+    fn from((state, steps, delta): SolverSolution<S>) -> Self {
+        Self {
+            state,
+            stats: RunStats { steps, delta },
+        }
+    }
+}
+
+impl fmt::Display for RunStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} steps, delta={:.3e}", self.steps, self.delta)
+    }
+}
+
+impl<S: fmt::Display> fmt::Display for Solution<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.state)?;
+        write!(f, "{}", self.stats)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: crate::display::Html> Solution<S> {
+    pub fn evcxr_display(&self) {
+        crate::display::evcxr_display(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::vector::VecState;
+
+    #[test]
+    fn test_from_tuple() {
+        let solution = Solution::from((VecState(vec![1.0]), 7, 1e-3));
+        assert_eq!(solution.state, VecState(vec![1.0]));
+        assert_eq!(
+            solution.stats,
+            RunStats {
+                steps: 7,
+                delta: 1e-3
+            }
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let solution = Solution::from((VecState(vec![0.5, -0.25]), 12, 3.5e-7));
+        assert_eq!(
+            solution.to_string(),
+            "[0.5, -0.25]\n12 steps, delta=3.500e-7"
+        );
+        assert_eq!(format!("{:.2}", solution.state), "[0.50, -0.25]");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let solution = Solution::from((VecState(vec![0.5, -0.5]), 3, 0.25));
+        let json = serde_json::to_string(&solution).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Solution<VecState>>(&json).unwrap(),
+            solution
+        );
+    }
+}
@@ -1,115 +1,947 @@
-use crate::{errors::Error, Result, Solver, SolverSolution, State};
-use tracing::{event, span, Level};
+use crate::{
+    checkpoint::{Checkpoint, CheckpointSink},
+    config::SolverConfig,
+    errors::{Error, ProjectorKind},
+    history::{History, Record},
+    solvers::{guard, project, workspace::StepWorkspace},
+    tolerances::Tolerances,
+    validation::{validate, validate_coordinates, ValidationReport},
+    BoundedNorm, Feasibility, Monitor, Projector, Result, ScalarAccess, Solver, SolverSolution,
+    State,
+};
+use alloc::boxed::Box;
+use core::ops::ControlFlow;
+use tracing::{event, field, span, Level};
+
+#[cfg(feature = "argmin")]
+pub mod argmin;
+
+// Consecutive rising deltas that, past the divergence factor, end a run.
+pub const DIVERGENCE_STREAK: usize = 5;
 
 pub struct DivideAndConcurSolver<S, D, C, N>
 where
     S: State,
-    D: Fn(S) -> Result<S>,
-    C: Fn(S) -> Result<S>,
+    D: Projector<S>,
+    C: Projector<S>,
     N: Fn(&S, &S) -> f32,
 {
     divide: D,
     concur: C,
     norm: N,
+    bounded_norm: Option<BoundedNorm<S>>,
     beta: f32,
-    epsilon: f32,
+    tolerances: Tolerances,
     n_steps: usize,
-    _marker: std::marker::PhantomData<S>,
+    feasibility: Option<Feasibility<S>>,
+    divergence_factor: Option<f32>,
+    catch_panics: bool,
+    difference_map: bool,
+    checkpoints: Option<(usize, CheckpointSink<S>)>,
+    monitor: Option<Monitor>,
+    // First delta, and the current run of rising deltas, of a run driven step
+    // by step through argmin's executor.
+    #[cfg(feature = "argmin")]
+    initial_delta: f32,
+    #[cfg(feature = "argmin")]
+    rising: usize,
+    _marker: core::marker::PhantomData<S>,
 }
 
 impl<S, D, N, C> DivideAndConcurSolver<S, D, C, N>
 where
     S: State,
-    D: Fn(S) -> Result<S>,
-    C: Fn(S) -> Result<S>,
+    D: Projector<S>,
+    C: Projector<S>,
     N: Fn(&S, &S) -> f32,
 {
+    // Each step moves by `beta` times P_C(R_D(x)) - P_D(x), with R = 2P - I, and
+    // `run` returns the divide projection. Only at beta = 1 is this the same
+    // iteration as Elser's difference map; `with_difference_map` runs that one,
+    // with its gamma = -1/beta, 1/beta, for any beta.
     pub fn new(divide: D, concur: C, norm: N, beta: f32, epsilon: f32, n_steps: usize) -> Self {
         Self {
             divide,
             concur,
             norm,
+            bounded_norm: None,
             beta,
-            epsilon,
+            tolerances: Tolerances::new(epsilon),
             n_steps,
-            _marker: std::marker::PhantomData,
+            feasibility: None,
+            divergence_factor: None,
+            catch_panics: false,
+            difference_map: false,
+            checkpoints: None,
+            monitor: None,
+            #[cfg(feature = "argmin")]
+            initial_delta: f32::NAN,
+            #[cfg(feature = "argmin")]
+            rising: 0,
+            _marker: core::marker::PhantomData,
         }
     }
-}
 
-impl<S, D, N, C> Solver<S, D, C, N> for DivideAndConcurSolver<S, D, C, N>
-where
-    S: State,
-    D: Fn(S) -> Result<S>,
-    C: Fn(S) -> Result<S>,
-    N: Fn(&S, &S) -> f32,
-{
-    fn run(&self, initial_state: S) -> Result<SolverSolution<S>> {
-        let mut state = initial_state;
+    pub fn with_tolerances(mut self, tolerances: Tolerances) -> Self {
+        self.tolerances = tolerances;
+        self
+    }
+
+    pub fn with_feasibility<F>(mut self, feasibility: F) -> Self
+    where
+        F: Fn(&S) -> f32 + Send + Sync + 'static,
+    {
+        self.feasibility = Some(Box::new(feasibility));
+        self
+    }
+
+    pub fn with_catch_panics(mut self, catch_panics: bool) -> Self {
+        self.catch_panics = catch_panics;
+        self
+    }
+
+    // Steps with `difference_map_step` instead of `step_with_solution`, for
+    // settings of beta tuned to Elser's form.
+    pub fn with_difference_map(mut self, difference_map: bool) -> Self {
+        self.difference_map = difference_map;
+        self
+    }
+
+    // Stops a run with `Error::Diverged` once delta has risen for
+    // `DIVERGENCE_STREAK` steps in a row and passed `factor` times the first
+    // delta; a bump that recovers sooner does not.
+    pub fn with_divergence_factor(mut self, factor: f32) -> Self {
+        self.divergence_factor = Some(factor);
+        self
+    }
+
+    // Hands a checkpoint to `sink` before every `every`-th step of `run`.
+    pub fn with_checkpoints<F>(mut self, every: usize, sink: F) -> Self
+    where
+        F: Fn(&Checkpoint<S>) + Send + Sync + 'static,
+    {
+        if every == 0 {
+            panic!("invalid checkpoint interval: expected at least 1, got {every}");
+        }
+        self.checkpoints = Some((every, Box::new(sink)));
+        self
+    }
+
+    // Sees the step and its delta after every step of `run`; breaking stops the
+    // run with `Error::Cancelled`.
+    pub fn with_monitor<F>(mut self, monitor: F) -> Self
+    where
+        F: Fn(usize, f32) -> ControlFlow<()> + Send + Sync + 'static,
+    {
+        self.monitor = Some(Box::new(monitor));
+        self
+    }
+
+    // Measures delta with `norm(current, previous, bound)` on steps where it only
+    // decides convergence, so the norm can stop once the distance reaches
+    // `bound` and return anything at least that large. The first step, and every
+    // step while a monitor, divergence check or history reads delta, still uses
+    // the solver's norm; metrics and a `Convergence` error may see the bound.
+    pub fn with_bounded_norm<F>(mut self, norm: F) -> Self
+    where
+        F: Fn(&S, &S, f32) -> f32 + Send + Sync + 'static,
+    {
+        self.bounded_norm = Some(Box::new(norm));
+        self
+    }
+
+    pub fn config(&self) -> SolverConfig {
+        SolverConfig {
+            beta: self.beta,
+            tolerances: self.tolerances,
+            n_steps: self.n_steps,
+            divergence_factor: self.divergence_factor,
+            catch_panics: self.catch_panics,
+            difference_map: self.difference_map,
+        }
+    }
+
+    pub fn with_config(mut self, config: SolverConfig) -> Self {
+        self.beta = config.beta;
+        self.tolerances = config.tolerances;
+        self.n_steps = config.n_steps;
+        self.divergence_factor = config.divergence_factor;
+        self.catch_panics = config.catch_panics;
+        self.difference_map = config.difference_map;
+        self
+    }
+
+    // Continues a run from a checkpoint, counting steps from where it left off.
+    pub fn resume(&self, checkpoint: Checkpoint<S>) -> Result<SolverSolution<S>> {
+        let initial_delta = checkpoint.initial_delta.unwrap_or(f32::NAN);
+        self.run_from(checkpoint.state, checkpoint.step, initial_delta, None)
+    }
+
+    // Runs every instance independently and in parallel, with results in input
+    // order. Checkpoints from all of the runs go to the same sink.
+    #[cfg(feature = "rayon")]
+    pub fn run_batch(&self, states: Vec<S>) -> Vec<Result<SolverSolution<S>>>
+    where
+        Self: Sync,
+        S: Send,
+    {
+        use rayon::prelude::*;
+        crate::parallel::install(|| {
+            states
+                .into_par_iter()
+                .map(|state| self.run_from(state, 0, f32::NAN, None))
+                .collect()
+        })
+    }
+
+    // Runs like `run`, also recording every step's delta and, with a
+    // feasibility check, the violation of the candidate read off each iterate.
+    // The history covers failed runs too, up to the step they stopped at.
+    pub fn run_with_history(&self, state: S) -> (Result<SolverSolution<S>>, History) {
+        let mut history = History::new();
+        let result = self.run_from(state, 0, f32::NAN, Some(&mut history));
+        (result, history)
+    }
+
+    fn run_from(
+        &self,
+        state: S,
+        start: usize,
+        initial_delta: f32,
+        history: Option<&mut History>,
+    ) -> Result<SolverSolution<S>> {
+        let span = span!(
+            Level::INFO,
+            "divide_and_concur_run",
+            start,
+            n_steps = self.n_steps,
+            beta = self.beta,
+            steps = field::Empty,
+            delta = field::Empty,
+        );
+        let _guard = span.enter();
+
+        let result = self.run_steps(state, start, initial_delta, history);
+        #[cfg(feature = "metrics")]
+        crate::metrics::run(&result);
+        if let Ok((_, steps, delta)) = &result {
+            span.record("steps", steps);
+            span.record("delta", delta);
+        }
+        result
+    }
+
+    fn run_steps(
+        &self,
+        state: S,
+        start: usize,
+        initial_delta: f32,
+        mut history: Option<&mut History>,
+    ) -> Result<SolverSolution<S>> {
+        let divide = |s: S| guard(&self.divide, s, self.catch_panics);
+        let concur = |s: S| guard(&self.concur, s, self.catch_panics);
+
+        let mut workspace = StepWorkspace::new();
+        let mut state = state;
         let mut delta = f32::NAN;
+        let mut initial_delta = initial_delta;
+        let mut rising = 0;
+
+        for t in start..self.n_steps {
+            if let Some((every, sink)) = &self.checkpoints {
+                if t > start && t % every == 0 {
+                    sink(&Checkpoint {
+                        config: self.config(),
+                        step: t,
+                        initial_delta: Some(initial_delta).filter(|d| !d.is_nan()),
+                        state: state.clone(),
+                    });
+                }
+            }
 
-        for t in 0..self.n_steps {
             let span = span!(tracing::Level::DEBUG, "divide_and_concur_outer_step");
             let _guard = span.enter();
 
-            let update = step(state.clone(), &self.divide, &self.concur, self.beta)?;
-            delta = (self.norm)(&update, &state);
+            let (update, candidate) = self
+                .step(workspace.copy(&state), &divide, &concur, &mut workspace)
+                .map_err(|err| err.at_step(t, &state))?;
+            let previous_delta = delta;
+            delta = {
+                let _guard = span!(Level::DEBUG, "norm").entered();
+                match &self.bounded_norm {
+                    Some(bounded) if self.bound_delta(initial_delta, history.is_some()) => {
+                        bounded(&update, &state, self.tolerances.threshold(initial_delta))
+                    }
+                    _ => (self.norm)(&update, &state),
+                }
+            };
+            #[cfg(feature = "metrics")]
+            crate::metrics::step(delta);
 
             event!(Level::INFO, delta, step = t);
             event!(Level::DEBUG, ?state, ?update);
 
-            if delta < self.epsilon {
-                state = solution(state, &self.divide, &self.concur, self.beta)?;
-                return Ok((state, t, delta));
+            if let Some(history) = history.as_deref_mut() {
+                let violation = self.feasibility.as_ref().map(|f| f(&candidate));
+                history.push(Record {
+                    step: t,
+                    delta,
+                    violation,
+                });
+            }
+
+            if t == start && initial_delta.is_nan() {
+                initial_delta = delta;
+            }
+
+            if let Some(monitor) = &self.monitor {
+                if monitor(t, delta).is_break() {
+                    return Err(Error::Cancelled(t + 1));
+                }
+            }
+
+            rising = if delta > previous_delta {
+                rising + 1
+            } else {
+                0
+            };
+            if self.diverged(rising, delta, initial_delta) {
+                return Err(Error::Diverged(t, delta));
+            }
+
+            if self.tolerances.converged(delta, initial_delta) {
+                match &self.feasibility {
+                    Some(feasibility) => {
+                        let violation = feasibility(&candidate);
+                        event!(Level::INFO, violation, step = t);
+                        if self.tolerances.feasible(violation) {
+                            return Ok((candidate, t, delta));
+                        }
+                    }
+                    None => return Ok((candidate, t, delta)),
+                }
             }
 
-            state = update;
+            workspace.recycle(candidate);
+            workspace.recycle(core::mem::replace(&mut state, update));
         }
 
         Err(Error::Convergence(self.n_steps, delta))
     }
+
+    // One step of `run`, with the candidate it reads off `state`.
+    fn step<P, Q>(
+        &self,
+        state: S,
+        divide: &P,
+        concur: &Q,
+        workspace: &mut StepWorkspace<S>,
+    ) -> Result<(S, S)>
+    where
+        P: Projector<S> + ?Sized,
+        Q: Projector<S> + ?Sized,
+    {
+        if self.difference_map {
+            difference_map_step(state, divide, concur, self.beta, workspace)
+        } else {
+            step_with_solution(state, divide, concur, self.beta, workspace)
+        }
+    }
+
+    fn diverged(&self, rising: usize, delta: f32, initial_delta: f32) -> bool {
+        self.divergence_factor
+            .is_some_and(|factor| rising >= DIVERGENCE_STREAK && delta > factor * initial_delta)
+    }
+
+    fn bound_delta(&self, initial_delta: f32, history: bool) -> bool {
+        !initial_delta.is_nan()
+            && !history
+            && self.monitor.is_none()
+            && self.divergence_factor.is_none()
+    }
+
+    // The candidate solution read off an iterate, as `run` returns it.
+    pub fn solution(&self, state: S) -> Result<S> {
+        let divide = |s: S| guard(&self.divide, s, self.catch_panics);
+        if self.difference_map {
+            let concur = |s: S| guard(&self.concur, s, self.catch_panics);
+            let mut workspace = StepWorkspace::new();
+            let (_, estimate) =
+                difference_map_step(state, &divide, &concur, self.beta, &mut workspace)?;
+            return Ok(estimate);
+        }
+        candidate(state, &divide)
+    }
+
+    // Runs `validate`, and also checks that each projector's output has only
+    // finite coordinates.
+    pub fn validate_coordinates(&self, sample_state: &S) -> Result<ValidationReport>
+    where
+        S: ScalarAccess,
+    {
+        validate_coordinates(
+            &self.divide,
+            &self.concur,
+            &self.norm,
+            sample_state,
+            &self.tolerances,
+        )
+    }
+}
+
+impl<S, D, N, C> Solver<S, D, C, N> for DivideAndConcurSolver<S, D, C, N>
+where
+    S: State,
+    D: Projector<S>,
+    C: Projector<S>,
+    N: Fn(&S, &S) -> f32,
+{
+    fn run(&self, initial_state: S) -> Result<SolverSolution<S>> {
+        self.run_from(initial_state, 0, f32::NAN, None)
+    }
+
+    fn validate(&self, sample_state: &S) -> Result<ValidationReport> {
+        validate(
+            &self.divide,
+            &self.concur,
+            &self.norm,
+            sample_state,
+            &self.tolerances,
+        )
+    }
 }
 
-pub fn step<S, D, C>(state: S, divide: D, concur: C, beta: f32) -> Result<S>
+pub fn step<S, D, C>(state: S, divide: &D, concur: &C, beta: f32) -> Result<S>
 where
     S: State,
-    D: Fn(S) -> Result<S>,
-    C: Fn(S) -> Result<S>,
+    D: Projector<S> + ?Sized,
+    C: Projector<S> + ?Sized,
+{
+    step_with(state, divide, concur, beta, &mut StepWorkspace::new())
+}
+
+// Same as `step`, taking its temporaries from `workspace` and returning them
+// there.
+pub fn step_with<S, D, C>(
+    state: S,
+    divide: &D,
+    concur: &C,
+    beta: f32,
+    workspace: &mut StepWorkspace<S>,
+) -> Result<S>
+where
+    S: State,
+    D: Projector<S> + ?Sized,
+    C: Projector<S> + ?Sized,
+{
+    let (result, candidate) = step_with_solution(state, divide, concur, beta, workspace)?;
+    workspace.recycle(candidate);
+    Ok(result)
+}
+
+// Same as `step_with`, updating `state` in place. The old iterate goes back to
+// `workspace`, and `state` is left as it was if a projection fails.
+pub fn step_mut<S, D, C>(
+    state: &mut S,
+    divide: &D,
+    concur: &C,
+    beta: f32,
+    workspace: &mut StepWorkspace<S>,
+) -> Result<()>
+where
+    S: State,
+    D: Projector<S> + ?Sized,
+    C: Projector<S> + ?Sized,
+{
+    let update = step_with(workspace.copy(state), divide, concur, beta, workspace)?;
+    workspace.recycle(core::mem::replace(state, update));
+    Ok(())
+}
+
+// Same as `step_with`, also returning the candidate solution read off `state`,
+// which the step computes on the way.
+//
+// The step is the relaxed Douglas-Rachford iteration in reflection form,
+//   x + beta (P_C(R_D(x)) - P_D(x)) = (1 - beta/2) x + beta/2 R_C(R_D(x)),
+// with R = 2P - I, so each step projects once onto each set. At beta = 1 it
+// is the difference map.
+//
+// The concur projection reads the divide's output, so the two can't run
+// side by side. Under `rayon`, the block-wise divides project their blocks in
+// parallel instead: `ConstraintProblem`'s, sudoku's and the latin square's
+// `AssignmentProjector`, which is where expensive projectors spend their time.
+pub fn step_with_solution<S, D, C>(
+    state: S,
+    divide: &D,
+    concur: &C,
+    beta: f32,
+    workspace: &mut StepWorkspace<S>,
+) -> Result<(S, S)>
+where
+    S: State,
+    D: Projector<S> + ?Sized,
+    C: Projector<S> + ?Sized,
 {
     let span = span!(tracing::Level::DEBUG, "divide_and_concur_inner_step");
     let _guard = span.enter();
 
+    let pd = project(divide, ProjectorKind::Divide, workspace.copy(&state))?;
+    let rd = workspace.copy(&pd).scale(2.0).axpy_ref(-1.0, &state);
+    event!(Level::DEBUG, ?pd);
+    event!(Level::DEBUG, ?rd);
+
+    let pcrd = project(concur, ProjectorKind::Concur, rd)?;
+    event!(Level::DEBUG, ?pcrd);
+
+    let inner = pcrd.axpy_ref(-1f32, &pd);
+    event!(Level::DEBUG, ?inner);
+
+    let result = state.axpy_ref(beta, &inner);
+    event!(Level::DEBUG, ?result);
+    workspace.recycle(inner);
+
+    Ok((result, pd))
+}
+
+// The candidate solution read off an iterate of `step`: its divide projection,
+// whose fixed points lie in both sets.
+pub fn candidate<S, D>(state: S, divide: &D) -> Result<S>
+where
+    S: State,
+    D: Projector<S> + ?Sized,
+{
+    project(divide, ProjectorKind::Divide, state)
+}
+
+// Elser's difference map, as `step_with_solution` returns it: the next iterate
+//   x + beta (P_C(f_D(x)) - P_D(f_C(x))),
+//   f_D = (1 + 1/beta) P_D - x/beta,  f_C = (1 - 1/beta) P_C + x/beta,
+// and its solution estimate P_D(f_C(x)). It agrees with `step` at beta = 1,
+// and costs four projections a step rather than two.
+pub fn difference_map_step<S, D, C>(
+    state: S,
+    divide: &D,
+    concur: &C,
+    beta: f32,
+    workspace: &mut StepWorkspace<S>,
+) -> Result<(S, S)>
+where
+    S: State,
+    D: Projector<S> + ?Sized,
+    C: Projector<S> + ?Sized,
+{
+    let span = span!(
+        tracing::Level::DEBUG,
+        "divide_and_concur_difference_map_step"
+    );
+    let _guard = span.enter();
+
     let gamma_a = -1f32 / beta;
     let gamma_b = 1f32 / beta;
-    event!(Level::DEBUG, gamma_a);
-    event!(Level::DEBUG, gamma_b);
 
-    let fa = concur(state.clone())? * (1.0 + gamma_a) + state.clone() * -gamma_a;
-    let fb = divide(state.clone())? * (1.0 + gamma_b) + state.clone() * -gamma_b;
+    let fa = project(concur, ProjectorKind::Concur, workspace.copy(&state))?
+        .scale(1.0 + gamma_a)
+        .axpy_ref(-gamma_a, &state);
+    let fb = project(divide, ProjectorKind::Divide, workspace.copy(&state))?
+        .scale(1.0 + gamma_b)
+        .axpy_ref(-gamma_b, &state);
     event!(Level::DEBUG, ?fa);
     event!(Level::DEBUG, ?fb);
 
-    let pafb = concur(fb)?;
-    let pbfa = divide(fa)?;
+    let pafb = project(concur, ProjectorKind::Concur, fb)?;
+    let pbfa = project(divide, ProjectorKind::Divide, fa)?;
     event!(Level::DEBUG, ?pafb);
     event!(Level::DEBUG, ?pbfa);
 
-    let inner = pafb + pbfa * -1f32;
-    event!(Level::DEBUG, ?inner);
-
-    let result = state + inner * beta;
+    let inner = pafb.axpy_ref(-1f32, &pbfa);
+    let result = state.axpy_ref(beta, &inner);
     event!(Level::DEBUG, ?result);
+    workspace.recycle(inner);
 
-    Ok(result)
+    Ok((result, pbfa))
 }
 
-pub fn solution<S, D, C>(state: S, divide: D, concur: C, beta: f32) -> Result<S>
+#[deprecated(note = "use `difference_map_step`, which returns this estimate with the step")]
+pub fn solution<S, D, C>(state: S, divide: &D, concur: &C, beta: f32) -> Result<S>
 where
     S: State,
-    D: Fn(S) -> Result<S>,
-    C: Fn(S) -> Result<S>,
+    D: Projector<S> + ?Sized,
+    C: Projector<S> + ?Sized,
 {
     let gamma_a = -1f32 / beta;
-    let fa = concur(state.clone())? * (1.0 + gamma_a) + state.clone() * -gamma_a;
-    divide(fa)
+    let fa = project(concur, ProjectorKind::Concur, state.clone())?
+        .scale(1.0 + gamma_a)
+        .axpy(-gamma_a, state);
+    project(divide, ProjectorKind::Divide, fa)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LinearCombine;
+    use core::ops::{Add, Mul};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Scalar(f32);
+
+    impl Add for Scalar {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self::Output {
+            Self(self.0 + rhs.0)
+        }
+    }
+
+    impl Mul<f32> for Scalar {
+        type Output = Self;
+
+        fn mul(self, rhs: f32) -> Self::Output {
+            Self(self.0 * rhs)
+        }
+    }
+
+    impl State for Scalar {}
+
+    impl ScalarAccess for Scalar {
+        fn scalars(&self) -> impl Iterator<Item = f32> + '_ {
+            core::iter::once(self.0)
+        }
+
+        fn scalars_mut(&mut self) -> impl Iterator<Item = &mut f32> + '_ {
+            core::iter::once(&mut self.0)
+        }
+    }
+
+    fn identity(state: Scalar) -> Result<Scalar> {
+        Ok(state)
+    }
+
+    fn failing(_: Scalar) -> Result<Scalar> {
+        Err(Error::Unknown("boom".into()))
+    }
+
+    fn norm(current: &Scalar, previous: &Scalar) -> f32 {
+        (current.0 - previous.0).abs()
+    }
+
+    // No `Add`/`Mul`, only the fused operations.
+    #[derive(Debug, Clone, PartialEq)]
+    struct Fused(f32);
+
+    impl LinearCombine for Fused {
+        fn scale(self, alpha: f32) -> Self {
+            Self(self.0 * alpha)
+        }
+
+        fn axpy(self, alpha: f32, y: Self) -> Self {
+            Self(alpha.mul_add(y.0, self.0))
+        }
+    }
+
+    impl State for Fused {}
+
+    #[test]
+    fn test_step_error_context() {
+        let solver = DivideAndConcurSolver::new(identity, failing, norm, 1.0, 0.1, 10);
+        match solver.run(Scalar(1.0)) {
+            Err(Error::Step {
+                projector,
+                step,
+                state,
+                ..
+            }) => {
+                assert_eq!(projector, ProjectorKind::Concur);
+                assert_eq!(step, 0);
+                // The iterate the step started from, not the concur's input.
+                let expected = cfg!(feature = "state-snapshots").then(|| "Scalar(1.0)".into());
+                assert_eq!(state, expected);
+            }
+            other => panic!("expected step error, got {other:?}"),
+        }
+
+        let err = step(Scalar(1.0), &failing, &identity, 1.0).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("step error: divide projector failed"));
+    }
+
+    #[test]
+    fn test_step_projections() {
+        let calls = core::cell::Cell::new((0, 0));
+        let clamp = |s: Scalar| -> Result<Scalar> {
+            calls.set((calls.get().0 + 1, calls.get().1));
+            Ok(Scalar(s.0.clamp(0.0, 1.0)))
+        };
+        let shift = |s: Scalar| -> Result<Scalar> {
+            calls.set((calls.get().0, calls.get().1 + 1));
+            Ok(Scalar(s.0.max(0.5)))
+        };
+
+        // P_D(2) = 1, R_D(2) = 0, P_C(0) = 0.5, so 2 + 0.9 (0.5 - 1).
+        let (output, candidate) =
+            step_with_solution(Scalar(2.0), &clamp, &shift, 0.9, &mut StepWorkspace::new())
+                .unwrap();
+        assert!((output.0 - 1.55).abs() < 1e-6);
+        assert_eq!(candidate, Scalar(1.0));
+        assert_eq!(calls.get(), (1, 1));
+    }
+
+    #[test]
+    fn test_step_mut() {
+        let clamp = |s: Scalar| -> Result<Scalar> { Ok(Scalar(s.0.clamp(0.0, 1.0))) };
+        let mut workspace = StepWorkspace::new();
+        let mut state = Scalar(2.0);
+        step_mut(&mut state, &clamp, &identity, 0.9, &mut workspace).unwrap();
+        assert_eq!(state, step(Scalar(2.0), &clamp, &identity, 0.9).unwrap());
+
+        let before = state.clone();
+        assert!(step_mut(&mut state, &failing, &identity, 0.9, &mut workspace).is_err());
+        assert_eq!(state, before);
+    }
+
+    #[test]
+    fn test_step_reuses_buffers() {
+        use crate::states::vector::VecState;
+
+        let clamp = |mut s: VecState| -> Result<VecState> {
+            s.0.iter_mut().for_each(|x| *x = x.clamp(0.0, 1.0));
+            Ok(s)
+        };
+        let mean = |mut s: VecState| -> Result<VecState> {
+            let mean = s.0.iter().sum::<f32>() / s.0.len() as f32;
+            s.0.iter_mut().for_each(|x| *x = mean);
+            Ok(s)
+        };
+
+        let mut workspace = StepWorkspace::new();
+        let mut state = VecState(vec![2.0, -1.0, 0.5]);
+        let mut ptrs = Vec::new();
+        for _ in 0..16 {
+            let update = step_with(workspace.copy(&state), &clamp, &mean, 0.9, &mut workspace);
+            workspace.recycle(core::mem::replace(&mut state, update.unwrap()));
+            ptrs.push(state.0.as_ptr());
+        }
+
+        // Once warm, the states cycle through the same few buffers.
+        let (warm, rest) = ptrs.split_at(8);
+        assert!(rest.iter().all(|ptr| warm[4..].contains(ptr)));
+    }
+
+    #[test]
+    fn test_difference_map_step() {
+        let clamp = |s: Scalar| -> Result<Scalar> { Ok(Scalar(s.0.clamp(0.0, 1.0))) };
+        let shift = |s: Scalar| -> Result<Scalar> { Ok(Scalar(s.0.max(0.5))) };
+
+        // f_C(2) = -1 * 2 + 2 * 2 = 2 and f_D(2) = 3 * 1 - 2 * 2 = -1, so
+        // 2 + 0.5 (P_C(-1) - P_D(2)) = 2 + 0.5 (0.5 - 1).
+        let (output, estimate) =
+            difference_map_step(Scalar(2.0), &clamp, &shift, 0.5, &mut StepWorkspace::new())
+                .unwrap();
+        assert!((output.0 - 1.75).abs() < 1e-6);
+        assert_eq!(estimate, Scalar(1.0));
+
+        // At beta = 1 it is the reflection step.
+        for x in [-0.7, 0.2, 0.6, 2.0] {
+            let (expected, _) =
+                step_with_solution(Scalar(x), &clamp, &shift, 1.0, &mut StepWorkspace::new())
+                    .unwrap();
+            let (output, _) =
+                difference_map_step(Scalar(x), &clamp, &shift, 1.0, &mut StepWorkspace::new())
+                    .unwrap();
+            assert!((output.0 - expected.0).abs() < 1e-6, "{x}");
+        }
+    }
+
+    #[test]
+    fn test_linear_combine_state() {
+        let clamp = |s: Fused| -> Result<Fused> { Ok(Fused(s.0.clamp(0.0, 1.0))) };
+        let shift = |s: Fused| -> Result<Fused> { Ok(Fused(s.0.max(0.5))) };
+        let output = step(Fused(2.0), &clamp, &shift, 0.9).unwrap();
+
+        let clamp = |s: Scalar| -> Result<Scalar> { Ok(Scalar(s.0.clamp(0.0, 1.0))) };
+        let shift = |s: Scalar| -> Result<Scalar> { Ok(Scalar(s.0.max(0.5))) };
+        let expected = step(Scalar(2.0), &clamp, &shift, 0.9).unwrap();
+        assert!((output.0 - expected.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_validate() {
+        let solver = DivideAndConcurSolver::new(identity, identity, norm, 1.0, 0.1, 10);
+        let report = solver.validate(&Scalar(1.0)).unwrap();
+        assert!(report.passed());
+
+        let double = |s: Scalar| -> Result<Scalar> { Ok(s * 2.0) };
+        let solver = DivideAndConcurSolver::new(double, identity, norm, 1.0, 0.1, 10);
+        let report = solver.validate(&Scalar(1.0)).unwrap();
+        let failures: Vec<_> = report.failures().map(|c| c.name).collect();
+        assert_eq!(failures, vec!["divide idempotency"]);
+
+        // Rounding-level asymmetry is held to the idempotency tolerance, not to
+        // a tight convergence epsilon.
+        let clamp = |s: Scalar| -> Result<Scalar> { Ok(Scalar(s.0.clamp(0.0, 1.0))) };
+        let rounded = |a: &Scalar, b: &Scalar| -> f32 {
+            (a.0 - b.0).abs() * if a.0 > b.0 { 1.0 + 1e-6 } else { 1.0 }
+        };
+        let solver = DivideAndConcurSolver::new(clamp, identity, rounded, 1.0, 1e-7, 10);
+        assert!(solver.validate(&Scalar(2.0)).unwrap().passed());
+
+        // A failing projector is a failed check, and the other one still runs.
+        let solver = DivideAndConcurSolver::new(failing, identity, norm, 1.0, 0.1, 10);
+        let report = solver.validate(&Scalar(1.0)).unwrap();
+        let failures: Vec<_> = report.failures().collect();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].name, "divide projection");
+        assert!(failures[0].error.as_deref().unwrap().contains("boom"));
+        assert!(report.checks.iter().any(|c| c.name == "concur idempotency"));
+
+        let solver = DivideAndConcurSolver::new(identity, identity, norm, 1.0, 0.1, 10);
+        assert!(solver.validate_coordinates(&Scalar(1.0)).unwrap().passed());
+
+        let blow_up = |_: Scalar| -> Result<Scalar> { Ok(Scalar(f32::INFINITY)) };
+        let solver = DivideAndConcurSolver::new(identity, blow_up, norm, 1.0, 0.1, 10);
+        let report = solver.validate_coordinates(&Scalar(1.0)).unwrap();
+        let finite = report
+            .checks
+            .iter()
+            .find(|c| c.name == "concur finite coordinates")
+            .unwrap();
+        assert!(!finite.passed);
+        assert_eq!(finite.value, 1.0);
+    }
+
+    #[test]
+    fn test_feasibility() {
+        let half = |s: Scalar| -> Result<Scalar> { Ok(s * 0.5) };
+        let solver = DivideAndConcurSolver::new(half, identity, norm, 1.0, 0.1, 100);
+        let (state, steps, _) = solver.run(Scalar(1.0)).unwrap();
+        assert!(state.0 > 0.0);
+
+        let solver = DivideAndConcurSolver::new(half, identity, norm, 1.0, 0.1, 100)
+            .with_feasibility(|s: &Scalar| (s.0.abs() - 1e-3).max(0.0));
+        let (state, later, _) = solver.run(Scalar(1.0)).unwrap();
+        assert!(state.0 <= 1e-3);
+        assert!(later > steps);
+    }
+
+    #[test]
+    fn test_divergence() {
+        let grow = |s: Scalar| -> Result<Scalar> { Ok(s * 3.0) };
+        let solver = DivideAndConcurSolver::new(grow, identity, norm, 1.0, 0.1, 1000);
+        assert!(matches!(
+            solver.run(Scalar(1.0)),
+            Err(Error::Convergence(1000, _))
+        ));
+
+        let solver = DivideAndConcurSolver::new(grow, identity, norm, 1.0, 0.1, 1000)
+            .with_divergence_factor(100.0);
+        match solver.run(Scalar(1.0)) {
+            Err(Error::Diverged(t, delta)) => {
+                assert_eq!(t, DIVERGENCE_STREAK);
+                assert!(delta > 100.0);
+            }
+            other => panic!("expected divergence error, got {other:?}"),
+        }
+
+        // Deltas 2, 6, 18 pass five times the first, then fall back as the
+        // iterate halves.
+        let calls = core::cell::Cell::new(0);
+        let bump = |s: Scalar| -> Result<Scalar> {
+            calls.set(calls.get() + 1);
+            Ok(s * if calls.get() <= 3 { 3.0 } else { 0.5 })
+        };
+        let solver = DivideAndConcurSolver::new(bump, identity, norm, 1.0, 0.1, 1000)
+            .with_divergence_factor(5.0);
+        assert!(solver.run(Scalar(1.0)).is_ok());
+    }
+
+    #[test]
+    fn test_catch_panics() {
+        let panicking = |_: Scalar| -> Result<Scalar> { panic!("index out of bounds") };
+        let solver = DivideAndConcurSolver::new(panicking, identity, norm, 1.0, 0.1, 10)
+            .with_catch_panics(true);
+        let err = solver.run(Scalar(1.0)).unwrap_err();
+        assert!(err
+            .to_string()
+            .ends_with("projection error: projector panicked: index out of bounds"));
+    }
+
+    #[test]
+    fn test_bounded_norm() {
+        use std::sync::{Arc, Mutex};
+
+        let half = |s: Scalar| -> Result<Scalar> { Ok(s * 0.5) };
+        let tolerances = Tolerances::new(1e-3).with_relative(0.01);
+        let expected = DivideAndConcurSolver::new(half, identity, norm, 1.0, 1e-3, 100)
+            .with_tolerances(tolerances)
+            .run(Scalar(1.0))
+            .unwrap();
+
+        let bounds = Arc::new(Mutex::new(Vec::new()));
+        let seen = bounds.clone();
+        let solver = DivideAndConcurSolver::new(half, identity, norm, 1.0, 1e-3, 100)
+            .with_tolerances(tolerances)
+            .with_bounded_norm(move |a: &Scalar, b: &Scalar, bound: f32| {
+                seen.lock().unwrap().push(bound);
+                norm(a, b).min(bound)
+            });
+        assert_eq!(solver.run(Scalar(1.0)).unwrap(), expected);
+
+        // The first step measures in full and sets the bound for the rest.
+        let bounds = bounds.lock().unwrap();
+        assert_eq!(bounds.len(), expected.1);
+        assert!(bounds.iter().all(|&b| b == tolerances.threshold(0.5)));
+
+        // A monitor needs every delta, so the solver's norm is used throughout.
+        let solver = DivideAndConcurSolver::new(half, identity, norm, 1.0, 1e-3, 100)
+            .with_bounded_norm(|_: &Scalar, _: &Scalar, _: f32| unreachable!())
+            .with_monitor(|_, _| ControlFlow::Continue(()));
+        assert!(solver.run(Scalar(1.0)).is_ok());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_run_batch() {
+        let half = |s: Scalar| -> Result<Scalar> { Ok(s * 0.5) };
+        let solver = DivideAndConcurSolver::new(half, identity, norm, 1.0, 1e-3, 100)
+            .with_feasibility(|s: &Scalar| (s.0.abs() - 1e-3).max(0.0));
+        let starts = vec![Scalar(1.0), Scalar(-4.0), Scalar(0.0)];
+        let results = solver.run_batch(starts.clone());
+
+        assert_eq!(results.len(), 3);
+        for (start, result) in starts.into_iter().zip(results) {
+            let expected = solver.run(start).unwrap();
+            assert_eq!(result.unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_monitor() {
+        let half = |s: Scalar| -> Result<Scalar> { Ok(s * 0.5) };
+        let solver = DivideAndConcurSolver::new(half, identity, norm, 1.0, 1e-9, 100).with_monitor(
+            |t, _| match t {
+                5 => ControlFlow::Break(()),
+                _ => ControlFlow::Continue(()),
+            },
+        );
+        assert!(matches!(solver.run(Scalar(1.0)), Err(Error::Cancelled(6))));
+    }
+
+    #[test]
+    fn test_run_with_history() {
+        let half = |s: Scalar| -> Result<Scalar> { Ok(s * 0.5) };
+        let solver = DivideAndConcurSolver::new(half, identity, norm, 1.0, 1e-3, 100)
+            .with_feasibility(|s: &Scalar| (s.0.abs() - 1e-3).max(0.0));
+        let (result, history) = solver.run_with_history(Scalar(1.0));
+        let (_, steps, delta) = result.unwrap();
+
+        assert_eq!(history.len(), steps + 1);
+        assert_eq!(history[steps].delta, delta);
+        assert!(history.records().iter().all(|r| r.violation.is_some()));
+
+        let solver = DivideAndConcurSolver::new(half, identity, norm, 1.0, 1e-9, 4);
+        let (result, history) = solver.run_with_history(Scalar(1.0));
+        assert!(matches!(result, Err(Error::Convergence(4, _))));
+        assert_eq!(history.len(), 4);
+        assert_eq!(history[3].violation, None);
+    }
 }
@@ -0,0 +1,176 @@
+use super::DivideAndConcurSolver;
+use crate::{
+    errors::Error,
+    solvers::{guard, workspace::StepWorkspace},
+    Projector, State,
+};
+use argmin::core::{
+    self, ArgminError, IterState, Problem, Solver, State as _, TerminationReason, KV,
+};
+
+pub type ArgminState<S> = IterState<S, (), (), (), (), f32>;
+
+// Drives the solver one step per argmin iteration, so argmin's executors and
+// observers work unchanged. The parameter is the divide-and-concur iterate,
+// not its solution: read the answer off the final parameter with
+// `DivideAndConcurSolver::solution`. The cost is the step's delta, and the run
+// stops with `SolverConverged` under the same conditions as `Solver::run`.
+impl<O, S, D, C, N> Solver<O, ArgminState<S>> for DivideAndConcurSolver<S, D, C, N>
+where
+    S: State,
+    D: Projector<S>,
+    C: Projector<S>,
+    N: Fn(&S, &S) -> f32,
+{
+    fn name(&self) -> &str {
+        "divide-and-concur"
+    }
+
+    fn init(
+        &mut self,
+        _problem: &mut Problem<O>,
+        state: ArgminState<S>,
+    ) -> Result<(ArgminState<S>, Option<KV>), core::Error> {
+        if state.get_param().is_none() {
+            return Err(not_initialized());
+        }
+
+        self.initial_delta = f32::NAN;
+        self.rising = 0;
+        // Keep `n_steps` as the budget unless the executor was given its own.
+        let state = match state.get_max_iters() {
+            u64::MAX => state.max_iters(self.n_steps as u64),
+            _ => state,
+        };
+        Ok((state, None))
+    }
+
+    fn next_iter(
+        &mut self,
+        _problem: &mut Problem<O>,
+        mut state: ArgminState<S>,
+    ) -> Result<(ArgminState<S>, Option<KV>), core::Error> {
+        let t = state.get_iter() as usize;
+        let current = state.take_param().ok_or_else(not_initialized)?;
+        let divide = |s: S| guard(&self.divide, s, self.catch_panics);
+        let concur = |s: S| guard(&self.concur, s, self.catch_panics);
+
+        let (update, candidate) = self
+            .step(current.clone(), &divide, &concur, &mut StepWorkspace::new())
+            .map_err(|err| convert(err.at_step(t, &current)))?;
+        let previous_delta = state.get_cost();
+        let delta = (self.norm)(&update, &current);
+        #[cfg(feature = "metrics")]
+        crate::metrics::step(delta);
+        if t == 0 {
+            self.initial_delta = delta;
+        }
+
+        self.rising = if delta > previous_delta {
+            self.rising + 1
+        } else {
+            0
+        };
+        if self.diverged(self.rising, delta, self.initial_delta) {
+            return Err(convert(Error::Diverged(t, delta)));
+        }
+
+        let mut kv = KV::new();
+        kv.insert("delta", delta.into());
+        if self.tolerances.converged(delta, self.initial_delta) {
+            let feasible = match &self.feasibility {
+                Some(feasibility) => {
+                    let violation = feasibility(&candidate);
+                    kv.insert("violation", violation.into());
+                    self.tolerances.feasible(violation)
+                }
+                None => true,
+            };
+
+            // Stop on the iterate whose solution passed, as `run` does.
+            if feasible {
+                let state = state
+                    .param(current)
+                    .cost(delta)
+                    .terminate_with(TerminationReason::SolverConverged);
+                return Ok((state, Some(kv)));
+            }
+        }
+
+        Ok((state.param(update).cost(delta), Some(kv)))
+    }
+}
+
+fn not_initialized() -> core::Error {
+    ArgminError::NotInitialized {
+        text: "divide-and-concur needs an initial state, set with `state.param(...)`".into(),
+    }
+    .into()
+}
+
+// Kept whole, so callers can downcast back to `Error`.
+fn convert(err: Error) -> core::Error {
+    core::Error::new(err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        norms::euclidean_contiguous,
+        projectors::{box_constraints::BoxProjector, hyperplane::HyperplaneProjector},
+        states::vector::VecState,
+    };
+    use argmin::core::{Executor, TerminationStatus};
+
+    #[test]
+    fn test_executor() {
+        let solver = DivideAndConcurSolver::new(
+            BoxProjector::new(0.0, 1.0),
+            HyperplaneProjector::new(vec![1.0, 1.0], 1.5),
+            euclidean_contiguous,
+            0.8,
+            1e-6,
+            1000,
+        );
+        let result = Executor::new((), solver)
+            .configure(|state| state.param(VecState(vec![2.0, -1.0])))
+            .run()
+            .unwrap();
+        let state = result.state();
+        assert_eq!(
+            state.get_termination_status(),
+            &TerminationStatus::Terminated(TerminationReason::SolverConverged)
+        );
+        assert!(state.get_iter() < 1000);
+
+        let x = result
+            .solver()
+            .solution(state.get_param().unwrap().clone())
+            .unwrap();
+        assert!((x[0] + x[1] - 1.5).abs() < 1e-3);
+        assert!(x.iter().all(|&v| (-1e-3..=1.0 + 1e-3).contains(&v)));
+    }
+
+    #[test]
+    fn test_executor_budget() {
+        // Two disjoint boxes never agree, so the executor stops on max_iters.
+        let solver = DivideAndConcurSolver::new(
+            BoxProjector::new(0.0, 1.0),
+            BoxProjector::new(2.0, 3.0),
+            euclidean_contiguous,
+            0.5,
+            1e-6,
+            50,
+        );
+        let result = Executor::new((), solver)
+            .configure(|state| state.param(VecState(vec![0.5])))
+            .run()
+            .unwrap();
+        assert_eq!(result.state().get_iter(), 50);
+        assert_eq!(
+            result.state().get_termination_status(),
+            &TerminationStatus::Terminated(TerminationReason::MaxItersReached)
+        );
+    }
+}
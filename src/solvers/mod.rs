@@ -1 +1,74 @@
 pub mod divide_and_concur;
+pub mod workspace;
+
+use crate::{errors::ProjectorKind, Projector, Result, State};
+use tracing::{span, span::EnteredSpan, Level};
+
+#[cfg(feature = "std")]
+pub(crate) fn guard<S, P>(projector: &P, state: S, catch_panics: bool) -> Result<S>
+where
+    P: Projector<S> + ?Sized,
+{
+    if !catch_panics {
+        return projector.project(state);
+    }
+
+    use crate::errors::{Error, ProjectorPanic};
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    catch_unwind(AssertUnwindSafe(|| projector.project(state)))
+        .unwrap_or_else(|payload| Err(Error::Projection(Box::new(ProjectorPanic::from(payload)))))
+}
+
+// Without `std` there is no unwinding to catch, so panics always propagate.
+#[cfg(not(feature = "std"))]
+pub(crate) fn guard<S, P>(projector: &P, state: S, _catch_panics: bool) -> Result<S>
+where
+    P: Projector<S> + ?Sized,
+{
+    projector.project(state)
+}
+
+// Held for the length of one projection. The span is named after the phase, so
+// trace backends can time divides and concurs apart.
+struct Phase {
+    _span: EnteredSpan,
+    #[cfg(feature = "metrics")]
+    kind: ProjectorKind,
+    #[cfg(feature = "metrics")]
+    start: std::time::Instant,
+}
+
+impl Phase {
+    fn enter(kind: ProjectorKind) -> Self {
+        let span = match kind {
+            ProjectorKind::Divide => span!(Level::DEBUG, "divide"),
+            ProjectorKind::Concur => span!(Level::DEBUG, "concur"),
+        };
+        Self {
+            _span: span.entered(),
+            #[cfg(feature = "metrics")]
+            kind,
+            #[cfg(feature = "metrics")]
+            start: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "metrics")]
+impl Drop for Phase {
+    fn drop(&mut self) {
+        crate::metrics::projection(self.kind, self.start.elapsed());
+    }
+}
+
+pub(crate) fn project<S, P>(projector: &P, kind: ProjectorKind, state: S) -> Result<S>
+where
+    S: State,
+    P: Projector<S> + ?Sized,
+{
+    let _phase = Phase::enter(kind);
+    projector
+        .project(state)
+        .map_err(|err| err.in_projector(kind))
+}
@@ -0,0 +1,81 @@
+use alloc::vec::Vec;
+// Pool of state buffers reused across steps. Copies go through `clone_from`
+// and the steps update states in place, so states whose `Clone` keeps its
+// allocation stop allocating once warmed up. The solver owns one per run;
+// `step_with` takes one for callers driving the iteration themselves.
+#[derive(Debug, Clone)]
+pub struct StepWorkspace<S> {
+    pool: Vec<S>,
+    capacity: usize,
+}
+
+impl<S> StepWorkspace<S>
+where
+    S: Clone,
+{
+    pub fn new() -> Self {
+        Self::with_capacity(8)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            pool: Vec::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    pub fn copy(&mut self, source: &S) -> S {
+        match self.pool.pop() {
+            Some(mut buffer) => {
+                buffer.clone_from(source);
+                buffer
+            }
+            None => source.clone(),
+        }
+    }
+
+    pub fn recycle(&mut self, state: S) {
+        if self.pool.len() < self.capacity {
+            self.pool.push(state);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.pool.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pool.is_empty()
+    }
+}
+
+impl<S> Default for StepWorkspace<S>
+where
+    S: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_workspace() {
+        let mut workspace = StepWorkspace::with_capacity(1);
+        let copy = workspace.copy(&vec![1.0f32, 2.0]);
+        assert!(workspace.is_empty());
+
+        let ptr = copy.as_ptr();
+        workspace.recycle(copy);
+        workspace.recycle(vec![0.0]);
+        assert_eq!(workspace.len(), 1);
+
+        // The recycled allocation is reused for the next copy.
+        let copy = workspace.copy(&vec![3.0, 4.0]);
+        assert_eq!(copy, vec![3.0, 4.0]);
+        assert_eq!(copy.as_ptr(), ptr);
+    }
+}
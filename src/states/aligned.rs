@@ -0,0 +1,232 @@
+use crate::{norms, ScalarAccess, State};
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul, Sub};
+
+const LANES: usize = 8;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[repr(C, align(32))]
+struct Lane([f32; LANES]);
+
+impl Lane {
+    fn add_assign(&mut self, rhs: &Self) {
+        for (x, y) in self.0.iter_mut().zip(&rhs.0) {
+            *x += y;
+        }
+    }
+
+    fn scale(&mut self, alpha: f32) {
+        for x in self.0.iter_mut() {
+            *x *= alpha;
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+fn parallel(len: usize) -> bool {
+    len >= crate::parallel::threshold()
+}
+
+// Stored as 32-byte aligned lanes of eight so the arithmetic loops compile down to
+// packed instructions; trailing padding is kept at zero.
+#[derive(Debug, Default, PartialEq)]
+pub struct AlignedVecState {
+    lanes: Vec<Lane>,
+    len: usize,
+}
+
+impl Clone for AlignedVecState {
+    fn clone(&self) -> Self {
+        Self {
+            lanes: self.lanes.clone(),
+            len: self.len,
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.lanes.clone_from(&source.lanes);
+        self.len = source.len;
+    }
+}
+
+impl AlignedVecState {
+    pub fn zeros(len: usize) -> Self {
+        Self {
+            lanes: vec![Lane::default(); len.div_ceil(LANES)],
+            len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn to_vec(&self) -> Vec<f32> {
+        self.as_ref().to_vec()
+    }
+}
+
+impl From<&[f32]> for AlignedVecState {
+    fn from(values: &[f32]) -> Self {
+        let mut state = Self::zeros(values.len());
+        state.as_mut().copy_from_slice(values);
+        state
+    }
+}
+
+impl From<Vec<f32>> for AlignedVecState {
+    fn from(values: Vec<f32>) -> Self {
+        Self::from(values.as_slice())
+    }
+}
+
+impl AsRef<[f32]> for AlignedVecState {
+    fn as_ref(&self) -> &[f32] {
+        // SAFETY: `Lane` is `repr(C)` over `[f32; LANES]`, so the lanes are a
+        // contiguous run of `lanes.len() * LANES >= len` initialized floats.
+        unsafe { core::slice::from_raw_parts(self.lanes.as_ptr().cast::<f32>(), self.len) }
+    }
+}
+
+impl AsMut<[f32]> for AlignedVecState {
+    fn as_mut(&mut self) -> &mut [f32] {
+        // SAFETY: as in `as_ref`, with exclusive access through `&mut self`.
+        unsafe { core::slice::from_raw_parts_mut(self.lanes.as_mut_ptr().cast::<f32>(), self.len) }
+    }
+}
+
+impl Add for AlignedVecState {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        if self.len != rhs.len {
+            panic!(
+                "invalid state: expected length {}, got {}",
+                self.len, rhs.len
+            );
+        }
+
+        #[cfg(feature = "rayon")]
+        if parallel(self.len) {
+            use rayon::prelude::*;
+            crate::parallel::install(|| {
+                self.lanes
+                    .par_iter_mut()
+                    .zip(&rhs.lanes)
+                    .for_each(|(l, r)| l.add_assign(r))
+            });
+            return self;
+        }
+
+        self.lanes
+            .iter_mut()
+            .zip(&rhs.lanes)
+            .for_each(|(l, r)| l.add_assign(r));
+        self
+    }
+}
+
+impl Mul<f32> for AlignedVecState {
+    type Output = Self;
+
+    fn mul(mut self, rhs: f32) -> Self::Output {
+        #[cfg(feature = "rayon")]
+        if parallel(self.len) {
+            use rayon::prelude::*;
+            crate::parallel::install(|| self.lanes.par_iter_mut().for_each(|l| l.scale(rhs)));
+            return self;
+        }
+
+        self.lanes.iter_mut().for_each(|l| l.scale(rhs));
+        self
+    }
+}
+
+impl Sub for &AlignedVecState {
+    type Output = AlignedVecState;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self.len != rhs.len {
+            panic!(
+                "invalid state: expected length {}, got {}",
+                self.len, rhs.len
+            );
+        }
+
+        let mut output = self.clone();
+        for (l, r) in output.lanes.iter_mut().zip(&rhs.lanes) {
+            for (x, y) in l.0.iter_mut().zip(&r.0) {
+                *x -= y;
+            }
+        }
+        output
+    }
+}
+
+impl ScalarAccess for AlignedVecState {
+    fn scalars(&self) -> impl Iterator<Item = f32> + '_ {
+        self.as_ref().iter().copied()
+    }
+
+    fn scalars_mut(&mut self) -> impl Iterator<Item = &mut f32> + '_ {
+        self.as_mut().iter_mut()
+    }
+}
+
+impl State for AlignedVecState {
+    fn zeros_like(&self) -> Self {
+        Self::zeros(self.len)
+    }
+
+    fn axpy_ref(mut self, alpha: f32, y: &Self) -> Self {
+        if self.len != y.len {
+            panic!("invalid state: expected length {}, got {}", self.len, y.len);
+        }
+
+        for (l, r) in self.lanes.iter_mut().zip(&y.lanes) {
+            for (x, y) in l.0.iter_mut().zip(&r.0) {
+                *x += alpha * y;
+            }
+        }
+        self
+    }
+}
+
+// Lane-wise accumulation; padding contributes nothing since it is zero in both.
+pub fn norm(current: &AlignedVecState, previous: &AlignedVecState) -> f32 {
+    let mut acc = [0f32; LANES];
+    for (c, p) in current.lanes.iter().zip(&previous.lanes) {
+        for ((a, x), y) in acc.iter_mut().zip(&c.0).zip(&p.0) {
+            *a += (x - y) * (x - y);
+        }
+    }
+    norms::sqrt(acc.iter().sum::<f32>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{norms::euclidean, projectors::box_constraints::BoxProjector, Projector};
+
+    #[test]
+    fn test_aligned_vec_state() {
+        let values: Vec<f32> = (0..19).map(|i| i as f32).collect();
+        let state = AlignedVecState::from(values.clone());
+        assert_eq!(state.len(), 19);
+        assert_eq!(state.as_ref().as_ptr() as usize % 32, 0);
+
+        let output = (state.clone() + state.clone()) * 0.25;
+        let expected: Vec<f32> = values.iter().map(|x| x * 0.5).collect();
+        assert_eq!(output.to_vec(), expected);
+
+        let zeros = AlignedVecState::zeros(19);
+        assert_eq!(norm(&state, &zeros), euclidean(&values, &vec![0.0; 19]));
+
+        let output = BoxProjector::new(0.0, 1.0).project(state).unwrap();
+        assert_eq!(output.as_ref()[..3], [0.0, 1.0, 1.0]);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{ScalarAccess, State};
+use core::ops::{Add, Deref, DerefMut, Mul, Sub};
+
+// Same orphan-rule workaround as `VecState`, for stack-allocated toy problems.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArrayState<const N: usize>(pub [f32; N]);
+
+impl<const N: usize> Default for ArrayState<N> {
+    fn default() -> Self {
+        Self([0.0; N])
+    }
+}
+
+impl<const N: usize> From<[f32; N]> for ArrayState<N> {
+    fn from(values: [f32; N]) -> Self {
+        Self(values)
+    }
+}
+
+impl<const N: usize> From<ArrayState<N>> for [f32; N] {
+    fn from(state: ArrayState<N>) -> Self {
+        state.0
+    }
+}
+
+impl<const N: usize> Deref for ArrayState<N> {
+    type Target = [f32; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const N: usize> DerefMut for ArrayState<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> AsRef<[f32]> for ArrayState<N> {
+    fn as_ref(&self) -> &[f32] {
+        &self.0
+    }
+}
+
+impl<const N: usize> AsMut<[f32]> for ArrayState<N> {
+    fn as_mut(&mut self) -> &mut [f32] {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> Add for ArrayState<N> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.0.iter_mut().zip(rhs.0).for_each(|(l, r)| *l += r);
+        self
+    }
+}
+
+impl<const N: usize> Mul<f32> for ArrayState<N> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: f32) -> Self::Output {
+        self.0.iter_mut().for_each(|l| *l *= rhs);
+        self
+    }
+}
+
+impl<const N: usize> Sub for &ArrayState<N> {
+    type Output = ArrayState<N>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut output = *self;
+        output.0.iter_mut().zip(&rhs.0).for_each(|(l, r)| *l -= r);
+        output
+    }
+}
+
+impl<const N: usize> ScalarAccess for ArrayState<N> {
+    fn scalars(&self) -> impl Iterator<Item = f32> + '_ {
+        self.0.iter().copied()
+    }
+
+    fn scalars_mut(&mut self) -> impl Iterator<Item = &mut f32> + '_ {
+        self.0.iter_mut()
+    }
+}
+
+impl<const N: usize> State for ArrayState<N> {
+    fn zeros_like(&self) -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        norms::euclidean,
+        projectors::{box_constraints::BoxProjector, hyperplane::HyperplaneProjector},
+        solvers::divide_and_concur::{candidate, DivideAndConcurSolver},
+        Solver,
+    };
+
+    #[test]
+    fn test_array_state_arithmetic() {
+        let state = ArrayState::from([1.0, 2.0, 3.0]) + ArrayState([1.0, 0.0, -1.0]);
+        assert_eq!(state * 2.0, ArrayState([4.0, 4.0, 4.0]));
+    }
+
+    #[test]
+    fn test_array_state_solver() {
+        let divide = BoxProjector::new(0.0, 1.0);
+        let concur = HyperplaneProjector::new(vec![1.0, 1.0, 1.0], 2.5);
+        let solver =
+            DivideAndConcurSolver::new(divide.clone(), concur.clone(), euclidean, 0.9, 1e-6, 1000);
+
+        let (state, _, _) = solver.run(ArrayState([0.0, 0.0, 0.0])).unwrap();
+        let output = candidate(state, &divide).unwrap();
+        assert!((output.iter().sum::<f32>() - 2.5).abs() < 1e-4);
+        assert!(output.iter().all(|&x| (-1e-6..=1.0 + 1e-6).contains(&x)));
+    }
+}
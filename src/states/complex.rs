@@ -0,0 +1,141 @@
+use crate::State;
+use num_complex::Complex32;
+use std::ops::{Add, Deref, DerefMut, Mul, Sub};
+
+// Complex coordinates with a real step multiplier, as used by phase retrieval.
+#[derive(Debug, Default, PartialEq)]
+pub struct ComplexState(pub Vec<Complex32>);
+
+// Spelled out so `clone_from` reuses the existing allocation.
+impl Clone for ComplexState {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0);
+    }
+}
+
+impl From<Vec<Complex32>> for ComplexState {
+    fn from(values: Vec<Complex32>) -> Self {
+        Self(values)
+    }
+}
+
+impl From<ComplexState> for Vec<Complex32> {
+    fn from(state: ComplexState) -> Self {
+        state.0
+    }
+}
+
+impl Deref for ComplexState {
+    type Target = Vec<Complex32>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ComplexState {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<[Complex32]> for ComplexState {
+    fn as_ref(&self) -> &[Complex32] {
+        &self.0
+    }
+}
+
+impl AsMut<[Complex32]> for ComplexState {
+    fn as_mut(&mut self) -> &mut [Complex32] {
+        &mut self.0
+    }
+}
+
+impl Add for ComplexState {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.0.len() != rhs.0.len() {
+            panic!(
+                "invalid state: expected length {}, got {}",
+                self.0.len(),
+                rhs.0.len()
+            );
+        }
+
+        Self(self.0.into_iter().zip(rhs.0).map(|(l, r)| l + r).collect())
+    }
+}
+
+impl Mul<f32> for ComplexState {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self(self.0.into_iter().map(|l| l * rhs).collect())
+    }
+}
+
+impl Sub for &ComplexState {
+    type Output = ComplexState;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self.0.len() != rhs.0.len() {
+            panic!(
+                "invalid state: expected length {}, got {}",
+                self.0.len(),
+                rhs.0.len()
+            );
+        }
+
+        ComplexState(self.0.iter().zip(&rhs.0).map(|(l, r)| l - r).collect())
+    }
+}
+
+impl State for ComplexState {
+    fn zeros_like(&self) -> Self {
+        Self(vec![Complex32::new(0.0, 0.0); self.0.len()])
+    }
+}
+
+pub fn norm(current: &ComplexState, previous: &ComplexState) -> f32 {
+    current
+        .iter()
+        .zip(previous.iter())
+        .map(|(c, p)| (c - p).norm_sqr())
+        .sum::<f32>()
+        .sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complex_state() {
+        let a = ComplexState(vec![Complex32::new(1.0, 2.0), Complex32::new(0.0, -1.0)]);
+        let b = ComplexState(vec![Complex32::new(1.0, 0.0), Complex32::new(2.0, 1.0)]);
+
+        let sum = (a.clone() + b.clone()) * 0.5;
+        assert_eq!(
+            sum,
+            ComplexState(vec![Complex32::new(1.0, 1.0), Complex32::new(1.0, 0.0)])
+        );
+        assert_eq!(norm(&a, &b), 12f32.sqrt());
+    }
+
+    #[cfg(feature = "fft")]
+    #[test]
+    fn test_complex_state_fourier_projector() {
+        use crate::{projectors::fourier::FourierMagnitudeProjector, Projector};
+
+        let projector = FourierMagnitudeProjector::new(vec![1.0; 4]);
+        let output = projector
+            .project(ComplexState(vec![Complex32::new(1.0, 0.0); 4]))
+            .unwrap();
+        assert_eq!(output.len(), 4);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{states::kernels, ScalarAccess, State};
+use alloc::{vec, vec::Vec};
+use core::{
+    ops::{Add, Mul},
+    slice::{ChunksExact, ChunksExactMut},
+};
+
+// One contiguous block per constraint, stored row after row, for problems
+// whose divide projects each constraint's variables on their own, such as the
+// assignments over a Sudoku's rows, columns and blocks. Blocks come out as
+// slices, so a projector like `PermutationProjector` works on them in place
+// instead of gathering a copy.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConstraintMatrixState {
+    values: Vec<f32>,
+    width: usize,
+}
+
+// Spelled out so `clone_from` reuses the existing allocation.
+impl Clone for ConstraintMatrixState {
+    fn clone(&self) -> Self {
+        Self {
+            values: self.values.clone(),
+            width: self.width,
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.values.clone_from(&source.values);
+        self.width = source.width;
+    }
+}
+
+impl ConstraintMatrixState {
+    pub fn new(values: Vec<f32>, width: usize) -> Self {
+        if width == 0 {
+            panic!("invalid block width: expected at least 1, got 0");
+        }
+        if !values.len().is_multiple_of(width) {
+            panic!(
+                "invalid state: expected a multiple of {width} values, got {}",
+                values.len()
+            );
+        }
+
+        Self { values, width }
+    }
+
+    pub fn zeros(n_blocks: usize, width: usize) -> Self {
+        Self::new(vec![0.0; n_blocks * width], width)
+    }
+
+    pub fn n_blocks(&self) -> usize {
+        self.values.len() / self.width
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn block(&self, i: usize) -> &[f32] {
+        &self.values[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn block_mut(&mut self, i: usize) -> &mut [f32] {
+        &mut self.values[i * self.width..(i + 1) * self.width]
+    }
+
+    pub fn blocks(&self) -> ChunksExact<'_, f32> {
+        self.values.chunks_exact(self.width)
+    }
+
+    pub fn blocks_mut(&mut self) -> ChunksExactMut<'_, f32> {
+        self.values.chunks_exact_mut(self.width)
+    }
+
+    pub fn into_values(self) -> Vec<f32> {
+        self.values
+    }
+
+    fn check(&self, other: &Self) {
+        if self.width != other.width || self.values.len() != other.values.len() {
+            panic!(
+                "invalid state: expected {} blocks of width {}, got {} of width {}",
+                self.n_blocks(),
+                self.width,
+                other.n_blocks(),
+                other.width
+            );
+        }
+    }
+}
+
+impl AsRef<[f32]> for ConstraintMatrixState {
+    fn as_ref(&self) -> &[f32] {
+        &self.values
+    }
+}
+
+impl AsMut<[f32]> for ConstraintMatrixState {
+    fn as_mut(&mut self) -> &mut [f32] {
+        &mut self.values
+    }
+}
+
+impl Add for ConstraintMatrixState {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.check(&rhs);
+        kernels::add_assign(&mut self.values, &rhs.values);
+        self
+    }
+}
+
+impl Mul<f32> for ConstraintMatrixState {
+    type Output = Self;
+
+    fn mul(mut self, rhs: f32) -> Self::Output {
+        kernels::scale(&mut self.values, rhs);
+        self
+    }
+}
+
+impl ScalarAccess for ConstraintMatrixState {
+    fn scalars(&self) -> impl Iterator<Item = f32> + '_ {
+        self.values.iter().copied()
+    }
+
+    fn scalars_mut(&mut self) -> impl Iterator<Item = &mut f32> + '_ {
+        self.values.iter_mut()
+    }
+}
+
+impl State for ConstraintMatrixState {
+    fn zeros_like(&self) -> Self {
+        Self::zeros(self.n_blocks(), self.width)
+    }
+
+    fn axpy_ref(mut self, alpha: f32, y: &Self) -> Self {
+        self.check(y);
+        kernels::axpy(&mut self.values, alpha, &y.values);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{projectors::permutation::PermutationProjector, Projector};
+
+    #[test]
+    fn test_blocks() {
+        let mut state = ConstraintMatrixState::new((0..6).map(|i| i as f32).collect(), 3);
+        assert_eq!(state.n_blocks(), 2);
+        assert_eq!(state.block(1), &[3.0, 4.0, 5.0]);
+
+        // Blocks project in place, without leaving the state's buffer.
+        let ptr = state.as_ref().as_ptr();
+        let permutation = PermutationProjector::new(1);
+        for block in state.blocks_mut() {
+            permutation.project(&mut block[..1]).unwrap();
+        }
+        assert_eq!(state.blocks().map(|b| b[0]).collect::<Vec<_>>(), [1.0, 1.0]);
+        assert_eq!(state.as_ref().as_ptr(), ptr);
+
+        let doubled = state.clone().axpy_ref(1.0, &state);
+        assert_eq!(doubled.block(0), &[2.0, 2.0, 4.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid state: expected a multiple of 4 values, got 6")]
+    fn test_ragged() {
+        ConstraintMatrixState::new(vec![0.0; 6], 4);
+    }
+}
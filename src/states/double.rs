@@ -0,0 +1,234 @@
+use crate::{states::kernels, State};
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Deref, DerefMut, Mul, Sub};
+
+// Double-precision storage. Step coefficients stay f32 (the `State` contract),
+// but they are widened before use so accumulated iterates keep f64 accuracy.
+#[derive(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VecState64(pub Vec<f64>);
+
+// Spelled out so `clone_from` reuses the existing allocation.
+impl Clone for VecState64 {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0);
+    }
+}
+
+impl From<Vec<f64>> for VecState64 {
+    fn from(values: Vec<f64>) -> Self {
+        Self(values)
+    }
+}
+
+impl From<VecState64> for Vec<f64> {
+    fn from(state: VecState64) -> Self {
+        state.0
+    }
+}
+
+impl Deref for VecState64 {
+    type Target = Vec<f64>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for VecState64 {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<[f64]> for VecState64 {
+    fn as_ref(&self) -> &[f64] {
+        &self.0
+    }
+}
+
+impl AsMut<[f64]> for VecState64 {
+    fn as_mut(&mut self) -> &mut [f64] {
+        &mut self.0
+    }
+}
+
+impl Add for VecState64 {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        if self.0.len() != rhs.0.len() {
+            panic!(
+                "invalid state: expected length {}, got {}",
+                self.0.len(),
+                rhs.0.len()
+            );
+        }
+
+        kernels::add_assign(&mut self.0, &rhs.0);
+        self
+    }
+}
+
+impl Mul<f32> for VecState64 {
+    type Output = Self;
+
+    fn mul(mut self, rhs: f32) -> Self::Output {
+        kernels::scale(&mut self.0, rhs as f64);
+        self
+    }
+}
+
+impl Sub for &VecState64 {
+    type Output = VecState64;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self.0.len() != rhs.0.len() {
+            panic!(
+                "invalid state: expected length {}, got {}",
+                self.0.len(),
+                rhs.0.len()
+            );
+        }
+
+        VecState64(self.0.iter().zip(&rhs.0).map(|(l, r)| l - r).collect())
+    }
+}
+
+impl State for VecState64 {
+    fn zeros_like(&self) -> Self {
+        Self(vec![0.0; self.0.len()])
+    }
+
+    fn axpy_ref(mut self, alpha: f32, y: &Self) -> Self {
+        if self.0.len() != y.0.len() {
+            panic!(
+                "invalid state: expected length {}, got {}",
+                self.0.len(),
+                y.0.len()
+            );
+        }
+
+        kernels::axpy(&mut self.0, alpha as f64, &y.0);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArrayState64<const N: usize>(pub [f64; N]);
+
+impl<const N: usize> Default for ArrayState64<N> {
+    fn default() -> Self {
+        Self([0.0; N])
+    }
+}
+
+impl<const N: usize> From<[f64; N]> for ArrayState64<N> {
+    fn from(values: [f64; N]) -> Self {
+        Self(values)
+    }
+}
+
+impl<const N: usize> Deref for ArrayState64<N> {
+    type Target = [f64; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const N: usize> DerefMut for ArrayState64<N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> AsRef<[f64]> for ArrayState64<N> {
+    fn as_ref(&self) -> &[f64] {
+        &self.0
+    }
+}
+
+impl<const N: usize> AsMut<[f64]> for ArrayState64<N> {
+    fn as_mut(&mut self) -> &mut [f64] {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> Add for ArrayState64<N> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.0.iter_mut().zip(rhs.0).for_each(|(l, r)| *l += r);
+        self
+    }
+}
+
+impl<const N: usize> Mul<f32> for ArrayState64<N> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: f32) -> Self::Output {
+        self.0.iter_mut().for_each(|l| *l *= rhs as f64);
+        self
+    }
+}
+
+impl<const N: usize> Sub for &ArrayState64<N> {
+    type Output = ArrayState64<N>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut output = *self;
+        output.0.iter_mut().zip(&rhs.0).for_each(|(l, r)| *l -= r);
+        output
+    }
+}
+
+impl<const N: usize> State for ArrayState64<N> {
+    fn zeros_like(&self) -> Self {
+        Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        norms::euclidean64,
+        solvers::divide_and_concur::{candidate, DivideAndConcurSolver},
+        Result, Solver,
+    };
+
+    fn clamp(mut state: VecState64) -> Result<VecState64> {
+        state.iter_mut().for_each(|x| *x = x.clamp(0.0, 1.0));
+        Ok(state)
+    }
+
+    fn hyperplane(mut state: VecState64) -> Result<VecState64> {
+        let shift = (state.iter().sum::<f64>() - 1.000_000_1) / state.len() as f64;
+        state.iter_mut().for_each(|x| *x -= shift);
+        Ok(state)
+    }
+
+    #[test]
+    fn test_double_states() {
+        let state = ArrayState64([1.0, 2.0]) + ArrayState64([0.5, 0.5]);
+        assert_eq!(state * 2.0, ArrayState64([3.0, 5.0]));
+
+        let state = (VecState64(vec![1e-9, 1.0]) + VecState64(vec![1e-9, 1.0])) * 0.5;
+        assert_eq!(state, VecState64(vec![1e-9, 1.0]));
+    }
+
+    #[test]
+    fn test_double_state_solver() {
+        let solver = DivideAndConcurSolver::new(clamp, hyperplane, euclidean64, 0.9, 1e-9, 10000);
+        let (state, _, _) = solver.run(VecState64(vec![0.0; 3])).unwrap();
+        let output = candidate(state, &clamp).unwrap();
+
+        // The offset is below f32 resolution around 1.0.
+        assert!((output.iter().sum::<f64>() - 1.000_000_1).abs() < 1e-8);
+    }
+}
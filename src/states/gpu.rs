@@ -0,0 +1,452 @@
+use crate::{errors::Error, Result, State};
+use std::{
+    fmt,
+    ops::{Add, Mul},
+    sync::{mpsc, Arc},
+};
+use wgpu::util::DeviceExt;
+
+const WORKGROUP_SIZE: u32 = 256;
+const MAX_WORKGROUPS: u32 = 65535;
+
+const UPDATE_SHADER: &str = r#"
+struct Params { scale: f32, len: u32, _pad0: u32, _pad1: u32 }
+
+@group(0) @binding(0) var<storage, read_write> lhs: array<f32>;
+@group(0) @binding(1) var<storage, read> rhs: array<f32>;
+@group(0) @binding(2) var<uniform> params: Params;
+
+fn index(gid: vec3<u32>, groups: vec3<u32>) -> u32 {
+    return gid.x + gid.y * groups.x * 256u;
+}
+
+@compute @workgroup_size(256)
+fn add(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = index(gid, groups);
+    if (i < params.len) {
+        lhs[i] = lhs[i] + rhs[i];
+    }
+}
+
+@compute @workgroup_size(256)
+fn scale(@builtin(global_invocation_id) gid: vec3<u32>, @builtin(num_workgroups) groups: vec3<u32>) {
+    let i = index(gid, groups);
+    if (i < params.len) {
+        lhs[i] = lhs[i] * params.scale;
+    }
+}
+"#;
+
+const REDUCE_SHADER: &str = r#"
+struct Params { scale: f32, len: u32, _pad0: u32, _pad1: u32 }
+
+@group(0) @binding(0) var<storage, read> lhs: array<f32>;
+@group(0) @binding(1) var<storage, read> rhs: array<f32>;
+@group(0) @binding(2) var<uniform> params: Params;
+@group(0) @binding(3) var<storage, read_write> partials: array<f32>;
+
+var<workgroup> scratch: array<f32, 256>;
+
+@compute @workgroup_size(256)
+fn squared_distance(
+    @builtin(local_invocation_index) lid: u32,
+    @builtin(workgroup_id) wid: vec3<u32>,
+    @builtin(num_workgroups) groups: vec3<u32>,
+) {
+    let group = wid.x + wid.y * groups.x;
+    let i = group * 256u + lid;
+    var d = 0.0;
+    if (i < params.len) {
+        d = lhs[i] - rhs[i];
+    }
+    scratch[lid] = d * d;
+    workgroupBarrier();
+
+    for (var stride = 128u; stride > 0u; stride = stride / 2u) {
+        if (lid < stride) {
+            scratch[lid] = scratch[lid] + scratch[lid + stride];
+        }
+        workgroupBarrier();
+    }
+
+    if (lid == 0u) {
+        partials[group] = scratch[0];
+    }
+}
+"#;
+
+// Device, queue and compiled kernels shared by every `GpuState` on one adapter.
+pub struct GpuContext {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    update_layout: wgpu::BindGroupLayout,
+    reduce_layout: wgpu::BindGroupLayout,
+    add: wgpu::ComputePipeline,
+    scale: wgpu::ComputePipeline,
+    squared_distance: wgpu::ComputePipeline,
+    dummy: wgpu::Buffer,
+}
+
+impl GpuContext {
+    pub fn new() -> Result<Arc<Self>> {
+        pollster::block_on(Self::request())
+    }
+
+    async fn request() -> Result<Arc<Self>> {
+        let instance =
+            wgpu::Instance::new(wgpu::InstanceDescriptor::new_without_display_handle_from_env());
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions::default())
+            .await
+            .map_err(|err| Error::Unknown(Box::new(err)))?;
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor::default())
+            .await
+            .map_err(|err| Error::Unknown(Box::new(err)))?;
+
+        let storage = |binding, read_only| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let uniform = wgpu::BindGroupLayoutEntry {
+            binding: 2,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+
+        let update_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("drs-update"),
+            entries: &[storage(0, false), storage(1, true), uniform],
+        });
+        let reduce_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("drs-reduce"),
+            entries: &[
+                storage(0, true),
+                storage(1, true),
+                uniform,
+                storage(3, false),
+            ],
+        });
+
+        let pipeline = |layout: &wgpu::BindGroupLayout, source: &str, entry_point: &str| {
+            let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some(entry_point),
+                source: wgpu::ShaderSource::Wgsl(source.into()),
+            });
+            let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some(entry_point),
+                bind_group_layouts: &[Some(layout)],
+                immediate_size: 0,
+            });
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some(entry_point),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: Some(entry_point),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        };
+
+        let add = pipeline(&update_layout, UPDATE_SHADER, "add");
+        let scale = pipeline(&update_layout, UPDATE_SHADER, "scale");
+        let squared_distance = pipeline(&reduce_layout, REDUCE_SHADER, "squared_distance");
+        let dummy = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("drs-dummy"),
+            size: 4,
+            usage: wgpu::BufferUsages::STORAGE,
+            mapped_at_creation: false,
+        });
+
+        Ok(Arc::new(Self {
+            device,
+            queue,
+            update_layout,
+            reduce_layout,
+            add,
+            scale,
+            squared_distance,
+            dummy,
+        }))
+    }
+
+    fn params(&self, scale: f32, len: usize) -> wgpu::Buffer {
+        let mut contents = Vec::with_capacity(16);
+        contents.extend(scale.to_le_bytes());
+        contents.extend((len as u32).to_le_bytes());
+        contents.extend([0u8; 8]);
+        self.device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("drs-params"),
+                contents: &contents,
+                usage: wgpu::BufferUsages::UNIFORM,
+            })
+    }
+
+    fn storage(&self, size: u64, usage: wgpu::BufferUsages) -> wgpu::Buffer {
+        self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("drs-state"),
+            size: size.max(4),
+            usage,
+            mapped_at_creation: false,
+        })
+    }
+
+    fn dispatch(
+        &self,
+        pipeline: &wgpu::ComputePipeline,
+        layout: &wgpu::BindGroupLayout,
+        buffers: &[&wgpu::Buffer],
+        len: usize,
+    ) {
+        let entries: Vec<_> = buffers
+            .iter()
+            .enumerate()
+            .map(|(i, buffer)| wgpu::BindGroupEntry {
+                binding: i as u32,
+                resource: buffer.as_entire_binding(),
+            })
+            .collect();
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout,
+            entries: &entries,
+        });
+
+        let (x, y) = workgroups(len);
+        let mut encoder = self.device.create_command_encoder(&Default::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&Default::default());
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(x, y, 1);
+        }
+        self.queue.submit([encoder.finish()]);
+    }
+
+    fn read(&self, buffer: &wgpu::Buffer, len: usize) -> Vec<f32> {
+        let size = (len * 4) as u64;
+        let staging = self.storage(
+            size,
+            wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+        );
+        let mut encoder = self.device.create_command_encoder(&Default::default());
+        encoder.copy_buffer_to_buffer(buffer, 0, &staging, 0, size);
+        self.queue.submit([encoder.finish()]);
+
+        let (sender, receiver) = mpsc::channel();
+        staging.map_async(wgpu::MapMode::Read, .., move |result| {
+            let _ = sender.send(result);
+        });
+        self.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .expect("failed to wait for the GPU");
+        receiver
+            .recv()
+            .expect("GPU mapping callback dropped")
+            .expect("failed to map GPU buffer");
+
+        let view = staging
+            .get_mapped_range(..)
+            .expect("failed to read GPU buffer");
+        let values = view[..len * 4]
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect();
+        drop(view);
+        staging.unmap();
+        values
+    }
+}
+
+fn workgroups(len: usize) -> (u32, u32) {
+    let groups = (len as u32).div_ceil(WORKGROUP_SIZE).max(1);
+    let x = groups.min(MAX_WORKGROUPS);
+    (x, groups.div_ceil(x))
+}
+
+// A device-resident vector: arithmetic runs as compute kernels and data only
+// crosses the bus through `upload` and `download`.
+pub struct GpuState {
+    context: Arc<GpuContext>,
+    buffer: wgpu::Buffer,
+    len: usize,
+}
+
+impl GpuState {
+    pub fn upload(context: &Arc<GpuContext>, values: &[f32]) -> Self {
+        let contents: Vec<u8> = values.iter().flat_map(|x| x.to_le_bytes()).collect();
+        let buffer = context
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("drs-state"),
+                contents: if contents.is_empty() {
+                    &[0; 4]
+                } else {
+                    &contents
+                },
+                usage: Self::usage(),
+            });
+
+        Self {
+            context: context.clone(),
+            buffer,
+            len: values.len(),
+        }
+    }
+
+    pub fn download(&self) -> Vec<f32> {
+        self.context.read(&self.buffer, self.len)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn usage() -> wgpu::BufferUsages {
+        wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::COPY_DST
+    }
+}
+
+impl Clone for GpuState {
+    fn clone(&self) -> Self {
+        let size = (self.len.max(1) * 4) as u64;
+        let buffer = self.context.storage(size, Self::usage());
+        let mut encoder = self
+            .context
+            .device
+            .create_command_encoder(&Default::default());
+        encoder.copy_buffer_to_buffer(&self.buffer, 0, &buffer, 0, size);
+        self.context.queue.submit([encoder.finish()]);
+
+        Self {
+            context: self.context.clone(),
+            buffer,
+            len: self.len,
+        }
+    }
+}
+
+impl fmt::Debug for GpuState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GpuState").field("len", &self.len).finish()
+    }
+}
+
+impl Add for GpuState {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.len != rhs.len {
+            panic!(
+                "invalid state: expected length {}, got {}",
+                self.len, rhs.len
+            );
+        }
+
+        let params = self.context.params(0.0, self.len);
+        self.context.dispatch(
+            &self.context.add,
+            &self.context.update_layout,
+            &[&self.buffer, &rhs.buffer, &params],
+            self.len,
+        );
+        self
+    }
+}
+
+impl Mul<f32> for GpuState {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        let params = self.context.params(rhs, self.len);
+        self.context.dispatch(
+            &self.context.scale,
+            &self.context.update_layout,
+            &[&self.buffer, &self.context.dummy, &params],
+            self.len,
+        );
+        self
+    }
+}
+
+impl State for GpuState {
+    // wgpu zero-initializes new buffers, so nothing needs to be dispatched.
+    fn zeros_like(&self) -> Self {
+        Self {
+            context: self.context.clone(),
+            buffer: self
+                .context
+                .storage((self.len.max(1) * 4) as u64, Self::usage()),
+            len: self.len,
+        }
+    }
+}
+
+// Per-workgroup partial sums are reduced on the device; only those come back.
+pub fn norm(current: &GpuState, previous: &GpuState) -> f32 {
+    let context = &current.context;
+    let (x, y) = workgroups(current.len);
+    let n_partials = (x * y) as usize;
+
+    let params = context.params(0.0, current.len.min(previous.len));
+    let partials = context.storage(
+        (n_partials * 4) as u64,
+        wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+    );
+    context.dispatch(
+        &context.squared_distance,
+        &context.reduce_layout,
+        &[&current.buffer, &previous.buffer, &params, &partials],
+        current.len,
+    );
+
+    context
+        .read(&partials, n_partials)
+        .iter()
+        .sum::<f32>()
+        .sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // CI machines usually have no adapter, so these only run where one exists.
+    fn context() -> Option<Arc<GpuContext>> {
+        GpuContext::new().ok()
+    }
+
+    #[test]
+    fn test_gpu_state() {
+        let Some(context) = context() else {
+            return;
+        };
+
+        let values: Vec<f32> = (0..1000).map(|i| i as f32).collect();
+        let state = GpuState::upload(&context, &values);
+        let output = (state.clone() + state.clone()) * 0.5;
+        assert_eq!(output.download(), values);
+
+        let zeros = GpuState::upload(&context, &vec![0.0; 1000]);
+        let expected = values.iter().map(|x| x * x).sum::<f32>().sqrt();
+        assert!((norm(&state, &zeros) - expected).abs() / expected < 1e-5);
+        assert_eq!(norm(&state, &state), 0.0);
+        assert_eq!(state.zeros_like().download(), vec![0.0; 1000]);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{errors::Error, Projector, Result, State};
+use half::{bf16, f16};
+use std::{
+    fmt::Debug,
+    ops::{Add, Mul},
+};
+
+pub trait HalfFloat: Copy + Debug + Default + PartialEq + Send + Sync {
+    fn from_f32(value: f32) -> Self;
+
+    fn to_f32(self) -> f32;
+}
+
+impl HalfFloat for f16 {
+    fn from_f32(value: f32) -> Self {
+        f16::from_f32(value)
+    }
+
+    fn to_f32(self) -> f32 {
+        f16::to_f32(self)
+    }
+}
+
+impl HalfFloat for bf16 {
+    fn from_f32(value: f32) -> Self {
+        bf16::from_f32(value)
+    }
+
+    fn to_f32(self) -> f32 {
+        bf16::to_f32(self)
+    }
+}
+
+// Stored at half precision; arithmetic widens each coordinate to f32 and rounds
+// once on the way back.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HalfState<T = f16>(pub Vec<T>);
+
+impl<T> HalfState<T>
+where
+    T: HalfFloat,
+{
+    pub fn from_f32(values: &[f32]) -> Self {
+        Self(values.iter().map(|&x| T::from_f32(x)).collect())
+    }
+
+    pub fn to_f32(&self) -> Vec<f32> {
+        self.0.iter().map(|x| x.to_f32()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T> Add for HalfState<T>
+where
+    T: HalfFloat,
+{
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        if self.0.len() != rhs.0.len() {
+            panic!(
+                "invalid state: expected length {}, got {}",
+                self.0.len(),
+                rhs.0.len()
+            );
+        }
+
+        for (l, r) in self.0.iter_mut().zip(&rhs.0) {
+            *l = T::from_f32(l.to_f32() + r.to_f32());
+        }
+        self
+    }
+}
+
+impl<T> Mul<f32> for HalfState<T>
+where
+    T: HalfFloat,
+{
+    type Output = Self;
+
+    fn mul(mut self, rhs: f32) -> Self::Output {
+        for x in self.0.iter_mut() {
+            *x = T::from_f32(x.to_f32() * rhs);
+        }
+        self
+    }
+}
+
+impl<T> State for HalfState<T>
+where
+    T: HalfFloat,
+{
+    fn zeros_like(&self) -> Self {
+        Self(vec![T::default(); self.0.len()])
+    }
+}
+
+// Accumulates in f32 regardless of the storage type.
+pub fn norm<T>(current: &HalfState<T>, previous: &HalfState<T>) -> f32
+where
+    T: HalfFloat,
+{
+    current
+        .0
+        .iter()
+        .zip(&previous.0)
+        .map(|(c, p)| (c.to_f32() - p.to_f32()).powi(2))
+        .sum::<f32>()
+        .sqrt()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Widened<P> {
+    projector: P,
+}
+
+impl<T, P> Projector<HalfState<T>> for Widened<P>
+where
+    T: HalfFloat,
+    P: Projector<Vec<f32>>,
+{
+    fn project(&self, state: HalfState<T>) -> Result<HalfState<T>> {
+        let projected = self.projector.project(state.to_f32())?;
+        if projected.len() != state.len() {
+            return Err(Error::Dimension(state.len(), projected.len()));
+        }
+
+        Ok(HalfState::from_f32(&projected))
+    }
+}
+
+// Runs an f32 projector on a half-precision state by widening and re-rounding.
+pub fn widened<P>(projector: P) -> Widened<P> {
+    Widened { projector }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projectors::box_constraints::BoxProjector;
+
+    #[test]
+    fn test_half_state() {
+        let a = HalfState::<f16>::from_f32(&[1.0, 2.0, 3.0]);
+        let b = HalfState::<f16>::from_f32(&[0.5, 0.5, 0.5]);
+        assert_eq!(((a.clone() + b) * 2.0).to_f32(), vec![3.0, 5.0, 7.0]);
+        assert_eq!(norm(&a, &a.zeros_like()), 14f32.sqrt());
+
+        let c = HalfState::<bf16>::from_f32(&[1.0, -1.0]);
+        assert_eq!((c * 0.5).to_f32(), vec![0.5, -0.5]);
+    }
+
+    #[test]
+    fn test_widened() {
+        let projector = widened(BoxProjector::new(0.0, 1.0));
+        let output = projector
+            .project(HalfState::<f16>::from_f32(&[-1.0, 0.5, 4.0]))
+            .unwrap();
+        assert_eq!(output.to_f32(), vec![0.0, 0.5, 1.0]);
+    }
+}
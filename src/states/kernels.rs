@@ -0,0 +1,158 @@
+// Element-wise loops shared by the contiguous states, parallel above
+// `parallel::threshold()` when the `rayon` feature is on.
+use crate::Scalar;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "rayon")]
+fn parallel(len: usize) -> bool {
+    len >= crate::parallel::threshold()
+}
+
+pub(crate) fn add_assign<T: Scalar>(lhs: &mut [T], rhs: &[T]) {
+    #[cfg(feature = "rayon")]
+    if parallel(lhs.len()) {
+        return crate::parallel::install(|| {
+            lhs.par_iter_mut().zip(rhs).for_each(|(l, &r)| *l += r)
+        });
+    }
+
+    lhs.iter_mut().zip(rhs).for_each(|(l, &r)| *l += r);
+}
+
+pub(crate) fn axpy<T: Scalar>(lhs: &mut [T], alpha: T, rhs: &[T]) {
+    #[cfg(feature = "rayon")]
+    if parallel(lhs.len()) {
+        return crate::parallel::install(|| {
+            lhs.par_iter_mut()
+                .zip(rhs)
+                .for_each(|(l, &r)| *l += alpha * r)
+        });
+    }
+
+    lhs.iter_mut().zip(rhs).for_each(|(l, &r)| *l += alpha * r);
+}
+
+pub(crate) fn scale<T: Scalar>(values: &mut [T], alpha: T) {
+    #[cfg(feature = "rayon")]
+    if parallel(values.len()) {
+        return crate::parallel::install(|| values.par_iter_mut().for_each(|x| *x *= alpha));
+    }
+
+    values.iter_mut().for_each(|x| *x *= alpha);
+}
+
+pub(crate) fn squared_distance<T: Scalar>(a: &[T], b: &[T]) -> T {
+    #[cfg(feature = "rayon")]
+    if parallel(a.len()) {
+        return crate::parallel::install(|| {
+            a.par_iter().zip(b).map(|(&x, &y)| (x - y) * (x - y)).sum()
+        });
+    }
+
+    a.iter().zip(b).map(|(&x, &y)| (x - y) * (x - y)).sum()
+}
+
+// Independent Kahan sums over `LANES` interleaved lanes: the lanes don't depend
+// on each other, so the loop vectorizes, and the compensation keeps the error
+// flat as states grow. Under `rayon`, blocks are summed the same way and their
+// partial sums combined with compensation again.
+const LANES: usize = 8;
+
+pub(crate) fn compensated_squared_distance(a: &[f32], b: &[f32]) -> f32 {
+    #[cfg(feature = "rayon")]
+    if parallel(a.len()) {
+        let block = crate::parallel::threshold().max(LANES);
+        let partials: alloc::vec::Vec<f32> = crate::parallel::install(|| {
+            a.par_chunks(block)
+                .zip(b.par_chunks(block))
+                .map(|(a, b)| compensated_block(a, b))
+                .collect()
+        });
+        return compensated_sum(&partials);
+    }
+
+    compensated_block(a, b)
+}
+
+fn compensated_block(a: &[f32], b: &[f32]) -> f32 {
+    let len = a.len().min(b.len());
+    let (a, b) = (&a[..len], &b[..len]);
+    let mut sum = [0f32; LANES];
+    let mut compensation = [0f32; LANES];
+
+    let mut add = |lane: usize, x: f32, y: f32| {
+        let term = (x - y) * (x - y) - compensation[lane];
+        let total = sum[lane] + term;
+        compensation[lane] = (total - sum[lane]) - term;
+        sum[lane] = total;
+    };
+
+    let (a_chunks, b_chunks) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+    let (a_rest, b_rest) = (a_chunks.remainder(), b_chunks.remainder());
+    for (x, y) in a_chunks.zip(b_chunks) {
+        for lane in 0..LANES {
+            add(lane, x[lane], y[lane]);
+        }
+    }
+    for (lane, (&x, &y)) in a_rest.iter().zip(b_rest).enumerate() {
+        add(lane, x, y);
+    }
+
+    compensated_sum(&sum)
+}
+
+fn compensated_sum(values: &[f32]) -> f32 {
+    let (mut sum, mut compensation) = (0f32, 0f32);
+    for &x in values {
+        let term = x - compensation;
+        let total = sum + term;
+        compensation = (total - sum) - term;
+        sum = total;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kernels() {
+        let mut a: Vec<f32> = (0..100).map(|i| i as f32).collect();
+        let b = vec![1.0; 100];
+
+        add_assign(&mut a, &b);
+        scale(&mut a, 2.0);
+        assert_eq!(a[..3], [2.0, 4.0, 6.0]);
+        axpy(&mut a, -2.0, &b);
+        assert_eq!(a[..3], [0.0, 2.0, 4.0]);
+        assert_eq!(squared_distance(&a[1..3], &[0.0, 0.0]), 20.0);
+        assert_eq!(compensated_squared_distance(&a[1..3], &[0.0, 0.0]), 20.0);
+    }
+
+    #[test]
+    fn test_compensated_squared_distance() {
+        // One large term and many small ones, which a plain f32 sum drops.
+        let mut a = vec![1e-4f32; 1_000_003];
+        a[0] = 1.0;
+        let exact = 1.0 + 1_000_002.0 * 1e-8f64;
+        let zeros = vec![0.0; a.len()];
+
+        let plain = squared_distance(&a, &zeros) as f64;
+        let compensated = compensated_squared_distance(&a, &zeros) as f64;
+        assert!((compensated - exact).abs() < 1e-6);
+        assert!((compensated - exact).abs() < (plain - exact).abs());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_kernels_parallel() {
+        let n = crate::parallel::threshold() + 1;
+        let mut a = vec![1.0f32; n];
+        add_assign(&mut a, &vec![1.0; n]);
+        scale(&mut a, 0.5);
+        assert!(a.iter().all(|&x| x == 1.0));
+        assert_eq!(squared_distance(&a, &vec![0.0; n]), n as f32);
+    }
+}
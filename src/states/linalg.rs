@@ -0,0 +1,69 @@
+use crate::{ScalarAccess, State};
+use nalgebra::{allocator::Allocator, DefaultAllocator, Dim, OMatrix};
+
+// Covers `DVector`, `DMatrix`, `SVector` and `SMatrix` over f32.
+impl<R, C> State for OMatrix<f32, R, C>
+where
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<R, C>,
+{
+    fn zeros_like(&self) -> Self {
+        let (nrows, ncols) = self.shape_generic();
+        Self::zeros_generic(nrows, ncols)
+    }
+}
+
+impl<R, C> ScalarAccess for OMatrix<f32, R, C>
+where
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<R, C>,
+{
+    fn scalars(&self) -> impl Iterator<Item = f32> + '_ {
+        self.iter().copied()
+    }
+
+    fn scalars_mut(&mut self) -> impl Iterator<Item = &mut f32> + '_ {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        norms::euclidean, solvers::divide_and_concur::DivideAndConcurSolver, Result, Solver,
+    };
+    use nalgebra::{DMatrix, DVector, Vector3};
+
+    fn clamp(mut state: DVector<f32>) -> Result<DVector<f32>> {
+        state.apply(|x| *x = x.clamp(0.0, 1.0));
+        Ok(state)
+    }
+
+    fn mean(state: DVector<f32>) -> Result<DVector<f32>> {
+        let m = state.mean();
+        Ok(state.map(|_| m))
+    }
+
+    #[test]
+    fn test_dvector_state() {
+        let solver = DivideAndConcurSolver::new(clamp, mean, euclidean, 0.9, 1e-6, 1000);
+        let (state, _, _) = solver.run(DVector::from_vec(vec![-2.0, 0.5, 3.0])).unwrap();
+        assert!(state.iter().all(|x| x.is_finite()));
+    }
+
+    #[test]
+    fn test_static_and_matrix_states() {
+        let v = Vector3::new(1.0f32, 2.0, 3.0) + Vector3::new(1.0, 1.0, 1.0) * 2.0;
+        assert_eq!(v, Vector3::new(3.0, 4.0, 5.0));
+
+        let m = DMatrix::<f32>::identity(2, 2) * 0.5 + DMatrix::identity(2, 2);
+        assert_eq!(m, DMatrix::identity(2, 2) * 1.5);
+
+        fn assert_state<S: crate::State>(_: &S) {}
+        assert_state(&v);
+        assert_state(&m);
+        assert_eq!(crate::State::zeros_like(&m), DMatrix::zeros(2, 2));
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{Projector, Result, ScalarAccess, State};
+use alloc::{sync::Arc, vec::Vec};
+use core::ops::{Add, Mul};
+
+// Coordinates with a given value stay pinned through arithmetic and projections.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaskedState<S> {
+    state: S,
+    givens: Arc<[Option<f32>]>,
+}
+
+impl<S> MaskedState<S>
+where
+    S: AsMut<[f32]>,
+{
+    pub fn new(mut state: S, givens: Vec<Option<f32>>) -> Self {
+        let n = state.as_mut().len();
+        if givens.len() != n {
+            panic!("invalid givens: expected {n} values, got {}", givens.len());
+        }
+
+        let mut output = Self {
+            state,
+            givens: givens.into(),
+        };
+        output.pin();
+        output
+    }
+
+    pub fn pin(&mut self) {
+        for (x, given) in self.state.as_mut().iter_mut().zip(self.givens.iter()) {
+            if let Some(value) = given {
+                *x = *value;
+            }
+        }
+    }
+}
+
+impl<S> MaskedState<S> {
+    pub fn inner(&self) -> &S {
+        &self.state
+    }
+
+    pub fn into_inner(self) -> S {
+        self.state
+    }
+
+    pub fn givens(&self) -> &[Option<f32>] {
+        &self.givens
+    }
+}
+
+impl<S> AsRef<[f32]> for MaskedState<S>
+where
+    S: AsRef<[f32]>,
+{
+    fn as_ref(&self) -> &[f32] {
+        self.state.as_ref()
+    }
+}
+
+impl<S> Add for MaskedState<S>
+where
+    S: State + AsMut<[f32]>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut output = Self {
+            state: self.state.axpy(1.0, rhs.state),
+            givens: self.givens,
+        };
+        output.pin();
+        output
+    }
+}
+
+impl<S> Mul<f32> for MaskedState<S>
+where
+    S: State + AsMut<[f32]>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        let mut output = Self {
+            state: self.state.scale(rhs),
+            givens: self.givens,
+        };
+        output.pin();
+        output
+    }
+}
+
+// Mutable access only reaches the free coordinates, so the givens stay pinned.
+impl<S> ScalarAccess for MaskedState<S>
+where
+    S: ScalarAccess,
+{
+    fn scalars(&self) -> impl Iterator<Item = f32> + '_ {
+        self.state.scalars()
+    }
+
+    fn scalars_mut(&mut self) -> impl Iterator<Item = &mut f32> + '_ {
+        self.state
+            .scalars_mut()
+            .zip(self.givens.iter())
+            .filter(|(_, given)| given.is_none())
+            .map(|(x, _)| x)
+    }
+}
+
+// Givens stay pinned, so only the free coordinates are zeroed.
+impl<S> State for MaskedState<S>
+where
+    S: State + AsMut<[f32]>,
+{
+    fn zeros_like(&self) -> Self {
+        let mut output = Self {
+            state: self.state.zeros_like(),
+            givens: self.givens.clone(),
+        };
+        output.pin();
+        output
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frozen<P> {
+    projector: P,
+}
+
+impl<S, P> Projector<MaskedState<S>> for Frozen<P>
+where
+    S: AsMut<[f32]>,
+    P: Projector<S>,
+{
+    fn project(&self, state: MaskedState<S>) -> Result<MaskedState<S>> {
+        let mut output = MaskedState {
+            state: self.projector.project(state.state)?,
+            givens: state.givens,
+        };
+        output.pin();
+        Ok(output)
+    }
+}
+
+// Lifts a projector on `S` to one on `MaskedState<S>` that re-pins the givens.
+pub fn frozen<P>(projector: P) -> Frozen<P> {
+    Frozen { projector }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{projectors::box_constraints::BoxProjector, states::vector::VecState};
+
+    #[test]
+    fn test_masked_state() {
+        let givens = vec![Some(1.0), None, Some(-2.0)];
+        let state = MaskedState::new(VecState(vec![0.0, 0.5, 0.0]), givens);
+        assert_eq!(state.as_ref(), &[1.0, 0.5, -2.0]);
+
+        let output = (state.clone() + state.clone()) * 3.0;
+        assert_eq!(output.as_ref(), &[1.0, 3.0, -2.0]);
+        assert_eq!(output.zeros_like().as_ref(), &[1.0, 0.0, -2.0]);
+
+        let projector = frozen(BoxProjector::new(0.0, 0.25));
+        let mut output = projector.project(state).unwrap();
+        assert_eq!(output.as_ref(), &[1.0, 0.25, -2.0]);
+
+        output.scalars_mut().for_each(|x| *x = 7.0);
+        assert_eq!(output.scalars().collect::<Vec<_>>(), vec![1.0, 7.0, -2.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid givens: expected 2 values, got 1")]
+    fn test_masked_state_invalid_givens() {
+        MaskedState::new(VecState(vec![0.0, 0.0]), vec![None]);
+    }
+}
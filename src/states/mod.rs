@@ -0,0 +1,21 @@
+pub mod aligned;
+pub mod array;
+#[cfg(feature = "complex")]
+pub mod complex;
+pub mod constraint_matrix;
+pub mod double;
+#[cfg(feature = "gpu")]
+pub mod gpu;
+#[cfg(feature = "half")]
+pub mod half_precision;
+pub(crate) mod kernels;
+#[cfg(feature = "nalgebra")]
+pub mod linalg;
+pub mod masked;
+pub mod product_space;
+pub mod product_state;
+#[cfg(feature = "sprs")]
+pub mod sparse;
+#[cfg(feature = "candle")]
+pub mod tensor;
+pub mod vector;
@@ -0,0 +1,198 @@
+use crate::{ScalarAccess, State};
+use alloc::{vec, vec::Vec};
+use core::ops::{Add, Mul};
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProductSpace<S> {
+    pub copies: Vec<S>,
+}
+
+// Spelled out so `clone_from` reuses the existing copies' allocations.
+impl<S> Clone for ProductSpace<S>
+where
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            copies: self.copies.clone(),
+        }
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.copies.clone_from(&source.copies);
+    }
+}
+
+impl<S> ProductSpace<S>
+where
+    S: State,
+{
+    pub fn new(copies: Vec<S>) -> Self {
+        Self { copies }
+    }
+
+    pub fn replicate(state: S, n: usize) -> Self {
+        Self {
+            copies: vec![state; n],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.copies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.copies.is_empty()
+    }
+
+    pub fn mean(&self) -> Option<S> {
+        let d = 1.0 / self.copies.len() as f32;
+        self.copies
+            .iter()
+            .cloned()
+            .map(|c| c.scale(d))
+            .reduce(|l, r| l.axpy(1.0, r))
+    }
+
+    // `None` when empty, or when there isn't exactly one weight per copy.
+    pub fn weighted_mean(&self, weights: &[f32]) -> Option<S> {
+        if weights.len() != self.copies.len() {
+            return None;
+        }
+
+        let total: f32 = weights.iter().sum();
+        self.copies
+            .iter()
+            .cloned()
+            .zip(weights)
+            .map(|(c, &w)| c.scale(w / total))
+            .reduce(|l, r| l.axpy(1.0, r))
+    }
+}
+
+impl<S> ProductSpace<S> {
+    fn check(&self, other: &Self) {
+        if self.copies.len() != other.copies.len() {
+            panic!(
+                "invalid state: expected {} copies, got {}",
+                self.copies.len(),
+                other.copies.len()
+            );
+        }
+    }
+
+    // Sums the per-copy distances, matching the solver's `norm` signature.
+    pub fn norm<N>(norm: N) -> impl Fn(&Self, &Self) -> f32
+    where
+        N: Fn(&S, &S) -> f32,
+    {
+        move |current, previous| {
+            current
+                .copies
+                .iter()
+                .zip(&previous.copies)
+                .map(|(c, p)| norm(c, p))
+                .sum()
+        }
+    }
+}
+
+impl<S> Add for ProductSpace<S>
+where
+    S: State,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.check(&rhs);
+        Self {
+            copies: self
+                .copies
+                .into_iter()
+                .zip(rhs.copies)
+                .map(|(l, r)| l.axpy(1.0, r))
+                .collect(),
+        }
+    }
+}
+
+impl<S> Mul<f32> for ProductSpace<S>
+where
+    S: State,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self {
+            copies: self.copies.into_iter().map(|l| l.scale(rhs)).collect(),
+        }
+    }
+}
+
+impl<S> ScalarAccess for ProductSpace<S>
+where
+    S: ScalarAccess,
+{
+    fn scalars(&self) -> impl Iterator<Item = f32> + '_ {
+        self.copies.iter().flat_map(|c| c.scalars())
+    }
+
+    fn scalars_mut(&mut self) -> impl Iterator<Item = &mut f32> + '_ {
+        self.copies.iter_mut().flat_map(|c| c.scalars_mut())
+    }
+}
+
+impl<S> State for ProductSpace<S>
+where
+    S: State,
+{
+    fn zeros_like(&self) -> Self {
+        Self {
+            copies: self.copies.iter().map(|c| c.zeros_like()).collect(),
+        }
+    }
+
+    // Collected in place, so the outer `Vec` is kept too.
+    fn axpy_ref(self, alpha: f32, y: &Self) -> Self {
+        self.check(y);
+        Self {
+            copies: self
+                .copies
+                .into_iter()
+                .zip(&y.copies)
+                .map(|(l, r)| l.axpy_ref(alpha, r))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::states::vector::VecState;
+
+    fn space(values: &[f32]) -> ProductSpace<VecState> {
+        ProductSpace::new(values.iter().map(|&v| VecState(vec![v])).collect())
+    }
+
+    #[test]
+    fn test_weighted_mean() {
+        let state = space(&[1.0, 3.0]);
+        assert_eq!(state.weighted_mean(&[1.0, 3.0]), Some(VecState(vec![2.5])));
+        assert_eq!(state.weighted_mean(&[1.0]), None);
+        assert_eq!(state.weighted_mean(&[1.0, 1.0, 1.0]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid state: expected 2 copies, got 3")]
+    fn test_add_mismatch() {
+        let _ = space(&[1.0, 2.0]) + space(&[1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid state: expected 3 copies, got 2")]
+    fn test_axpy_mismatch() {
+        let _ = space(&[1.0, 2.0, 3.0]).axpy_ref(1.0, &space(&[1.0, 2.0]));
+    }
+}
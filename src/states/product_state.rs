@@ -0,0 +1,151 @@
+use crate::{Projector, Result, ScalarAccess, State};
+use core::ops::{Add, Mul, Sub};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProductState<A, B>(pub A, pub B);
+
+impl<A, B> ProductState<A, B> {
+    pub fn new(a: A, b: B) -> Self {
+        Self(a, b)
+    }
+
+    pub fn into_parts(self) -> (A, B) {
+        (self.0, self.1)
+    }
+
+    // Sums the component distances, matching the solver's `norm` signature.
+    pub fn norm<NA, NB>(norm_a: NA, norm_b: NB) -> impl Fn(&Self, &Self) -> f32
+    where
+        NA: Fn(&A, &A) -> f32,
+        NB: Fn(&B, &B) -> f32,
+    {
+        move |current, previous| norm_a(&current.0, &previous.0) + norm_b(&current.1, &previous.1)
+    }
+}
+
+impl<A, B> Add for ProductState<A, B>
+where
+    A: State,
+    B: State,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0.axpy(1.0, rhs.0), self.1.axpy(1.0, rhs.1))
+    }
+}
+
+impl<A, B> Mul<f32> for ProductState<A, B>
+where
+    A: State,
+    B: State,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self(self.0.scale(rhs), self.1.scale(rhs))
+    }
+}
+
+impl<'a, A, B> Sub for &'a ProductState<A, B>
+where
+    &'a A: Sub<Output = A>,
+    &'a B: Sub<Output = B>,
+{
+    type Output = ProductState<A, B>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        ProductState(&self.0 - &rhs.0, &self.1 - &rhs.1)
+    }
+}
+
+impl<A, B> State for ProductState<A, B>
+where
+    A: State,
+    B: State,
+{
+    fn zeros_like(&self) -> Self {
+        Self(self.0.zeros_like(), self.1.zeros_like())
+    }
+}
+
+impl<A, B> ScalarAccess for ProductState<A, B>
+where
+    A: ScalarAccess,
+    B: ScalarAccess,
+{
+    fn scalars(&self) -> impl Iterator<Item = f32> + '_ {
+        self.0.scalars().chain(self.1.scalars())
+    }
+
+    fn scalars_mut(&mut self) -> impl Iterator<Item = &mut f32> + '_ {
+        self.0.scalars_mut().chain(self.1.scalars_mut())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ProductProjector<PA, PB>(pub PA, pub PB);
+
+impl<A, B, PA, PB> Projector<ProductState<A, B>> for ProductProjector<PA, PB>
+where
+    PA: Projector<A>,
+    PB: Projector<B>,
+{
+    fn project(&self, state: ProductState<A, B>) -> Result<ProductState<A, B>> {
+        Ok(ProductState(
+            self.0.project(state.0)?,
+            self.1.project(state.1)?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        norms::euclidean,
+        projectors::{box_constraints::BoxProjector, nonnegative::NonNegativeProjector},
+        states::{array::ArrayState, product_space::ProductSpace, vector::VecState},
+    };
+
+    #[test]
+    fn test_product_state() {
+        let a = ProductState(VecState(vec![1.0, 2.0]), ArrayState([3.0]));
+        let b = ProductState(VecState(vec![1.0, 0.0]), ArrayState([-1.0]));
+        assert_eq!(
+            (a.clone() + b.clone()) * 0.5,
+            ProductState(VecState(vec![1.0, 1.0]), ArrayState([1.0]))
+        );
+
+        assert_eq!(
+            a.zeros_like(),
+            ProductState(VecState(vec![0.0, 0.0]), ArrayState([0.0]))
+        );
+
+        let norm = ProductState::norm(euclidean, euclidean);
+        assert_eq!(norm(&a, &b), 6.0);
+
+        let copies = ProductSpace::new(vec![VecState(vec![3.0]), VecState(vec![4.0])]);
+        let norm = ProductSpace::norm(euclidean);
+        assert_eq!(
+            norm(&copies, &ProductSpace::replicate(VecState(vec![0.0]), 2)),
+            7.0
+        );
+    }
+
+    #[test]
+    fn test_product_projector() {
+        let projector = ProductProjector(BoxProjector::new(0.0, 1.0), NonNegativeProjector::new());
+        let output = projector
+            .project(ProductState(
+                VecState(vec![2.0, 0.5]),
+                ArrayState([-1.0, 3.0]),
+            ))
+            .unwrap();
+        assert_eq!(
+            output,
+            ProductState(VecState(vec![1.0, 0.5]), ArrayState([0.0, 3.0]))
+        );
+    }
+}
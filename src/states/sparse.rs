@@ -0,0 +1,112 @@
+use crate::State;
+use sprs::CsVec;
+use std::ops::{Add, Mul, Sub};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseState(pub CsVec<f32>);
+
+impl SparseState {
+    pub fn new(dim: usize, indices: Vec<usize>, data: Vec<f32>) -> Self {
+        Self(CsVec::new(dim, indices, data))
+    }
+
+    pub fn zeros(dim: usize) -> Self {
+        Self(CsVec::empty(dim))
+    }
+
+    pub fn dim(&self) -> usize {
+        self.0.dim()
+    }
+
+    pub fn nnz(&self) -> usize {
+        self.0.nnz()
+    }
+
+    pub fn to_dense(&self) -> Vec<f32> {
+        self.0.to_dense().to_vec()
+    }
+}
+
+impl From<CsVec<f32>> for SparseState {
+    fn from(values: CsVec<f32>) -> Self {
+        Self(values)
+    }
+}
+
+impl Add for SparseState {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.dim() != rhs.dim() {
+            panic!(
+                "invalid state: expected dimension {}, got {}",
+                self.dim(),
+                rhs.dim()
+            );
+        }
+
+        Self(&self.0 + &rhs.0)
+    }
+}
+
+impl Mul<f32> for SparseState {
+    type Output = Self;
+
+    fn mul(mut self, rhs: f32) -> Self::Output {
+        self.0.map_inplace(|x| x * rhs);
+        self
+    }
+}
+
+impl Sub for &SparseState {
+    type Output = SparseState;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self.dim() != rhs.dim() {
+            panic!(
+                "invalid state: expected dimension {}, got {}",
+                self.dim(),
+                rhs.dim()
+            );
+        }
+
+        SparseState(&self.0 - &rhs.0)
+    }
+}
+
+impl State for SparseState {
+    fn zeros_like(&self) -> Self {
+        Self::zeros(self.dim())
+    }
+}
+
+// Euclidean distance touching only the union of the two supports.
+pub fn norm(current: &SparseState, previous: &SparseState) -> f32 {
+    (current - previous).0.l2_norm()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_state_arithmetic() {
+        let a = SparseState::new(1_000_000, vec![3, 10], vec![1.0, 2.0]);
+        let b = SparseState::new(1_000_000, vec![10, 999_999], vec![1.0, -4.0]);
+
+        let sum = (a.clone() + b.clone()) * 0.5;
+        assert_eq!(sum.nnz(), 3);
+        assert_eq!(
+            sum,
+            SparseState::new(1_000_000, vec![3, 10, 999_999], vec![0.5, 1.5, -2.0])
+        );
+        assert_eq!(norm(&a, &a), 0.0);
+        assert_eq!(norm(&a, &SparseState::zeros(1_000_000)), 5f32.sqrt());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid state: expected dimension 4, got 2")]
+    fn test_sparse_state_dimension_mismatch() {
+        let _ = SparseState::zeros(4) + SparseState::zeros(2);
+    }
+}
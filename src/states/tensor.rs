@@ -0,0 +1,136 @@
+use crate::{errors::Error, Projector, Result, State};
+use candle_core::{DType, Tensor};
+use std::ops::{Add, Mul};
+
+// A candle tensor of any shape, dtype and device, updated with tensor ops so
+// the iterate stays on the device. Clones share storage, which is safe since
+// every op builds a new tensor.
+#[derive(Debug, Clone)]
+pub struct TensorState(pub Tensor);
+
+impl From<Tensor> for TensorState {
+    fn from(tensor: Tensor) -> Self {
+        Self(tensor)
+    }
+}
+
+impl Add for TensorState {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.0.shape() != rhs.0.shape() {
+            panic!(
+                "invalid state: expected shape {:?}, got {:?}",
+                self.0.shape(),
+                rhs.0.shape()
+            );
+        }
+
+        Self((&self.0 + &rhs.0).expect("failed to add tensors"))
+    }
+}
+
+impl Mul<f32> for TensorState {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self(
+            self.0
+                .affine(rhs as f64, 0.0)
+                .expect("failed to scale tensor"),
+        )
+    }
+}
+
+impl State for TensorState {
+    fn zeros_like(&self) -> Self {
+        Self(self.0.zeros_like().expect("failed to allocate tensor"))
+    }
+}
+
+// Reduced on the device; only the scalar comes back.
+pub fn norm(current: &TensorState, previous: &TensorState) -> f32 {
+    (&current.0 - &previous.0)
+        .and_then(|d| d.to_dtype(DType::F32)?.sqr()?.sum_all()?.to_scalar::<f32>())
+        .expect("failed to reduce tensors")
+        .sqrt()
+}
+
+// Runs a projector written for `Vec<f32>` on a tensor, by copying it to the host
+// and back to its device, dtype and shape. Projectors that have tensor-op
+// forms should act on `TensorState` directly instead.
+#[derive(Debug, Clone)]
+pub struct OnHost<P>(pub P);
+
+pub fn on_host<P>(projector: P) -> OnHost<P> {
+    OnHost(projector)
+}
+
+impl<P> Projector<TensorState> for OnHost<P>
+where
+    P: Projector<Vec<f32>>,
+{
+    fn project(&self, state: TensorState) -> Result<TensorState> {
+        let tensor = &state.0;
+        let values = tensor
+            .flatten_all()
+            .and_then(|t| t.to_dtype(DType::F32)?.to_vec1::<f32>())
+            .map_err(|err| Error::Projection(Box::new(err)))?;
+
+        let projected = self.0.project(values)?;
+        Tensor::from_vec(projected, tensor.shape(), tensor.device())
+            .and_then(|t| t.to_dtype(tensor.dtype()))
+            .map(TensorState)
+            .map_err(|err| Error::Projection(Box::new(err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        projectors::{box_constraints::BoxProjector, hyperplane::HyperplaneProjector},
+        solvers::divide_and_concur::DivideAndConcurSolver,
+        Solver,
+    };
+    use candle_core::Device;
+
+    #[test]
+    fn test_tensor_state() {
+        let state = TensorState(Tensor::new(&[[1.0f32, 2.0], [3.0, 4.0]], &Device::Cpu).unwrap());
+        let output = (state.clone() + state.clone()) * 0.5;
+        assert_eq!(
+            output.0.to_vec2::<f32>().unwrap(),
+            vec![vec![1.0, 2.0], vec![3.0, 4.0]]
+        );
+        assert_eq!(norm(&state, &state.zeros_like()), 30f32.sqrt());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid state: expected shape [2], got [3]")]
+    fn test_shape_mismatch() {
+        let a = TensorState(Tensor::zeros(2, DType::F32, &Device::Cpu).unwrap());
+        let b = TensorState(Tensor::zeros(3, DType::F32, &Device::Cpu).unwrap());
+        let _ = a + b;
+    }
+
+    #[test]
+    fn test_solve() {
+        // Weights clipped to a box while their sum is pinned, in f64.
+        let solver = DivideAndConcurSolver::new(
+            on_host(BoxProjector::new(0.0, 1.0)),
+            on_host(HyperplaneProjector::new(vec![1.0, 1.0], 1.5)),
+            norm,
+            0.8,
+            1e-6,
+            1000,
+        );
+        let weights = Tensor::new(&[2.0f64, -1.0], &Device::Cpu).unwrap();
+        let (state, _, _) = solver.run(TensorState(weights)).unwrap();
+
+        assert_eq!(state.0.dtype(), DType::F64);
+        let x = state.0.to_vec1::<f64>().unwrap();
+        assert!((x[0] + x[1] - 1.5).abs() < 1e-3);
+        assert!(x.iter().all(|&x| (-1e-3..=1.0 + 1e-3).contains(&x)));
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{states::kernels, ScalarAccess, State};
+use alloc::{vec, vec::Vec};
+use core::{
+    fmt,
+    ops::{Add, Deref, DerefMut, Mul, Sub},
+};
+
+// `Vec<f32>` can't carry `State` directly since `Add` is foreign on both sides,
+// so this is the thinnest wrapper that can.
+#[derive(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VecState(pub Vec<f32>);
+
+// Spelled out so `clone_from` reuses the existing allocation.
+impl Clone for VecState {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.0.clone_from(&source.0);
+    }
+}
+
+impl From<Vec<f32>> for VecState {
+    fn from(values: Vec<f32>) -> Self {
+        Self(values)
+    }
+}
+
+impl From<VecState> for Vec<f32> {
+    fn from(state: VecState) -> Self {
+        state.0
+    }
+}
+
+impl Deref for VecState {
+    type Target = Vec<f32>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for VecState {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+// Forwards the formatter's precision to every entry, so `{:.3}` works.
+impl fmt::Display for VecState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, x) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            fmt::Display::fmt(x, f)?;
+        }
+        write!(f, "]")
+    }
+}
+
+#[cfg(feature = "std")]
+impl VecState {
+    pub fn evcxr_display(&self) {
+        crate::display::evcxr_display(self);
+    }
+}
+
+impl AsRef<[f32]> for VecState {
+    fn as_ref(&self) -> &[f32] {
+        &self.0
+    }
+}
+
+impl AsMut<[f32]> for VecState {
+    fn as_mut(&mut self) -> &mut [f32] {
+        &mut self.0
+    }
+}
+
+impl Add for VecState {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        if self.0.len() != rhs.0.len() {
+            panic!(
+                "invalid state: expected length {}, got {}",
+                self.0.len(),
+                rhs.0.len()
+            );
+        }
+
+        kernels::add_assign(&mut self.0, &rhs.0);
+        self
+    }
+}
+
+impl Mul<f32> for VecState {
+    type Output = Self;
+
+    fn mul(mut self, rhs: f32) -> Self::Output {
+        kernels::scale(&mut self.0, rhs);
+        self
+    }
+}
+
+impl Sub for &VecState {
+    type Output = VecState;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        if self.0.len() != rhs.0.len() {
+            panic!(
+                "invalid state: expected length {}, got {}",
+                self.0.len(),
+                rhs.0.len()
+            );
+        }
+
+        VecState(self.0.iter().zip(&rhs.0).map(|(l, r)| l - r).collect())
+    }
+}
+
+impl ScalarAccess for VecState {
+    fn scalars(&self) -> impl Iterator<Item = f32> + '_ {
+        self.0.iter().copied()
+    }
+
+    fn scalars_mut(&mut self) -> impl Iterator<Item = &mut f32> + '_ {
+        self.0.iter_mut()
+    }
+}
+
+impl State for VecState {
+    fn zeros_like(&self) -> Self {
+        Self(vec![0.0; self.0.len()])
+    }
+
+    fn axpy_ref(mut self, alpha: f32, y: &Self) -> Self {
+        if self.0.len() != y.0.len() {
+            panic!(
+                "invalid state: expected length {}, got {}",
+                self.0.len(),
+                y.0.len()
+            );
+        }
+
+        kernels::axpy(&mut self.0, alpha, &y.0);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        norms::euclidean,
+        projectors::{ball::SphereProjector, hyperplane::HyperplaneProjector},
+        solvers::divide_and_concur::{candidate, DivideAndConcurSolver},
+        Solver,
+    };
+
+    #[test]
+    fn test_vec_state_arithmetic() {
+        let state = VecState::from(vec![1.0, 2.0]) + VecState(vec![3.0, -1.0]);
+        assert_eq!(state.clone() * 0.5, VecState(vec![2.0, 0.5]));
+        assert_eq!(state.zeros_like(), VecState(vec![0.0, 0.0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid state: expected length 2, got 1")]
+    fn test_vec_state_length_mismatch() {
+        let _ = VecState(vec![1.0, 2.0]) + VecState(vec![1.0]);
+    }
+
+    #[test]
+    fn test_vec_state_solver() {
+        let divide = SphereProjector::new(1.0);
+        let concur = HyperplaneProjector::new(vec![1.0, 0.0], 0.6);
+        let solver = DivideAndConcurSolver::new(divide, concur.clone(), euclidean, 0.9, 1e-6, 1000);
+
+        let (state, _, _) = solver.run(VecState(vec![0.3, 0.2])).unwrap();
+        let output = candidate(state, &divide).unwrap();
+        assert!((output[0] - 0.6).abs() < 1e-4);
+        assert!((output[1].abs() - 0.8).abs() < 1e-4);
+    }
+}
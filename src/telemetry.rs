@@ -0,0 +1,62 @@
+use crate::{errors::Error, Result};
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{trace::SdkTracerProvider, Resource};
+use tracing_subscriber::{
+    filter::{filter_fn, EnvFilter, LevelFilter},
+    layer::SubscriberExt,
+    util::SubscriberInitExt,
+    Layer,
+};
+
+// Exports the solver's spans to an OTLP collector over HTTP: one
+// `divide_and_concur_run` per run, and below it a span per step with `divide`,
+// `concur` and `norm` children. Events still print to stderr, filtered by
+// `RUST_LOG` as with `tracing_subscriber::fmt::init`. Keep the handle alive for
+// the whole program; dropping it flushes the spans still buffered.
+pub struct Telemetry {
+    provider: SdkTracerProvider,
+}
+
+impl Telemetry {
+    // `endpoint` is the collector's traces URL, such as
+    // `http://localhost:4318/v1/traces`.
+    pub fn init(service_name: &str, endpoint: &str) -> Result<Self> {
+        let exporter = SpanExporter::builder()
+            .with_http()
+            .with_endpoint(endpoint)
+            .build()
+            .map_err(|err| Error::Unknown(Box::new(err)))?;
+        let provider = SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_resource(
+                Resource::builder()
+                    .with_service_name(service_name.to_string())
+                    .build(),
+            )
+            .build();
+
+        // Spans only: the per-step debug events carry whole states.
+        let spans = tracing_opentelemetry::layer()
+            .with_tracer(provider.tracer("drs"))
+            .with_filter(LevelFilter::DEBUG)
+            .with_filter(filter_fn(|metadata| metadata.is_span()));
+        let events = tracing_subscriber::fmt::layer().with_filter(EnvFilter::from_default_env());
+
+        tracing_subscriber::registry()
+            .with(spans)
+            .with(events)
+            .try_init()
+            .map_err(|err| Error::Unknown(Box::new(err)))?;
+
+        Ok(Self { provider })
+    }
+}
+
+impl Drop for Telemetry {
+    fn drop(&mut self) {
+        if let Err(err) = self.provider.shutdown() {
+            eprintln!("failed to flush spans: {err}");
+        }
+    }
+}
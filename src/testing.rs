@@ -0,0 +1,157 @@
+use crate::Projector;
+use proptest::{
+    strategy::Strategy,
+    test_runner::{TestCaseError, TestRunner},
+};
+
+pub type CheckResult = std::result::Result<(), TestCaseError>;
+
+pub fn check_idempotent<S, P, N>(projector: &P, state: S, norm: &N, tolerance: f32) -> CheckResult
+where
+    S: Clone,
+    P: Projector<S>,
+    N: Fn(&S, &S) -> f32,
+{
+    let once = projector.project(state).map_err(fail)?;
+    let twice = projector.project(once.clone()).map_err(fail)?;
+    let delta = norm(&twice, &once);
+
+    if delta.is_nan() || delta > tolerance {
+        return Err(TestCaseError::fail(format!(
+            "projector is not idempotent: |P(P(x)) - P(x)|={delta}, tolerance={tolerance}"
+        )));
+    }
+
+    Ok(())
+}
+
+pub fn check_nonexpansive<S, P, N>(
+    projector: &P,
+    a: S,
+    b: S,
+    norm: &N,
+    tolerance: f32,
+) -> CheckResult
+where
+    P: Projector<S>,
+    N: Fn(&S, &S) -> f32,
+{
+    let before = norm(&a, &b);
+    let pa = projector.project(a).map_err(fail)?;
+    let pb = projector.project(b).map_err(fail)?;
+    let after = norm(&pa, &pb);
+
+    if after.is_nan() || after > before + tolerance {
+        return Err(TestCaseError::fail(format!(
+            "projector is expansive: |P(a) - P(b)|={after} > |a - b|={before}, tolerance={tolerance}"
+        )));
+    }
+
+    Ok(())
+}
+
+pub fn assert_idempotent<S, P, N>(projector: P, state: S, norm: N, tolerance: f32)
+where
+    S: Clone,
+    P: Projector<S>,
+    N: Fn(&S, &S) -> f32,
+{
+    if let Err(err) = check_idempotent(&projector, state, &norm, tolerance) {
+        panic!("{err}");
+    }
+}
+
+pub fn assert_nonexpansive<S, P, N>(projector: P, a: S, b: S, norm: N, tolerance: f32)
+where
+    P: Projector<S>,
+    N: Fn(&S, &S) -> f32,
+{
+    if let Err(err) = check_nonexpansive(&projector, a, b, &norm, tolerance) {
+        panic!("{err}");
+    }
+}
+
+// Projections onto arbitrary sets are only guaranteed to be idempotent.
+pub fn check_projector<S, P, N, G>(projector: P, states: G, norm: N, tolerance: f32)
+where
+    S: Clone + std::fmt::Debug,
+    P: Projector<S>,
+    N: Fn(&S, &S) -> f32,
+    G: Strategy<Value = S>,
+{
+    let mut runner = TestRunner::default();
+    let result = runner.run(&states, |state| {
+        check_idempotent(&projector, state, &norm, tolerance)
+    });
+
+    if let Err(err) = result {
+        panic!("{err}");
+    }
+}
+
+// Projections onto convex sets are additionally nonexpansive.
+pub fn check_convex_projector<S, P, N, G>(projector: P, states: G, norm: N, tolerance: f32)
+where
+    S: Clone + std::fmt::Debug,
+    P: Projector<S>,
+    N: Fn(&S, &S) -> f32,
+    G: Strategy<Value = S> + Clone,
+{
+    let mut runner = TestRunner::default();
+    let result = runner.run(&(states.clone(), states), |(a, b)| {
+        check_idempotent(&projector, a.clone(), &norm, tolerance)?;
+        check_nonexpansive(&projector, a, b, &norm, tolerance)
+    });
+
+    if let Err(err) = result {
+        panic!("{err}");
+    }
+}
+
+fn fail(err: crate::errors::Error) -> TestCaseError {
+    TestCaseError::fail(format!("projector failed: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Result;
+    use proptest::{collection::vec, num::f32::NORMAL};
+
+    fn clamp(state: Vec<f32>) -> Result<Vec<f32>> {
+        Ok(state.into_iter().map(|x| x.clamp(-1.0, 1.0)).collect())
+    }
+
+    fn double(state: Vec<f32>) -> Result<Vec<f32>> {
+        Ok(state.into_iter().map(|x| 2.0 * x).collect())
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn norm(current: &Vec<f32>, previous: &Vec<f32>) -> f32 {
+        current
+            .iter()
+            .zip(previous)
+            .map(|(c, p)| (c - p).powi(2))
+            .sum::<f32>()
+            .sqrt()
+    }
+
+    #[test]
+    fn test_check_convex_projector() {
+        let states = vec(-1e3f32..1e3, 4);
+        check_convex_projector(clamp, states, norm, 1e-5);
+    }
+
+    #[test]
+    #[should_panic(expected = "projector is not idempotent")]
+    fn test_check_projector_failure() {
+        let states = vec(NORMAL, 4);
+        check_projector(double, states, norm, 1e-5);
+    }
+
+    #[test]
+    #[should_panic(expected = "projector is expansive")]
+    fn test_assert_nonexpansive_failure() {
+        assert_nonexpansive(double, vec![0.0, 0.0], vec![1.0, 1.0], norm, 1e-5);
+    }
+}
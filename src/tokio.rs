@@ -0,0 +1,153 @@
+use crate::{
+    errors::Error, solvers::divide_and_concur::DivideAndConcurSolver, Projector, Result, Solver,
+    SolverSolution, State,
+};
+use ::tokio::{sync::watch, task::JoinHandle};
+use std::{
+    future::Future,
+    ops::ControlFlow,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+// The latest step of a run on the blocking pool; `step` is `None` until the
+// first step finishes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Progress {
+    pub step: Option<usize>,
+    pub delta: f32,
+}
+
+// Resolves to the result of `run`. Dropping it, or calling `cancel`, stops the
+// run at its next step with `Error::Cancelled`.
+pub struct BlockingSolve<S> {
+    handle: JoinHandle<Result<SolverSolution<S>>>,
+    cancelled: Arc<AtomicBool>,
+    progress: watch::Receiver<Progress>,
+}
+
+impl<S> BlockingSolve<S> {
+    pub fn progress(&self) -> watch::Receiver<Progress> {
+        self.progress.clone()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+impl<S> Future for BlockingSolve<S> {
+    type Output = Result<SolverSolution<S>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.handle)
+            .poll(cx)
+            .map(|joined| match joined {
+                Ok(result) => result,
+                Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+                // The runtime shut down under the task.
+                Err(_) => Err(Error::Cancelled(
+                    self.progress.borrow().step.map_or(0, |t| t + 1),
+                )),
+            })
+    }
+}
+
+impl<S> Drop for BlockingSolve<S> {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+// Runs `solver` on tokio's blocking pool, so async callers don't stall their
+// executor. This installs the solver's monitor, replacing any it already had,
+// and must be called from inside a runtime.
+pub fn solve_on_blocking<S, D, C, N>(
+    solver: DivideAndConcurSolver<S, D, C, N>,
+    state: S,
+) -> BlockingSolve<S>
+where
+    S: State + Send + 'static,
+    D: Projector<S> + Send + 'static,
+    C: Projector<S> + Send + 'static,
+    N: Fn(&S, &S) -> f32 + Send + 'static,
+{
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let (sender, progress) = watch::channel(Progress {
+        step: None,
+        delta: f32::NAN,
+    });
+
+    let flag = Arc::clone(&cancelled);
+    let solver = solver.with_monitor(move |t, delta| {
+        sender.send_replace(Progress {
+            step: Some(t),
+            delta,
+        });
+        match flag.load(Ordering::Relaxed) {
+            true => ControlFlow::Break(()),
+            false => ControlFlow::Continue(()),
+        }
+    });
+    let handle = ::tokio::task::spawn_blocking(move || solver.run(state));
+
+    BlockingSolve {
+        handle,
+        cancelled,
+        progress,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        norms::euclidean_contiguous,
+        projectors::{box_constraints::BoxProjector, hyperplane::HyperplaneProjector},
+        states::vector::VecState,
+    };
+
+    #[::tokio::test]
+    async fn test_solve_on_blocking() {
+        let solver = DivideAndConcurSolver::new(
+            BoxProjector::new(0.0, 1.0),
+            HyperplaneProjector::new(vec![1.0, 1.0], 1.5),
+            euclidean_contiguous,
+            0.8,
+            1e-6,
+            1000,
+        );
+        let solve = solve_on_blocking(solver, VecState(vec![2.0, -1.0]));
+        let progress = solve.progress();
+
+        let (x, steps, _) = solve.await.unwrap();
+        assert!((x[0] + x[1] - 1.5).abs() < 1e-3);
+        assert_eq!(progress.borrow().step, Some(steps));
+    }
+
+    #[::tokio::test]
+    async fn test_cancel() {
+        // Two disjoint boxes never agree, so only cancelling ends the run early.
+        let solver = DivideAndConcurSolver::new(
+            BoxProjector::new(0.0, 1.0),
+            BoxProjector::new(2.0, 3.0),
+            euclidean_contiguous,
+            0.5,
+            1e-6,
+            usize::MAX,
+        );
+        let solve = solve_on_blocking(solver, VecState(vec![0.5]));
+        let mut progress = solve.progress();
+        progress
+            .wait_for(|p| p.step.is_some_and(|t| t >= 10))
+            .await
+            .unwrap();
+
+        solve.cancel();
+        assert!(matches!(solve.await, Err(Error::Cancelled(t)) if t > 10));
+    }
+}
@@ -0,0 +1,82 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tolerances {
+    pub absolute: f32,
+    pub relative: f32,
+    pub idempotency: f32,
+    pub feasibility: f32,
+}
+
+impl Tolerances {
+    pub fn new(absolute: f32) -> Self {
+        Self {
+            absolute,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_relative(mut self, relative: f32) -> Self {
+        self.relative = relative;
+        self
+    }
+
+    pub fn with_idempotency(mut self, idempotency: f32) -> Self {
+        self.idempotency = idempotency;
+        self
+    }
+
+    pub fn with_feasibility(mut self, feasibility: f32) -> Self {
+        self.feasibility = feasibility;
+        self
+    }
+
+    pub fn converged(&self, delta: f32, initial_delta: f32) -> bool {
+        delta < self.threshold(initial_delta)
+    }
+
+    // The delta a run must get below, given its first one.
+    pub fn threshold(&self, initial_delta: f32) -> f32 {
+        let relative = if initial_delta.is_finite() {
+            self.relative * initial_delta
+        } else {
+            0.0
+        };
+        self.absolute + relative
+    }
+
+    pub fn idempotent(&self, delta: f32) -> bool {
+        delta <= self.idempotency
+    }
+
+    pub fn feasible(&self, violation: f32) -> bool {
+        violation <= self.feasibility
+    }
+}
+
+impl Default for Tolerances {
+    fn default() -> Self {
+        Self {
+            absolute: 1e-6,
+            relative: 0.0,
+            idempotency: 1e-5,
+            feasibility: 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_converged() {
+        let tolerances = Tolerances::new(0.1);
+        assert!(tolerances.converged(0.05, 10.0));
+        assert!(!tolerances.converged(0.5, 10.0));
+
+        let tolerances = tolerances.with_relative(0.1);
+        assert!(tolerances.converged(0.5, 10.0));
+        assert!(!tolerances.converged(0.5, f32::NAN));
+        assert!((tolerances.threshold(10.0) - 1.1).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{
+    errors::ProjectorKind, solvers::project, tolerances::Tolerances, Projector, Result,
+    ScalarAccess, State,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub name: &'static str,
+    pub value: f32,
+    pub passed: bool,
+    pub error: Option<String>,
+}
+
+impl Check {
+    fn new(name: &'static str, value: f32, passed: bool) -> Self {
+        Self {
+            name,
+            value,
+            passed: passed && value.is_finite(),
+            error: None,
+        }
+    }
+
+    fn failed(name: &'static str, error: String) -> Self {
+        Self {
+            name,
+            value: f32::NAN,
+            passed: false,
+            error: Some(error),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationReport {
+    pub checks: Vec<Check>,
+}
+
+impl ValidationReport {
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|c| c.passed)
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &Check> {
+        self.checks.iter().filter(|c| !c.passed)
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in &self.checks {
+            let status = if check.passed { "ok" } else { "FAILED" };
+            write!(f, "{}: {} (value={})", check.name, status, check.value)?;
+            match &check.error {
+                Some(error) => writeln!(f, ": {error}")?,
+                None => writeln!(f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn validate<S, D, C, N>(
+    divide: &D,
+    concur: &C,
+    norm: N,
+    sample_state: &S,
+    tolerances: &Tolerances,
+) -> Result<ValidationReport>
+where
+    S: State,
+    D: Projector<S>,
+    C: Projector<S>,
+    N: Fn(&S, &S) -> f32,
+{
+    report(divide, concur, norm, sample_state, tolerances, |_| None)
+}
+
+// Like `validate`, and also fails a projector whose output has NaN or infinite
+// coordinates, with the count of those coordinates as the value.
+pub fn validate_coordinates<S, D, C, N>(
+    divide: &D,
+    concur: &C,
+    norm: N,
+    sample_state: &S,
+    tolerances: &Tolerances,
+) -> Result<ValidationReport>
+where
+    S: State + ScalarAccess,
+    D: Projector<S>,
+    C: Projector<S>,
+    N: Fn(&S, &S) -> f32,
+{
+    report(divide, concur, norm, sample_state, tolerances, |state| {
+        Some(state.scalars().filter(|x| !x.is_finite()).count())
+    })
+}
+
+fn report<S, D, C, N>(
+    divide: &D,
+    concur: &C,
+    norm: N,
+    sample_state: &S,
+    tolerances: &Tolerances,
+    non_finite: impl Fn(&S) -> Option<usize>,
+) -> Result<ValidationReport>
+where
+    S: State,
+    D: Projector<S>,
+    C: Projector<S>,
+    N: Fn(&S, &S) -> f32,
+{
+    let mut checks = Vec::new();
+
+    for (kind, projector) in [
+        (ProjectorKind::Divide, divide as &dyn Projector<S>),
+        (ProjectorKind::Concur, concur as &dyn Projector<S>),
+    ] {
+        let (name_projection, name_finite, name_idempotent, name_symmetric, name_nonnegative) =
+            match kind {
+                ProjectorKind::Divide => (
+                    "divide projection",
+                    "divide finite coordinates",
+                    "divide idempotency",
+                    "norm symmetry (divide)",
+                    "norm non-negativity (divide)",
+                ),
+                ProjectorKind::Concur => (
+                    "concur projection",
+                    "concur finite coordinates",
+                    "concur idempotency",
+                    "norm symmetry (concur)",
+                    "norm non-negativity (concur)",
+                ),
+            };
+
+        // A projector that fails is reported, not propagated, so the other
+        // projector still gets checked.
+        let projected = project(projector, kind, sample_state.clone())
+            .and_then(|once| Ok((once.clone(), project(projector, kind, once)?)));
+        let (once, twice) = match projected {
+            Ok(pair) => pair,
+            Err(err) => {
+                checks.push(Check::failed(name_projection, err.to_string()));
+                continue;
+            }
+        };
+
+        if let Some(count) = non_finite(&once) {
+            checks.push(Check::new(name_finite, count as f32, count == 0));
+        }
+
+        let (forward, backward) = (norm(&once, sample_state), norm(sample_state, &once));
+        let idempotency = norm(&twice, &once);
+        let symmetry = (forward - backward).abs();
+
+        checks.push(Check::new(
+            name_idempotent,
+            idempotency,
+            tolerances.idempotent(idempotency),
+        ));
+        checks.push(Check::new(
+            name_symmetric,
+            symmetry,
+            tolerances.idempotent(symmetry),
+        ));
+        checks.push(Check::new(
+            name_nonnegative,
+            forward.min(backward),
+            forward >= 0.0 && backward >= 0.0,
+        ));
+    }
+
+    let identity = norm(sample_state, sample_state);
+    checks.push(Check::new(
+        "norm identity",
+        identity,
+        tolerances.idempotent(identity.abs()),
+    ));
+
+    Ok(ValidationReport { checks })
+}
@@ -0,0 +1,246 @@
+use crate::{
+    problem::{norm, ProblemState},
+    problems::{
+        rng::SplitMix64,
+        sat::SatProblem,
+        sudoku::{self, Sudoku, SudokuState},
+    },
+    solvers::{
+        divide_and_concur::{candidate, step_with},
+        workspace::StepWorkspace,
+    },
+    Result,
+};
+use wasm_bindgen::prelude::*;
+
+// Browser-facing handles that advance one batch of steps per call, so a page
+// can redraw between batches instead of blocking on a full `run`. Seeds are
+// explicit since there is no OS randomness on `wasm32-unknown-unknown`.
+#[wasm_bindgen]
+pub struct WasmSudoku {
+    puzzle: Sudoku,
+    state: SudokuState,
+    workspace: StepWorkspace<SudokuState>,
+    beta: f32,
+    steps: u32,
+    delta: f32,
+}
+
+#[wasm_bindgen]
+impl WasmSudoku {
+    #[wasm_bindgen(constructor)]
+    pub fn new(puzzle: &str, beta: f32, seed: u32) -> std::result::Result<WasmSudoku, JsError> {
+        let puzzle = Sudoku::parse(puzzle).map_err(js)?;
+        let mut rng = SplitMix64::new(seed as u64);
+        let state = puzzle.initial_state(|| rng.uniform());
+
+        Ok(Self {
+            puzzle,
+            state,
+            workspace: StepWorkspace::new(),
+            beta,
+            steps: 0,
+            delta: f32::NAN,
+        })
+    }
+
+    // Advances `n` steps and returns the last delta.
+    pub fn step(&mut self, n: u32) -> std::result::Result<f32, JsError> {
+        self.advance(n).map_err(js)
+    }
+
+    pub fn n(&self) -> u32 {
+        self.puzzle.n() as u32
+    }
+
+    pub fn steps(&self) -> u32 {
+        self.steps
+    }
+
+    pub fn delta(&self) -> f32 {
+        self.delta
+    }
+
+    // The puzzle's clues, row-major with 0 for a blank.
+    pub fn givens(&self) -> Vec<u32> {
+        self.puzzle.cells().iter().map(|&v| v as u32).collect()
+    }
+
+    // The current candidate grid, row-major.
+    pub fn grid(&self) -> std::result::Result<Vec<u32>, JsError> {
+        let candidate = self.candidate().map_err(js)?;
+        Ok(candidate
+            .solution()
+            .cells()
+            .iter()
+            .map(|&v| v as u32)
+            .collect())
+    }
+
+    pub fn violations(&self) -> std::result::Result<u32, JsError> {
+        let candidate = self.candidate().map_err(js)?;
+        Ok(candidate.solution().violations(&self.puzzle) as u32)
+    }
+}
+
+impl WasmSudoku {
+    fn advance(&mut self, n: u32) -> Result<f32> {
+        let (divide, concur) = (sudoku::divide_projector, sudoku::concur_projector);
+        for _ in 0..n {
+            let update = step_with(
+                self.state.clone(),
+                &divide,
+                &concur,
+                self.beta,
+                &mut self.workspace,
+            )?;
+            self.delta = sudoku::norm(&update, &self.state);
+            self.workspace
+                .recycle(std::mem::replace(&mut self.state, update));
+            self.steps += 1;
+        }
+        Ok(self.delta)
+    }
+
+    fn candidate(&self) -> Result<SudokuState> {
+        candidate(self.state.clone(), &sudoku::divide_projector)
+    }
+}
+
+#[wasm_bindgen]
+pub struct WasmSat {
+    problem: SatProblem,
+    state: ProblemState,
+    workspace: StepWorkspace<ProblemState>,
+    beta: f32,
+    steps: u32,
+    delta: f32,
+}
+
+#[wasm_bindgen]
+impl WasmSat {
+    #[wasm_bindgen(constructor)]
+    pub fn new(dimacs: &str, beta: f32, seed: u32) -> std::result::Result<WasmSat, JsError> {
+        let problem = SatProblem::from_dimacs(dimacs).map_err(js)?;
+        let mut rng = SplitMix64::new(seed as u64);
+        let values: Vec<f32> = (0..problem.cnf().n_variables())
+            .map(|_| 2.0 * rng.uniform() - 1.0)
+            .collect();
+        let state = problem.initial_state(&values).map_err(js)?;
+
+        Ok(Self {
+            problem,
+            state,
+            workspace: StepWorkspace::new(),
+            beta,
+            steps: 0,
+            delta: f32::NAN,
+        })
+    }
+
+    // Advances `n` steps and returns the last delta.
+    pub fn step(&mut self, n: u32) -> std::result::Result<f32, JsError> {
+        self.advance(n).map_err(js)
+    }
+
+    pub fn n_variables(&self) -> u32 {
+        self.problem.cnf().n_variables() as u32
+    }
+
+    pub fn n_clauses(&self) -> u32 {
+        self.problem.cnf().clauses().len() as u32
+    }
+
+    pub fn steps(&self) -> u32 {
+        self.steps
+    }
+
+    pub fn delta(&self) -> f32 {
+        self.delta
+    }
+
+    // The current candidate assignment, 1 for true and 0 for false.
+    pub fn assignment(&self) -> std::result::Result<Vec<u8>, JsError> {
+        let assignment = self.candidate().map_err(js)?;
+        Ok(assignment.into_iter().map(u8::from).collect())
+    }
+
+    pub fn unsatisfied(&self) -> std::result::Result<u32, JsError> {
+        let assignment = self.candidate().map_err(js)?;
+        Ok(self.problem.cnf().unsatisfied(&assignment) as u32)
+    }
+}
+
+impl WasmSat {
+    fn advance(&mut self, n: u32) -> Result<f32> {
+        let problem = self.problem.problem();
+        let (divide, concur) = (problem.divide(), problem.concur());
+        for _ in 0..n {
+            let update = step_with(
+                self.state.clone(),
+                &divide,
+                &concur,
+                self.beta,
+                &mut self.workspace,
+            )?;
+            self.delta = norm(&update, &self.state);
+            self.workspace
+                .recycle(std::mem::replace(&mut self.state, update));
+            self.steps += 1;
+        }
+        Ok(self.delta)
+    }
+
+    fn candidate(&self) -> Result<Vec<bool>> {
+        let problem = self.problem.problem();
+        let state = candidate(self.state.clone(), &problem.divide())?;
+        self.problem.decode(&state)
+    }
+}
+
+fn js(err: crate::errors::Error) -> JsError {
+    JsError::new(&err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Only success paths here: building a `JsError` needs a JS host.
+    #[test]
+    fn test_sudoku() {
+        let mut sudoku = WasmSudoku::new("1...\n..1.\n.3..\n...2", 0.9, 1).unwrap();
+        assert_eq!(sudoku.n(), 4);
+        assert_eq!(sudoku.givens()[0], 1);
+
+        let mut solved = false;
+        for _ in 0..200 {
+            sudoku.step(10).unwrap();
+            if sudoku.violations().unwrap() == 0 {
+                solved = true;
+                break;
+            }
+        }
+        assert!(solved);
+        assert_eq!(sudoku.grid().unwrap()[0], 1);
+        assert!(sudoku.steps() > 0);
+    }
+
+    #[test]
+    fn test_sat() {
+        let dimacs = "p cnf 3 3\n1 2 0\n-1 3 0\n-2 -3 0\n";
+        let mut sat = WasmSat::new(dimacs, 1.0, 2).unwrap();
+        assert_eq!((sat.n_variables(), sat.n_clauses()), (3, 3));
+
+        let mut unsatisfied = sat.unsatisfied().unwrap();
+        for _ in 0..100 {
+            if unsatisfied == 0 {
+                break;
+            }
+            sat.step(10).unwrap();
+            unsatisfied = sat.unsatisfied().unwrap();
+        }
+        assert_eq!(unsatisfied, 0);
+        assert_eq!(sat.assignment().unwrap().len(), 3);
+    }
+}